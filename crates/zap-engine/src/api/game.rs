@@ -1,10 +1,11 @@
 use crate::core::scene::Scene;
 use crate::api::types::{EntityId, SoundEvent, GameEvent};
 use crate::input::queue::InputQueue;
+use crate::input::state::InputState;
 use crate::renderer::instance::RenderBuffer;
 use crate::renderer::camera::Camera2D;
 use crate::systems::effects::EffectsState;
-use crate::systems::text::{FontConfig, build_text_entities, despawn_text};
+use crate::systems::text::{FontConfig, TextAlign, build_text_entities, build_text_entities_ex, despawn_text};
 use crate::assets::manifest::AssetManifest;
 use crate::assets::registry::SpriteRegistry;
 use crate::bridge::protocol::{DEFAULT_MAX_LAYER_BATCHES, DEFAULT_MAX_LIGHTS};
@@ -12,10 +13,11 @@ use crate::components::layer::RenderLayer;
 use crate::components::sprite::SpriteComponent;
 use crate::systems::lighting::LightState;
 use glam::Vec2;
+use std::collections::HashMap;
 #[cfg(feature = "physics")]
 use crate::core::physics::{
-    PhysicsWorld, BodyDesc, ColliderMaterial, CollisionPair,
-    JointHandle, JointDesc,
+    PhysicsWorld, BodyDesc, ColliderDesc, ColliderMaterial, CollisionPair,
+    JointHandle, JointDesc, ShapeCastHit,
 };
 #[cfg(feature = "physics")]
 use crate::components::entity::Entity;
@@ -56,6 +58,15 @@ pub struct GameConfig {
     pub max_lights: usize,
     /// Seed for effects RNG (default: 42). Change for different random sequences.
     pub effects_seed: u64,
+    /// Maximum fixed-timestep catch-up steps per frame (default: 10).
+    /// Bounds how hard the runner works after a stall: simulation-heavy games
+    /// may tolerate more, casual games can drop time sooner.
+    pub max_catch_up_steps: u32,
+    /// Maximum input events handed to `Game::update` per fixed step
+    /// (default: 0 = unlimited). After a page stall floods the queue, the
+    /// runner feeds at most this many events per step and defers the rest
+    /// to subsequent steps, keeping per-frame input logic sane.
+    pub max_input_events_per_step: u32,
     /// Gravity vector for physics simulation. Default: zero (no gravity).
     /// For Y-down coordinate systems, use positive Y for downward gravity.
     #[cfg(feature = "physics")]
@@ -65,6 +76,16 @@ pub struct GameConfig {
     /// with 60Hz game updates. Physics dt = fixed_dt / physics_substeps.
     #[cfg(feature = "physics")]
     pub physics_substeps: u32,
+    /// Enable continuous collision detection on every dynamic body. Default: false.
+    /// Fast-moving games (pool, projectiles) can set this once instead of
+    /// remembering `.with_ccd(true)` on each spawn.
+    #[cfg(feature = "physics")]
+    pub enable_ccd_for_dynamic: bool,
+    /// Draw wireframe collider outlines every frame. Default: false.
+    /// A one-line toggle for development — the runner calls
+    /// `debug_draw_colliders` so games don't have to.
+    #[cfg(feature = "physics")]
+    pub debug_draw_physics: bool,
 }
 
 impl Default for GameConfig {
@@ -85,10 +106,16 @@ impl Default for GameConfig {
             max_layer_batches: DEFAULT_MAX_LAYER_BATCHES,
             max_lights: DEFAULT_MAX_LIGHTS,
             effects_seed: 42,
+            max_catch_up_steps: crate::core::time::DEFAULT_MAX_CATCH_UP_STEPS,
+            max_input_events_per_step: 0,
             #[cfg(feature = "physics")]
             gravity: glam::Vec2::ZERO,
             #[cfg(feature = "physics")]
             physics_substeps: 1,
+            #[cfg(feature = "physics")]
+            enable_ccd_for_dynamic: false,
+            #[cfg(feature = "physics")]
+            debug_draw_physics: false,
         }
     }
 }
@@ -105,6 +132,12 @@ pub trait Game {
     }
 
     /// Setup initial state, spawn entities, configure the scene.
+    ///
+    /// `init` may run more than once on the same instance — replay rebuilds
+    /// the context and calls it again to restart the session. Any state the
+    /// game keeps in `self` (scores, timers, cached entity IDs) must be
+    /// reset here, not only in the constructor, or replays will diverge
+    /// from the original run.
     fn init(&mut self, ctx: &mut EngineContext);
 
     /// The game loop tick. Apply forces, check win conditions, spawn/despawn entities.
@@ -129,34 +162,92 @@ pub struct BakeState {
     mask: u8,
     /// Monotonic counter incremented on every bake/invalidate call.
     generation: u32,
+    /// Dirty region from the most recent `invalidate_region` call, if any.
+    /// Cleared by whole-layer invalidation (which supersedes it).
+    dirty_region: Option<DirtyRegion>,
+}
+
+/// A world-space rect on a baked layer that needs re-rendering.
+///
+/// Lets the renderer re-bake only the changed area of a large static layer
+/// instead of the whole texture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirtyRegion {
+    pub layer: RenderLayer,
+    /// Rect min corner in world units.
+    pub min: Vec2,
+    /// Rect max corner in world units.
+    pub max: Vec2,
 }
 
 impl BakeState {
     /// Create a new BakeState with no baked layers.
     pub fn new() -> Self {
-        Self { mask: 0, generation: 0 }
+        Self { mask: 0, generation: 0, dirty_region: None }
     }
 
     /// Mark a layer for baking. The renderer will cache this layer's contents
     /// to an intermediate texture and reuse it until `invalidate()` is called.
     pub fn bake(&mut self, layer: RenderLayer) {
         self.mask |= 1 << layer.as_u8();
+        // A full re-bake of the layer supersedes any pending partial one
+        if self.dirty_region.map(|r| r.layer) == Some(layer) {
+            self.dirty_region = None;
+        }
         self.generation = self.generation.wrapping_add(1);
     }
 
     /// Mark a baked layer as dirty, signaling the renderer to re-render
     /// this layer's cached texture on the next frame.
     pub fn invalidate(&mut self, _layer: RenderLayer) {
+        // A whole-layer invalidation supersedes any pending partial one
+        self.dirty_region = None;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Mark only a world-space rect of a baked layer as dirty.
+    ///
+    /// The layer stays baked; the renderer re-renders just the region into
+    /// its cached texture. Much cheaper than `invalidate()` when a small area
+    /// of a large static layer changes (e.g. one destroyed terrain tile).
+    ///
+    /// A rect pending for the same layer is widened to the union of both —
+    /// the header carries a single rect, and replacing it would drop the
+    /// earlier redraw. Rects for a different layer are replaced; the
+    /// renderer falls back to a full re-bake on a layer mismatch.
+    pub fn invalidate_region(&mut self, layer: RenderLayer, min: Vec2, max: Vec2) {
+        self.dirty_region = match self.dirty_region {
+            Some(pending) if pending.layer == layer => Some(DirtyRegion {
+                layer,
+                min: pending.min.min(min),
+                max: pending.max.max(max),
+            }),
+            _ => Some(DirtyRegion { layer, min, max }),
+        };
         self.generation = self.generation.wrapping_add(1);
     }
 
+    /// The pending dirty region, if the last invalidation was partial.
+    pub fn dirty_region(&self) -> Option<&DirtyRegion> {
+        self.dirty_region.as_ref()
+    }
+
+    /// Encode the dirty region for the bake header extension:
+    /// `[layer, min_x, min_y, max_x, max_y]`, or all -1.0 when none is pending.
+    pub fn encode_dirty_region(&self) -> [f32; 5] {
+        match &self.dirty_region {
+            Some(r) => [r.layer.as_u8() as f32, r.min.x, r.min.y, r.max.x, r.max.y],
+            None => [-1.0; 5],
+        }
+    }
+
     /// Remove a layer from baking — it will be rendered live every frame.
     pub fn unbake(&mut self, layer: RenderLayer) {
         self.mask &= !(1 << layer.as_u8());
         self.generation = self.generation.wrapping_add(1);
     }
 
-    /// Get the baked layers bitmask (bits 0-5 correspond to RenderLayer variants).
+    /// Get the baked layers bitmask (bits 0-6 correspond to RenderLayer variants).
     pub fn mask(&self) -> u8 {
         self.mask
     }
@@ -167,10 +258,11 @@ impl BakeState {
     }
 
     /// Encode bake state as a single f32 for the SAB header.
-    /// Format: `baked_mask | (bake_generation << 6)` stored as f32.
-    /// f32 can represent integers up to 2^24 exactly, giving ~262k generations.
+    /// Format: `baked_mask | (bake_generation << 7)` stored as f32 — one
+    /// mask bit per `RenderLayer`, generation above them.
+    /// f32 can represent integers up to 2^24 exactly, giving ~131k generations.
     pub fn encode(&self) -> f32 {
-        let encoded = (self.mask as u32) | (self.generation << 6);
+        let encoded = (self.mask as u32) | (self.generation << RenderLayer::COUNT);
         encoded as f32
     }
 }
@@ -179,6 +271,25 @@ impl BakeState {
 // EngineContext
 // ============================================================================
 
+/// Render-load summary of the last completed tick, filled by the runner —
+/// the owned, copyable counterpart of the counts flowing into the renderer's
+/// `FrameData`. Games query it via [`EngineContext::frame_data`] to
+/// self-throttle (e.g., cut particle spawns when instance counts near the
+/// configured capacity).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameData {
+    /// Render instances emitted last tick.
+    pub instances: u32,
+    /// SDF instances emitted last tick.
+    pub sdf_instances: u32,
+    /// Effects vertices (particles, arcs) emitted last tick.
+    pub effects_vertices: u32,
+    /// Active point lights last tick.
+    pub lights: u32,
+    /// Fixed timestep the tick ran at, in seconds.
+    pub dt: f32,
+}
+
 /// Mutable access to engine state, passed to Game::init and Game::update.
 pub struct EngineContext {
     // -- Core state --
@@ -194,6 +305,11 @@ pub struct EngineContext {
     pub lights: LightState,
     /// Layer baking state for render caching.
     pub bake: BakeState,
+    /// Polling-style input state (held keys/pointers), folded from the
+    /// event queue by the runner before each update.
+    pub input_state: InputState,
+    /// Per-layer parallax factors (1.0 = moves with the world).
+    layer_parallax: [f32; RenderLayer::COUNT],
 
     // -- Optional systems --
     #[cfg(feature = "vectors")]
@@ -203,9 +319,17 @@ pub struct EngineContext {
 
     // -- Private state --
     next_id: u32,
+    /// Per-frame sound budget; `emit_sound` evicts by priority beyond it.
+    max_sounds: usize,
     sprite_registry: SpriteRegistry,
+    fonts: HashMap<String, FontConfig>,
     #[cfg(feature = "physics")]
     collision_events: Vec<CollisionPair>,
+    /// Number of physics substeps per `step_physics` call (see
+    /// [`GameConfig::physics_substeps`]). The runner wires this from config.
+    #[cfg(feature = "physics")]
+    pub physics_substeps: u32,
+    frame_data: FrameData,
 }
 
 // -- Constructors --
@@ -217,39 +341,59 @@ impl EngineContext {
             effects: EffectsState::new(42),
             sounds: Vec::new(),
             events: Vec::new(),
+            max_sounds: GameConfig::default().max_sounds,
             camera: Camera2D::new(800.0, 600.0),
             lights: LightState::new(),
             bake: BakeState::new(),
+            input_state: InputState::new(),
+            layer_parallax: [1.0; RenderLayer::COUNT],
             next_id: 1,
             sprite_registry: SpriteRegistry::new(),
+            fonts: HashMap::new(),
             #[cfg(feature = "vectors")]
             vectors: VectorState::new(),
             #[cfg(feature = "physics")]
             physics: PhysicsWorld::new(Vec2::ZERO),
             #[cfg(feature = "physics")]
             collision_events: Vec::new(),
+            #[cfg(feature = "physics")]
+            physics_substeps: 1,
+            frame_data: FrameData::default(),
         }
     }
 
     /// Create an EngineContext configured from a GameConfig.
     /// This wires capacity settings to all subsystems.
     pub fn with_config(config: &GameConfig) -> Self {
+        #[cfg(feature = "physics")]
+        let physics = {
+            let mut physics = PhysicsWorld::new(config.gravity);
+            physics.set_ccd_for_dynamic(config.enable_ccd_for_dynamic);
+            physics
+        };
         Self {
             scene: Scene::with_capacity(config.max_entities),
             effects: EffectsState::with_capacity(config.effects_seed, config.max_effects_vertices),
             sounds: Vec::with_capacity(config.max_sounds),
             events: Vec::with_capacity(config.max_events),
+            max_sounds: config.max_sounds,
             camera: Camera2D::new(config.world_width, config.world_height),
             lights: LightState::with_capacity(config.max_lights),
             bake: BakeState::new(),
+            input_state: InputState::new(),
+            layer_parallax: [1.0; RenderLayer::COUNT],
             next_id: 1,
             sprite_registry: SpriteRegistry::new(),
+            fonts: HashMap::new(),
             #[cfg(feature = "vectors")]
             vectors: VectorState::with_capacity(config.max_vector_vertices),
             #[cfg(feature = "physics")]
-            physics: PhysicsWorld::new(config.gravity),
+            physics,
             #[cfg(feature = "physics")]
             collision_events: Vec::new(),
+            #[cfg(feature = "physics")]
+            physics_substeps: config.physics_substeps.max(1),
+            frame_data: FrameData::default(),
         }
     }
 
@@ -261,15 +405,21 @@ impl EngineContext {
             effects: EffectsState::new(42),
             sounds: Vec::new(),
             events: Vec::new(),
+            max_sounds: GameConfig::default().max_sounds,
             camera: Camera2D::new(800.0, 600.0),
             lights: LightState::new(),
             bake: BakeState::new(),
+            input_state: InputState::new(),
+            layer_parallax: [1.0; RenderLayer::COUNT],
             next_id: 1,
             sprite_registry: SpriteRegistry::new(),
+            fonts: HashMap::new(),
             #[cfg(feature = "vectors")]
             vectors: VectorState::new(),
             physics: PhysicsWorld::new(gravity),
             collision_events: Vec::new(),
+            physics_substeps: 1,
+            frame_data: FrameData::default(),
         }
     }
 }
@@ -288,18 +438,53 @@ impl EngineContext {
     /// Can be called multiple times — each call replaces the registry.
     pub fn load_manifest(&mut self, json: &str) -> Result<(), String> {
         let manifest = AssetManifest::from_json(json).map_err(|e| e.to_string())?;
+        manifest.validate().map_err(|problems| problems.join("; "))?;
         self.sprite_registry = SpriteRegistry::from_manifest(&manifest);
         Ok(())
     }
 
+    /// Merge an additional manifest into the sprite registry — atlas
+    /// indices are rebased past already-loaded atlases, and new sprites
+    /// win on name collision. Lets a shared base atlas coexist with
+    /// per-level atlases; `load_manifest` remains the replace-all path.
+    pub fn add_manifest(&mut self, json: &str) -> Result<(), String> {
+        let manifest = AssetManifest::from_json(json).map_err(|e| e.to_string())?;
+        manifest.validate().map_err(|problems| problems.join("; "))?;
+        self.sprite_registry.merge_manifest(&manifest);
+        Ok(())
+    }
+
     /// Look up a named sprite from the asset manifest.
     /// Returns a clone of the SpriteComponent, or None if not found.
     pub fn sprite(&self, name: &str) -> Option<SpriteComponent> {
         self.sprite_registry.get(name).cloned()
     }
 
+    /// Iterate over all loaded sprite names (arbitrary order) — useful
+    /// for debug tooling and graceful missing-asset handling.
+    pub fn sprite_names(&self) -> impl Iterator<Item = &str> {
+        self.sprite_registry.names()
+    }
+
     /// Emit a sound event to be forwarded to TypeScript.
+    /// When the per-frame budget (`max_sounds`) is full, the incoming
+    /// sound evicts the lowest-priority queued one — or is dropped itself
+    /// if nothing queued ranks below it. A collision burst can't silently
+    /// push out an important cue.
     pub fn emit_sound(&mut self, event: SoundEvent) {
+        if self.sounds.len() >= self.max_sounds {
+            if let Some((idx, lowest)) = self
+                .sounds
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, s)| s.priority)
+            {
+                if lowest.priority < event.priority {
+                    self.sounds[idx] = event;
+                }
+            }
+            return;
+        }
         self.sounds.push(event);
     }
 
@@ -308,6 +493,18 @@ impl EngineContext {
         self.events.push(event);
     }
 
+    /// Render-load summary of the last completed tick (see [`FrameData`]).
+    /// Zeroed until the runner finishes its first tick.
+    pub fn frame_data(&self) -> FrameData {
+        self.frame_data
+    }
+
+    /// Record the frame summary for [`frame_data`](Self::frame_data).
+    /// Called by the runner once the frame's buffers are built.
+    pub fn set_frame_data(&mut self, data: FrameData) {
+        self.frame_data = data;
+    }
+
     /// Clear per-frame transient data (sounds, events, collision events, vectors).
     pub fn clear_frame_data(&mut self) {
         self.sounds.clear();
@@ -338,12 +535,19 @@ impl EngineContext {
         self.bake.invalidate(layer);
     }
 
+    /// Mark only a world-space rect of a baked layer as dirty.
+    /// Cheaper than `invalidate_layer()` when a small area of a large
+    /// static layer changes.
+    pub fn invalidate_layer_region(&mut self, layer: RenderLayer, min: Vec2, max: Vec2) {
+        self.bake.invalidate_region(layer, min, max);
+    }
+
     /// Remove a layer from baking — it will be rendered live every frame.
     pub fn unbake_layer(&mut self, layer: RenderLayer) {
         self.bake.unbake(layer);
     }
 
-    /// Get the baked layers bitmask (bits 0-5 correspond to RenderLayer variants).
+    /// Get the baked layers bitmask (bits 0-6 correspond to RenderLayer variants).
     pub fn baked_layers_mask(&self) -> u8 {
         self.bake.mask()
     }
@@ -354,11 +558,40 @@ impl EngineContext {
     }
 
     /// Encode bake state as a single f32 for the SAB header.
-    /// Format: `baked_mask | (bake_generation << 6)` stored as f32.
-    /// f32 can represent integers up to 2^24 exactly, giving ~262k generations.
+    /// Format: `baked_mask | (bake_generation << 7)` stored as f32.
+    /// f32 can represent integers up to 2^24 exactly, giving ~131k generations.
     pub fn bake_state_encoded(&self) -> f32 {
         self.bake.encode()
     }
+
+    /// Encode the pending dirty region for SAB header[28..33]:
+    /// `[layer, min_x, min_y, max_x, max_y]`, or all -1.0 when none is pending.
+    pub fn dirty_region_encoded(&self) -> [f32; 5] {
+        self.bake.encode_dirty_region()
+    }
+}
+
+// -- Layer parallax methods --
+
+impl EngineContext {
+    /// Set the parallax factor for a render layer.
+    ///
+    /// `1.0` (the default) moves with the world; `0.5` scrolls at half the
+    /// camera speed; `0.0` pins the layer to the camera.
+    pub fn set_layer_parallax(&mut self, layer: RenderLayer, factor: f32) {
+        self.layer_parallax[layer.as_u8() as usize] = factor;
+    }
+
+    /// The parallax factor for a render layer.
+    pub fn layer_parallax(&self, layer: RenderLayer) -> f32 {
+        self.layer_parallax[layer.as_u8() as usize]
+    }
+
+    /// All per-layer parallax factors, indexed by `RenderLayer as u8`.
+    /// Used by the runner when building the render buffer.
+    pub fn layer_parallax_factors(&self) -> &[f32; RenderLayer::COUNT] {
+        &self.layer_parallax
+    }
 }
 
 // -- Text convenience methods --
@@ -395,12 +628,113 @@ impl EngineContext {
         ids
     }
 
+    /// Spawn text with alignment and optional word wrapping.
+    ///
+    /// Like `spawn_text`, but lays out multiple lines: explicit `\n`
+    /// always breaks, and with `max_width` lines also wrap on spaces.
+    /// Alignment offsets each line within `max_width` (or anchors on
+    /// `pos.x` when no width is given). Returns the spawned EntityIds.
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_text_ex(
+        &mut self,
+        text: &str,
+        pos: Vec2,
+        size: f32,
+        font: &FontConfig,
+        tag: &str,
+        align: TextAlign,
+        max_width: Option<f32>,
+    ) -> Vec<EntityId> {
+        // Use borrow-split pattern to avoid conflict between next_id() and scene.spawn()
+        let mut next = self.next_id;
+        let entities = build_text_entities_ex(text, pos, size, font, tag, align, max_width, &mut || {
+            let id = EntityId(next);
+            next += 1;
+            id
+        });
+        self.next_id = next;
+
+        let ids: Vec<EntityId> = entities.iter().map(|e| e.id).collect();
+        for entity in entities {
+            self.scene.spawn(entity);
+        }
+        ids
+    }
+
     /// Despawn all entities with the given tag.
     ///
     /// Useful for removing text that was spawned with a shared tag.
     pub fn despawn_text(&mut self, tag: &str) {
         despawn_text(&mut self.scene, tag);
     }
+
+    /// Register a named font for use with `spawn_text_with_font`.
+    ///
+    /// Games with several fonts (title vs body) register each once in
+    /// `init()` instead of threading FontConfig values through update code.
+    /// Re-registering a name replaces the previous config.
+    pub fn register_font(&mut self, name: impl Into<String>, font: FontConfig) {
+        self.fonts.insert(name.into(), font);
+    }
+
+    /// Look up a registered font by name.
+    pub fn font(&self, name: &str) -> Option<&FontConfig> {
+        self.fonts.get(name)
+    }
+
+    /// Spawn text using a font registered via `register_font`.
+    ///
+    /// Returns the EntityIds of all spawned characters, or an empty Vec
+    /// if no font with that name is registered.
+    pub fn spawn_text_with_font(
+        &mut self,
+        font_name: &str,
+        text: &str,
+        pos: Vec2,
+        size: f32,
+        tag: &str,
+    ) -> Vec<EntityId> {
+        let font = match self.fonts.get(font_name) {
+            Some(font) => font.clone(),
+            None => return Vec::new(),
+        };
+        self.spawn_text(text, pos, size, &font, tag)
+    }
+
+    /// Spawn a regular grid of entities, one per cell, in row-major order.
+    ///
+    /// The builder closure receives a fresh EntityId plus the cell's
+    /// (col, row) indices and returns the entity to spawn. The entity's
+    /// position is set to the cell center: `origin + (col + 0.5, row + 0.5) * cell_size`.
+    ///
+    /// Returns the EntityIds of all spawned entities.
+    pub fn spawn_grid<F>(
+        &mut self,
+        cols: u32,
+        rows: u32,
+        cell_size: f32,
+        origin: Vec2,
+        mut build: F,
+    ) -> Vec<EntityId>
+    where
+        F: FnMut(EntityId, u32, u32) -> crate::components::entity::Entity,
+    {
+        let mut ids = Vec::with_capacity((cols * rows) as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                let id = self.next_id();
+                let center = origin
+                    + Vec2::new(
+                        (col as f32 + 0.5) * cell_size,
+                        (row as f32 + 0.5) * cell_size,
+                    );
+                let entity = build(id, col, row).with_pos(center);
+                self.scene.spawn(entity);
+                ids.push(id);
+            }
+        }
+        ids
+    }
 }
 
 // -- Physics convenience methods --
@@ -425,6 +759,26 @@ impl EngineContext {
         id
     }
 
+    /// Spawn fixed physics bodies covering a tilemap's solid tiles.
+    /// Colliders come from [`TilemapComponent::build_colliders`] (greedy
+    /// cuboid merging), so a solid row costs one body, not one per tile.
+    /// Returns the spawned EntityIds.
+    pub fn spawn_tilemap_colliders(
+        &mut self,
+        tilemap: &crate::components::tilemap::TilemapComponent,
+        material: ColliderMaterial,
+    ) -> Vec<EntityId> {
+        tilemap
+            .build_colliders()
+            .into_iter()
+            .map(|(center, collider)| {
+                let id = self.next_id();
+                let desc = BodyDesc::fixed(collider).with_position(center);
+                self.spawn_with_body(Entity::new(id).with_pos(center), desc, material)
+            })
+            .collect()
+    }
+
     /// Despawn an entity, cleaning up its physics body if present.
     pub fn despawn(&mut self, id: EntityId) {
         if let Some(entity) = self.scene.despawn(id) {
@@ -452,6 +806,27 @@ impl EngineContext {
         }
     }
 
+    /// Set the velocity of an entity's `KinematicVelocityBased` body
+    /// (see [`PhysicsWorld::set_kinematic_velocity`]).
+    pub fn set_kinematic_velocity(&mut self, id: EntityId, linvel: Vec2, angvel: f32) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.set_kinematic_velocity(body, linvel, angvel);
+            }
+        }
+    }
+
+    /// Apply an instantaneous impulse at a world-space point on an entity's
+    /// physics body. Off-center points impart spin (see
+    /// [`PhysicsWorld::apply_impulse_at_point`]).
+    pub fn apply_impulse_at_point(&mut self, id: EntityId, impulse: Vec2, point: Vec2) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.apply_impulse_at_point(body, impulse, point);
+            }
+        }
+    }
+
     /// Set the linear velocity of an entity's physics body.
     pub fn set_velocity(&mut self, id: EntityId, vel: Vec2) {
         if let Some(entity) = self.scene.get(id) {
@@ -470,6 +845,75 @@ impl EngineContext {
             .unwrap_or(Vec2::ZERO)
     }
 
+    /// Set the angular velocity of an entity's physics body (radians/second).
+    pub fn set_angular_velocity(&mut self, id: EntityId, omega: f32) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.set_angular_velocity(body, omega);
+            }
+        }
+    }
+
+    /// Get the angular velocity of an entity's physics body (radians/second).
+    pub fn angular_velocity(&self, id: EntityId) -> f32 {
+        self.scene
+            .get(id)
+            .and_then(|e| e.body.as_ref())
+            .map(|body| self.physics.angular_velocity(body))
+            .unwrap_or(0.0)
+    }
+
+    /// Apply an instantaneous torque impulse to an entity's physics body.
+    pub fn apply_torque_impulse(&mut self, id: EntityId, torque: f32) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.apply_torque_impulse(body, torque);
+            }
+        }
+    }
+
+    /// Set the linear damping of an entity's physics body at runtime.
+    pub fn set_linear_damping(&mut self, id: EntityId, damping: f32) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.set_linear_damping(body, damping);
+            }
+        }
+    }
+
+    /// Set the angular damping of an entity's physics body at runtime.
+    pub fn set_angular_damping(&mut self, id: EntityId, damping: f32) {
+        if let Some(entity) = self.scene.get(id) {
+            if let Some(body) = &entity.body {
+                self.physics.set_angular_damping(body, damping);
+            }
+        }
+    }
+
+    /// Collect all entities whose physics colliders fall within a radius.
+    /// See [`PhysicsWorld::intersect_ball`].
+    pub fn entities_in_radius(&self, center: Vec2, radius: f32) -> Vec<EntityId> {
+        self.physics.intersect_ball(center, radius)
+    }
+
+    /// Find the entity whose physics collider contains a world-space point.
+    /// See [`PhysicsWorld::point_query`].
+    pub fn entity_at_point(&self, point: Vec2) -> Option<EntityId> {
+        self.physics.point_query(point)
+    }
+
+    /// Sweep a shape through the world and return the first entity hit.
+    /// See [`PhysicsWorld::cast_shape`].
+    pub fn cast_shape(
+        &self,
+        shape: &ColliderDesc,
+        from: Vec2,
+        dir: Vec2,
+        max_toi: f32,
+    ) -> Option<ShapeCastHit> {
+        self.physics.cast_shape(shape, from, dir, max_toi)
+    }
+
     /// Create a joint between two entities' physics bodies.
     /// Returns None if either entity lacks a physics body.
     pub fn create_joint(
@@ -495,9 +939,29 @@ impl EngineContext {
 
     /// Step the physics simulation and sync positions back to entities.
     /// Called automatically by the game runner after `Game::update()`.
+    ///
+    /// Runs `physics_substeps` internal steps (each at the physics dt the
+    /// runner configured as `fixed_dt / substeps`), accumulating collision
+    /// events from every substep into one frame's [`collisions`](Self::collisions).
+    /// A pair that starts and re-starts across substeps is reported once.
     pub fn step_physics(&mut self) {
         self.collision_events.clear();
-        self.physics.step_into(&mut self.collision_events);
+        for _ in 0..self.physics_substeps.max(1) {
+            self.physics.step_into(&mut self.collision_events);
+        }
+
+        // Dedup repeated start/stop reports from the substep loop — counts
+        // are small, so a linear seen-list beats hashing here
+        let mut seen: Vec<(EntityId, EntityId, bool)> = Vec::new();
+        self.collision_events.retain(|c| {
+            let key = (c.entity_a, c.entity_b, c.started);
+            if seen.contains(&key) {
+                false
+            } else {
+                seen.push(key);
+                true
+            }
+        });
 
         // Sync Rapier body positions back to entity positions
         for entity in self.scene.iter_mut() {
@@ -595,12 +1059,81 @@ mod bake_state_tests {
 
         let encoded = bake.encode();
         let raw = encoded as u32;
-        let decoded_mask = raw & 0x3F;
-        let decoded_gen = raw >> 6;
+        let decoded_mask = raw & 0x7F;
+        let decoded_gen = raw >> 7;
 
         assert_eq!(decoded_mask, 0b00_1010); // Terrain(1) + Foreground(3)
         assert_eq!(decoded_gen, 2);
     }
+
+    #[test]
+    fn invalidate_region_records_rect_and_keeps_mask() {
+        let mut bake = BakeState::new();
+        bake.bake(RenderLayer::Terrain);
+        let mask_before = bake.mask();
+        let gen_before = bake.generation();
+
+        bake.invalidate_region(
+            RenderLayer::Terrain,
+            Vec2::new(100.0, 200.0),
+            Vec2::new(164.0, 264.0),
+        );
+
+        assert_eq!(bake.mask(), mask_before);
+        assert_eq!(bake.generation(), gen_before + 1);
+
+        let region = bake.dirty_region().expect("dirty region recorded");
+        assert_eq!(region.layer, RenderLayer::Terrain);
+        assert_eq!(region.min, Vec2::new(100.0, 200.0));
+        assert_eq!(region.max, Vec2::new(164.0, 264.0));
+
+        let encoded = bake.encode_dirty_region();
+        assert_eq!(encoded, [RenderLayer::Terrain.as_u8() as f32, 100.0, 200.0, 164.0, 264.0]);
+    }
+
+    #[test]
+    fn same_layer_region_invalidations_union_their_rects() {
+        let mut bake = BakeState::new();
+        bake.bake(RenderLayer::Terrain);
+
+        // Two tiles destroyed before the renderer consumes the header:
+        // the single pending rect must cover both, or the first tile's
+        // pixels stay stale on the baked texture
+        bake.invalidate_region(
+            RenderLayer::Terrain,
+            Vec2::new(100.0, 200.0),
+            Vec2::new(164.0, 264.0),
+        );
+        bake.invalidate_region(
+            RenderLayer::Terrain,
+            Vec2::new(500.0, 50.0),
+            Vec2::new(564.0, 114.0),
+        );
+
+        let region = bake.dirty_region().expect("dirty region recorded");
+        assert_eq!(region.layer, RenderLayer::Terrain);
+        assert_eq!(region.min, Vec2::new(100.0, 50.0));
+        assert_eq!(region.max, Vec2::new(564.0, 264.0));
+
+        // A different layer replaces the rect instead of widening it
+        bake.invalidate_region(RenderLayer::Background, Vec2::ZERO, Vec2::ONE);
+        let region = bake.dirty_region().expect("dirty region recorded");
+        assert_eq!(region.layer, RenderLayer::Background);
+        assert_eq!(region.min, Vec2::ZERO);
+        assert_eq!(region.max, Vec2::ONE);
+    }
+
+    #[test]
+    fn whole_layer_invalidation_clears_dirty_region() {
+        let mut bake = BakeState::new();
+        bake.bake(RenderLayer::Terrain);
+        bake.invalidate_region(RenderLayer::Terrain, Vec2::ZERO, Vec2::ONE);
+        assert!(bake.dirty_region().is_some());
+
+        bake.invalidate(RenderLayer::Terrain);
+        assert!(bake.dirty_region().is_none());
+        assert_eq!(bake.encode_dirty_region(), [-1.0; 5]);
+    }
 }
 
 #[cfg(test)]
@@ -658,6 +1191,19 @@ mod bake_tests {
         assert_eq!(ctx.baked_layers_mask(), 0b00_0001); // only Background
     }
 
+    #[test]
+    fn baking_overlay_sets_bit_six_without_clobbering() {
+        let mut ctx = EngineContext::new();
+        ctx.bake_layer(RenderLayer::Background);
+        ctx.bake_layer(RenderLayer::Overlay);
+        assert_eq!(ctx.baked_layers_mask(), 0b100_0001); // bits 0 and 6
+
+        // Generation bits sit above all 7 mask bits
+        let raw = ctx.bake_state_encoded() as u32;
+        assert_eq!(raw & 0x7F, 0b100_0001);
+        assert_eq!(raw >> 7, 2);
+    }
+
     #[test]
     fn bake_state_encoding_round_trip() {
         let mut ctx = EngineContext::new();
@@ -666,14 +1212,83 @@ mod bake_tests {
 
         let encoded = ctx.bake_state_encoded();
         let raw = encoded as u32;
-        let decoded_mask = raw & 0x3F;
-        let decoded_gen = raw >> 6;
+        let decoded_mask = raw & 0x7F;
+        let decoded_gen = raw >> 7;
 
         assert_eq!(decoded_mask, 0b00_1010); // Terrain(1) + Foreground(3)
         assert_eq!(decoded_gen, 2);
     }
 }
 
+#[cfg(test)]
+mod font_registry_tests {
+    use super::*;
+    use crate::components::sprite::AtlasId;
+
+    #[test]
+    fn registered_fonts_use_their_own_metrics() {
+        let mut ctx = EngineContext::new();
+        ctx.register_font("title", FontConfig::new(AtlasId(1)).with_grid(16, 6));
+        ctx.register_font("body", FontConfig::new(AtlasId(2)).with_grid(8, 12));
+
+        let title_ids = ctx.spawn_text_with_font("title", "A", Vec2::ZERO, 20.0, "t");
+        let body_ids = ctx.spawn_text_with_font("body", "A", Vec2::ZERO, 20.0, "b");
+
+        // 'A' = index 33: 16-wide grid → (1, 2); 8-wide grid → (1, 4)
+        let title_sprite = ctx.scene.get(title_ids[0]).unwrap().sprite.as_ref().unwrap();
+        assert_eq!(title_sprite.atlas, AtlasId(1));
+        assert_eq!((title_sprite.col, title_sprite.row), (1.0, 2.0));
+
+        let body_sprite = ctx.scene.get(body_ids[0]).unwrap().sprite.as_ref().unwrap();
+        assert_eq!(body_sprite.atlas, AtlasId(2));
+        assert_eq!((body_sprite.col, body_sprite.row), (1.0, 4.0));
+    }
+
+    #[test]
+    fn unknown_font_spawns_nothing() {
+        let mut ctx = EngineContext::new();
+        let ids = ctx.spawn_text_with_font("missing", "Hi", Vec2::ZERO, 20.0, "t");
+        assert!(ids.is_empty());
+        assert_eq!(ctx.scene.len(), 0);
+        assert!(ctx.font("missing").is_none());
+    }
+}
+
+#[cfg(test)]
+mod spawn_grid_tests {
+    use super::*;
+    use crate::components::entity::Entity;
+
+    #[test]
+    fn spawn_grid_spawns_all_cells_at_centers() {
+        let mut ctx = EngineContext::new();
+        let origin = Vec2::new(100.0, 200.0);
+        let ids = ctx.spawn_grid(4, 3, 32.0, origin, |id, _col, _row| Entity::new(id));
+
+        assert_eq!(ids.len(), 12);
+        assert_eq!(ctx.scene.len(), 12);
+
+        // First cell (0,0): origin + half cell
+        let first = ctx.scene.get(ids[0]).unwrap();
+        assert_eq!(first.pos, Vec2::new(116.0, 216.0));
+
+        // Last cell (3,2): origin + (3.5, 2.5) * 32
+        let last = ctx.scene.get(ids[11]).unwrap();
+        assert_eq!(last.pos, Vec2::new(100.0 + 3.5 * 32.0, 200.0 + 2.5 * 32.0));
+    }
+
+    #[test]
+    fn spawn_grid_passes_cell_indices_row_major() {
+        let mut ctx = EngineContext::new();
+        let mut seen = Vec::new();
+        ctx.spawn_grid(2, 2, 16.0, Vec2::ZERO, |id, col, row| {
+            seen.push((col, row));
+            Entity::new(id)
+        });
+        assert_eq!(seen, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+    }
+}
+
 #[cfg(test)]
 mod camera_tests {
     use super::*;
@@ -690,6 +1305,43 @@ mod camera_tests {
     }
 }
 
+#[cfg(test)]
+mod sound_tests {
+    use super::*;
+
+    #[test]
+    fn emit_sound_keeps_highest_priority_when_full() {
+        let config = GameConfig {
+            max_sounds: 3,
+            ..Default::default()
+        };
+        let mut ctx = EngineContext::with_config(&config);
+
+        ctx.emit_sound(SoundEvent::new(0).with_priority(1));
+        ctx.emit_sound(SoundEvent::new(1).with_priority(5));
+        ctx.emit_sound(SoundEvent::new(2).with_priority(3));
+
+        // Queue is full: a high-priority sound evicts the lowest (id 0)
+        ctx.emit_sound(SoundEvent::new(3).with_priority(9));
+        assert_eq!(ctx.sounds.len(), 3);
+        assert!(!ctx.sounds.iter().any(|s| s.id == 0));
+        assert!(ctx.sounds.iter().any(|s| s.id == 3));
+
+        // A sound no louder than the quietest queued one is dropped
+        ctx.emit_sound(SoundEvent::new(4).with_priority(2));
+        assert_eq!(ctx.sounds.len(), 3);
+        assert!(!ctx.sounds.iter().any(|s| s.id == 4));
+    }
+
+    #[test]
+    fn voice_group_defaults_to_none() {
+        let plain = SoundEvent::new(7);
+        assert_eq!(plain.priority, 0);
+        assert_eq!(plain.voice_group, None);
+        assert_eq!(SoundEvent::new(7).with_voice_group(2).voice_group, Some(2));
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "physics")]
 mod physics_tests {
@@ -726,6 +1378,73 @@ mod physics_tests {
         assert_eq!(ctx.physics.body_count(), 0);
     }
 
+    #[test]
+    fn spawn_tilemap_colliders_spawns_merged_fixed_bodies() {
+        use crate::components::tilemap::{Tile, TilemapComponent};
+
+        let mut tm = TilemapComponent::new(8, 4, 32.0);
+        tm.fill_rect(0, 0, 8, 1, Some(Tile::new(0.0, 0.0)));
+
+        let mut ctx = EngineContext::new();
+        let ids = ctx.spawn_tilemap_colliders(&tm, ColliderMaterial::default());
+
+        // Solid row merges into a single fixed body
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ctx.physics.body_count(), 1);
+        let entity = ctx.scene.get(ids[0]).unwrap();
+        assert_eq!(entity.pos, Vec2::new(128.0, 16.0));
+    }
+
+    #[test]
+    fn config_enables_ccd_for_dynamic_bodies() {
+        let config = GameConfig {
+            enable_ccd_for_dynamic: true,
+            ..Default::default()
+        };
+        let mut ctx = EngineContext::with_config(&config);
+        let id = ctx.next_id();
+        let entity = Entity::new(id);
+        // No explicit .with_ccd(true) on the desc
+        let desc = BodyDesc::dynamic(ColliderDesc::Ball { radius: 10.0 });
+
+        ctx.spawn_with_body(entity, desc, ColliderMaterial::default());
+
+        let body = ctx.scene.get(id).unwrap().body.unwrap();
+        assert!(ctx.physics.bodies.get(body.body_handle).unwrap().is_ccd_enabled());
+    }
+
+    #[test]
+    fn substeps_catch_collisions_that_a_single_step_tunnels_through() {
+        // Ball at x=-20 moving right at 2400 u/s toward a thin wall at x=0.
+        // One 1/60 step travels 40 units, leaping clear over the ~12-unit
+        // contact window; four 1/240 substeps travel 10 units each and land
+        // inside it.
+        fn run(substeps: u32) -> usize {
+            let mut ctx = EngineContext::new();
+            ctx.physics_substeps = substeps;
+            ctx.physics.set_dt(1.0 / 60.0 / substeps as f32);
+
+            let wall = ctx.next_id();
+            ctx.spawn_with_body(
+                Entity::new(wall),
+                BodyDesc::fixed(ColliderDesc::Cuboid { half_width: 1.0, half_height: 50.0 }),
+                ColliderMaterial::default(),
+            );
+
+            let ball = ctx.next_id();
+            let mut desc = BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_position(Vec2::new(-20.0, 0.0));
+            desc.velocity = Vec2::new(2400.0, 0.0);
+            ctx.spawn_with_body(Entity::new(ball).with_pos(Vec2::new(-20.0, 0.0)), desc, ColliderMaterial::default());
+
+            ctx.step_physics();
+            ctx.collisions().iter().filter(|c| c.started).count()
+        }
+
+        assert_eq!(run(1), 0, "single step should tunnel through the wall");
+        assert!(run(4) > 0, "substeps should catch the collision");
+    }
+
     #[test]
     fn step_physics_syncs_positions() {
         let mut ctx = EngineContext::with_gravity(Vec2::new(0.0, 100.0));
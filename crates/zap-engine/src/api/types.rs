@@ -1,14 +1,70 @@
 use bytemuck::{Pod, Zeroable};
+use glam::Vec2;
 
 /// Unique identifier for an entity in the scene.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EntityId(pub u32);
 
 /// A sound event emitted by the game logic.
-/// The numeric value maps to a game-defined sound in the TypeScript SoundManager.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct SoundEvent(pub u32);
+/// `id` maps to a game-defined sound in the TypeScript SoundManager.
+/// Packed into a fixed-size byte record for the SAB — see
+/// `bridge::protocol::SOUND_EVENT_BYTES` for the wire layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundEvent {
+    pub id: u32,
+    /// Higher-priority sounds survive when the per-frame budget is full
+    /// (see `EngineContext::emit_sound`). Default: 0.
+    pub priority: u8,
+    /// Optional voice group for the JS mixer — sounds in the same group
+    /// can share/steal a voice instead of stacking.
+    pub voice_group: Option<u8>,
+    /// Loop until explicitly stopped (engine hums, ambience). Default: false.
+    pub looping: bool,
+    /// World position for spatialized playback, or None for non-positional.
+    pub position: Option<Vec2>,
+    /// Playback volume in [0, 1]. Default: 1.0.
+    pub volume: f32,
+}
+
+impl SoundEvent {
+    /// A one-shot, non-positional sound at full volume.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            priority: 0,
+            voice_group: None,
+            looping: false,
+            position: None,
+            volume: 1.0,
+        }
+    }
+
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_voice_group(mut self, group: u8) -> Self {
+        self.voice_group = Some(group);
+        self
+    }
+
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    pub fn with_position(mut self, position: Vec2) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+}
 
 /// A game event communicated from Rust to TypeScript via SharedArrayBuffer.
 /// Generic container: `kind` identifies the event, `a/b/c` carry payload.
@@ -23,4 +79,62 @@ pub struct GameEvent {
 
 impl GameEvent {
     pub const FLOATS: usize = 4;
+
+    /// Event with no payload.
+    pub fn new(kind: f32) -> Self {
+        Self { kind, ..Default::default() }
+    }
+
+    /// Pack a position or direction into `a`/`b`.
+    pub fn vec2(kind: f32, v: Vec2) -> Self {
+        Self { kind, a: v.x, b: v.y, c: 0.0 }
+    }
+
+    /// Read back a payload written by [`vec2`](Self::vec2).
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(self.a, self.b)
+    }
+
+    /// Pack an integer into `a`. Exact for |value| ≤ 2²⁴ (the f32
+    /// mantissa); larger values should be split across slots instead.
+    pub fn int(kind: f32, value: i32) -> Self {
+        Self { kind, a: value as f32, b: 0.0, c: 0.0 }
+    }
+
+    /// Read back a payload written by [`int`](Self::int).
+    pub fn as_int(&self) -> i32 {
+        self.a as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec2_event_round_trips() {
+        let event = GameEvent::vec2(3.0, Vec2::new(120.5, -64.25));
+        assert_eq!(event.kind, 3.0);
+        assert_eq!(event.a, 120.5);
+        assert_eq!(event.b, -64.25);
+        assert_eq!(event.c, 0.0);
+        assert_eq!(event.as_vec2(), Vec2::new(120.5, -64.25));
+    }
+
+    #[test]
+    fn int_event_round_trips_within_mantissa_range() {
+        let event = GameEvent::int(7.0, -123456);
+        assert_eq!(event.kind, 7.0);
+        assert_eq!(event.as_int(), -123456);
+
+        // Largest exactly-representable integer in an f32
+        let edge = GameEvent::int(7.0, 1 << 24);
+        assert_eq!(edge.as_int(), 1 << 24);
+    }
+
+    #[test]
+    fn plain_event_has_zero_payload() {
+        let event = GameEvent::new(5.0);
+        assert_eq!((event.a, event.b, event.c), (0.0, 0.0, 0.0));
+    }
 }
@@ -64,6 +64,39 @@ impl AssetManifest {
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    /// Check internal consistency: every sprite must reference an existing
+    /// atlas, sit within that atlas's grid, and have a positive span.
+    /// Collects all problems at once so a bad manifest can be fixed in one
+    /// pass instead of breaking silently at render time.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        for (name, sprite) in &self.sprites {
+            let Some(atlas) = self.atlases.get(sprite.atlas as usize) else {
+                problems.push(format!(
+                    "sprite '{}': atlas index {} out of range ({} atlases)",
+                    name,
+                    sprite.atlas,
+                    self.atlases.len()
+                ));
+                continue;
+            };
+            if sprite.span == 0 {
+                problems.push(format!("sprite '{}': span must be positive", name));
+            }
+            if sprite.col >= atlas.cols || sprite.row >= atlas.rows {
+                problems.push(format!(
+                    "sprite '{}': cell ({}, {}) outside atlas '{}' grid ({}x{})",
+                    name, sprite.col, sprite.row, atlas.name, atlas.cols, atlas.rows
+                ));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +155,54 @@ mod tests {
         assert_eq!(manifest.atlases[1].normal_map, None);
     }
 
+    #[test]
+    fn validate_accepts_consistent_manifest() {
+        let json = r#"{
+            "atlases": [
+                { "name": "tiles", "cols": 16, "rows": 8, "path": "tiles.png" }
+            ],
+            "sprites": {
+                "hero": { "atlas": 0, "col": 15, "row": 7 }
+            }
+        }"#;
+        let manifest = AssetManifest::from_json(json).unwrap();
+        assert!(manifest.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_atlas() {
+        let json = r#"{
+            "atlases": [
+                { "name": "tiles", "cols": 16, "rows": 8, "path": "tiles.png" }
+            ],
+            "sprites": {
+                "ghost": { "atlas": 5, "col": 0, "row": 0 }
+            }
+        }"#;
+        let manifest = AssetManifest::from_json(json).unwrap();
+        let problems = manifest.validate().unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("atlas index 5 out of range"));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_grid_cell_and_zero_span() {
+        let json = r#"{
+            "atlases": [
+                { "name": "tiles", "cols": 16, "rows": 8, "path": "tiles.png" }
+            ],
+            "sprites": {
+                "off_grid": { "atlas": 0, "col": 16, "row": 2 },
+                "flat": { "atlas": 0, "col": 0, "row": 0, "span": 0 }
+            }
+        }"#;
+        let manifest = AssetManifest::from_json(json).unwrap();
+        let problems = manifest.validate().unwrap_err();
+        assert_eq!(problems.len(), 2);
+        assert!(problems.iter().any(|p| p.contains("outside atlas 'tiles' grid (16x8)")));
+        assert!(problems.iter().any(|p| p.contains("span must be positive")));
+    }
+
     #[test]
     fn serialize_normal_map_omitted_when_none() {
         let atlas = AtlasDescriptor {
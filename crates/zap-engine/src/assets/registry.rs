@@ -1,17 +1,37 @@
 use std::collections::HashMap;
-use crate::assets::manifest::AssetManifest;
+use crate::assets::manifest::{AssetManifest, SpriteDescriptor};
 use crate::components::sprite::{SpriteComponent, AtlasId, BlendMode};
 
 /// Registry of named sprites, built from an AssetManifest.
 /// Provides convenient name-based sprite lookup for game code.
 pub struct SpriteRegistry {
     sprites: HashMap<String, SpriteComponent>,
+    /// Number of atlases the registered sprites reference — the rebase
+    /// offset when merging additional manifests.
+    atlas_count: u32,
+}
+
+/// Build the default sprite for a manifest descriptor, shifting its atlas
+/// index by `atlas_offset` (non-zero when merging a second manifest).
+fn sprite_from_desc(desc: &SpriteDescriptor, atlas_offset: u32) -> SpriteComponent {
+    SpriteComponent {
+        atlas: AtlasId(desc.atlas + atlas_offset),
+        col: desc.col as f32,
+        row: desc.row as f32,
+        cell_span: desc.span as f32,
+        alpha: 1.0,
+        alpha_cutoff: 0.0,
+        tint: [1.0, 1.0, 1.0],
+        nine_slice: None,
+        blend: BlendMode::Alpha,
+    }
 }
 
 impl SpriteRegistry {
     pub fn new() -> Self {
         Self {
             sprites: HashMap::new(),
+            atlas_count: 0,
         }
     }
 
@@ -19,22 +39,45 @@ impl SpriteRegistry {
     pub fn from_manifest(manifest: &AssetManifest) -> Self {
         let mut sprites = HashMap::with_capacity(manifest.sprites.len());
         for (name, desc) in &manifest.sprites {
-            sprites.insert(name.clone(), SpriteComponent {
-                atlas: AtlasId(desc.atlas),
-                col: desc.col as f32,
-                row: desc.row as f32,
-                cell_span: desc.span as f32,
-                alpha: 1.0,
-                blend: BlendMode::Alpha,
-            });
+            sprites.insert(name.clone(), sprite_from_desc(desc, 0));
+        }
+        Self {
+            sprites,
+            atlas_count: manifest.atlases.len() as u32,
         }
-        Self { sprites }
+    }
+
+    /// Merge another manifest into this registry. The incoming manifest's
+    /// atlas indices are rebased past the atlases already registered, so a
+    /// shared base atlas and a per-level atlas can coexist. On a name
+    /// collision the new sprite wins.
+    pub fn merge_manifest(&mut self, manifest: &AssetManifest) {
+        let offset = self.atlas_count;
+        for (name, desc) in &manifest.sprites {
+            self.sprites.insert(name.clone(), sprite_from_desc(desc, offset));
+        }
+        self.atlas_count += manifest.atlases.len() as u32;
     }
 
     /// Look up a sprite by name. Returns None if not found.
     pub fn get(&self, name: &str) -> Option<&SpriteComponent> {
         self.sprites.get(name)
     }
+
+    /// Iterate over all registered sprite names (arbitrary order).
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sprites.keys().map(String::as_str)
+    }
+
+    /// Check whether a sprite with this name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.sprites.contains_key(name)
+    }
+
+    /// Number of atlases referenced by the registered manifests.
+    pub fn atlas_count(&self) -> u32 {
+        self.atlas_count
+    }
 }
 
 impl Default for SpriteRegistry {
@@ -73,4 +116,60 @@ mod tests {
         let reg = SpriteRegistry::new();
         assert!(reg.get("nonexistent").is_none());
     }
+
+    #[test]
+    fn names_and_contains_reflect_loaded_sprites() {
+        let json = r#"{
+            "atlases": [
+                { "name": "tiles", "cols": 16, "rows": 8, "path": "tiles.png" }
+            ],
+            "sprites": {
+                "hero": { "atlas": 0, "col": 3, "row": 5 },
+                "tree": { "atlas": 0, "col": 1, "row": 1 }
+            }
+        }"#;
+        let reg = SpriteRegistry::from_manifest(&AssetManifest::from_json(json).unwrap());
+
+        let mut names: Vec<&str> = reg.names().collect();
+        names.sort_unstable();
+        assert_eq!(names, ["hero", "tree"]);
+
+        assert!(reg.contains("hero"));
+        assert!(!reg.contains("villain"));
+    }
+
+    #[test]
+    fn merge_rebases_atlas_indices_and_overrides_collisions() {
+        let base = r#"{
+            "atlases": [
+                { "name": "base", "cols": 16, "rows": 8, "path": "base.png" }
+            ],
+            "sprites": {
+                "hero": { "atlas": 0, "col": 3, "row": 5 },
+                "tree": { "atlas": 0, "col": 1, "row": 1 }
+            }
+        }"#;
+        let level = r#"{
+            "atlases": [
+                { "name": "level1", "cols": 8, "rows": 8, "path": "level1.png" }
+            ],
+            "sprites": {
+                "boss": { "atlas": 0, "col": 0, "row": 0 },
+                "tree": { "atlas": 0, "col": 7, "row": 7 }
+            }
+        }"#;
+
+        let mut reg = SpriteRegistry::from_manifest(&AssetManifest::from_json(base).unwrap());
+        reg.merge_manifest(&AssetManifest::from_json(level).unwrap());
+
+        // Sprites from both manifests resolve; the level atlas got rebased
+        assert_eq!(reg.get("hero").unwrap().atlas, AtlasId(0));
+        assert_eq!(reg.get("boss").unwrap().atlas, AtlasId(1));
+        assert_eq!(reg.atlas_count(), 2);
+
+        // Name collision: the newer manifest wins
+        let tree = reg.get("tree").unwrap();
+        assert_eq!(tree.atlas, AtlasId(1));
+        assert_eq!(tree.col, 7.0);
+    }
 }
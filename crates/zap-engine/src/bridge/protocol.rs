@@ -3,14 +3,14 @@
 ///
 /// Layout (all values in f32 / 4 bytes):
 /// ```text
-/// [Header: 28 floats]
-/// [Instances: max_instances × 8 floats]
+/// [Header: 33 floats]
+/// [Instances: max_instances × 14 floats]
 /// [Effects: max_effects_vertices × 5 floats]
-/// [Sounds: max_sounds × 1 float]
+/// [Sounds: max_sounds × 4 floats]
 /// [Events: max_events × 4 floats]
-/// [SDF: max_sdf_instances × 12 floats]
+/// [SDF: max_sdf_instances × 20 floats]
 /// [Vectors: max_vector_vertices × 6 floats]
-/// [LayerBatches: max_layer_batches × 4 floats]
+/// [LayerBatches: max_layer_batches × 5 floats]
 /// [Lights: max_lights × 8 floats]
 /// ```
 ///
@@ -20,7 +20,7 @@
 use crate::api::game::GameConfig;
 
 /// Number of floats in the header section.
-pub const HEADER_FLOATS: usize = 28;
+pub const HEADER_FLOATS: usize = 33;
 
 /// Header field indices.
 pub const HEADER_LOCK: usize = 0;
@@ -45,7 +45,7 @@ pub const HEADER_VECTOR_VERTEX_COUNT: usize = 17;
 pub const HEADER_MAX_LAYER_BATCHES: usize = 18;
 pub const HEADER_LAYER_BATCH_COUNT: usize = 19;
 pub const HEADER_LAYER_BATCH_OFFSET: usize = 20;
-/// Encoded bake state: `baked_layers_mask | (bake_generation << 6)`.
+/// Encoded bake state: `baked_layers_mask | (bake_generation << 7)`.
 pub const HEADER_BAKE_STATE: usize = 21;
 // Phase 9: Dynamic lighting
 pub const HEADER_MAX_LIGHTS: usize = 22;
@@ -54,34 +54,75 @@ pub const HEADER_AMBIENT_R: usize = 24;
 pub const HEADER_AMBIENT_G: usize = 25;
 pub const HEADER_AMBIENT_B: usize = 26;
 pub const HEADER_RESERVED_27: usize = 27;
+/// Dirty-region rect for partial re-bakes (see `BakeState::encode_dirty_region`):
+/// layer id (-1.0 = no pending region) and the world-space min/max corners.
+pub const HEADER_DIRTY_LAYER: usize = 28;
+pub const HEADER_DIRTY_MIN_X: usize = 29;
+pub const HEADER_DIRTY_MIN_Y: usize = 30;
+pub const HEADER_DIRTY_MAX_X: usize = 31;
+pub const HEADER_DIRTY_MAX_Y: usize = 32;
 
 /// Protocol version written into the header.
-pub const PROTOCOL_VERSION: f32 = 4.0;
-
-/// Floats per render instance (wire format — never changes).
-pub const INSTANCE_FLOATS: usize = 8;
+/// v5: instances grew from 8 to 9 floats (alpha_cutoff).
+/// v6: layer batches grew from 4 to 5 floats (parallax).
+/// v7: instances grew from 9 to 12 floats (RGB tint).
+/// v8: instances grew from 12 to 14 floats (scale_y, cell_span_y).
+/// v9: SDF instances grew from 12 to 16 floats (combine ops).
+/// v10: SDF instances grew from 16 to 20 floats (outlines).
+/// v11: sound events grew from 1 to 16 bytes (priority, looping, position, volume).
+/// v12: Overlay layer added — bake generation and light falloff bits moved up one.
+/// v13: instances grew from 14 to 15 floats (blend mode).
+/// v14: header grew from 28 to 33 floats (dirty-region rect for partial re-bakes).
+pub const PROTOCOL_VERSION: f32 = 14.0;
+
+/// Floats per render instance: x, y, rotation, scale, sprite_col, alpha,
+/// cell_span, atlas_row, alpha_cutoff, tint_r, tint_g, tint_b, scale_y,
+/// cell_span_y, blend. Bump PROTOCOL_VERSION when this changes.
+pub const INSTANCE_FLOATS: usize = 15;
 
 /// Floats per effects vertex: x, y, z, u, v (wire format — never changes).
 pub const EFFECTS_VERTEX_FLOATS: usize = 5;
 
+/// Bytes per packed sound event record:
+/// ```text
+/// byte 0      id
+/// byte 1      priority
+/// byte 2      voice_group (SOUND_NO_VOICE_GROUP = none)
+/// byte 3      flags: bit 0 = looping, bit 1 = positional
+/// bytes 4-7   volume (f32 LE)
+/// bytes 8-11  x (f32 LE, zero when non-positional)
+/// bytes 12-15 y (f32 LE, zero when non-positional)
+/// ```
+/// Bump PROTOCOL_VERSION when this changes.
+pub const SOUND_EVENT_BYTES: usize = 16;
+
+/// Sound event record size in floats (records are 4-byte aligned).
+pub const SOUND_EVENT_FLOATS: usize = SOUND_EVENT_BYTES / 4;
+
+/// Sentinel in the packed voice_group byte meaning "no voice group".
+pub const SOUND_NO_VOICE_GROUP: u8 = 255;
+
 /// Floats per game event: kind, a, b, c (wire format — never changes).
 pub const EVENT_FLOATS: usize = 4;
 
-/// Floats per SDF instance: x, y, radius, rotation, r, g, b, shininess, emissive, shape_type, half_height, extra.
-pub const SDF_INSTANCE_FLOATS: usize = 12;
+/// Floats per SDF instance: x, y, radius, rotation, r, g, b, shininess,
+/// emissive, shape_type, half_height, extra, radius_b, half_height_b,
+/// extra_b, smoothing, outline_width, outline_r, outline_g, outline_b.
+/// Bump PROTOCOL_VERSION when this changes.
+pub const SDF_INSTANCE_FLOATS: usize = 20;
 
 /// Floats per vector vertex: x, y, r, g, b, a (wire format — never changes).
 pub const VECTOR_VERTEX_FLOATS: usize = 6;
 
-/// Floats per layer batch descriptor: layer_id, start, end, atlas_id.
-pub const LAYER_BATCH_FLOATS: usize = 4;
+/// Floats per layer batch descriptor: layer_id, start, end, atlas_id, parallax.
+pub const LAYER_BATCH_FLOATS: usize = 5;
 
 /// Floats per point light: x, y, r, g, b, intensity, radius, layer_mask.
 pub const LIGHT_FLOATS: usize = 8;
 
 /// Default maximum layer batches (one per (layer, atlas) pair).
-/// With 6 layers and up to 8 atlases, 48 is a reasonable default.
-pub const DEFAULT_MAX_LAYER_BATCHES: usize = 48;
+/// With 7 layers and up to 8 atlases, 56 is a reasonable default.
+pub const DEFAULT_MAX_LAYER_BATCHES: usize = 56;
 
 /// Default maximum point lights.
 pub const DEFAULT_MAX_LIGHTS: usize = 64;
@@ -89,6 +130,10 @@ pub const DEFAULT_MAX_LIGHTS: usize = 64;
 /// Runtime-computed buffer layout. Replaces the old compile-time MAX_* constants.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ProtocolLayout {
+    /// Protocol version this layout was computed for (always the crate's
+    /// `PROTOCOL_VERSION`). Carried so tooling that serializes a layout can
+    /// detect wire-format mismatches instead of misreading memory.
+    pub version: f32,
     /// Maximum render instances.
     pub max_instances: usize,
     /// Maximum effects vertices.
@@ -160,7 +205,7 @@ impl ProtocolLayout {
     ) -> Self {
         let instance_data_floats = max_instances * INSTANCE_FLOATS;
         let effects_data_floats = max_effects_vertices * EFFECTS_VERTEX_FLOATS;
-        let sound_data_floats = max_sounds;
+        let sound_data_floats = max_sounds * SOUND_EVENT_FLOATS;
         let event_data_floats = max_events * EVENT_FLOATS;
         let sdf_data_floats = max_sdf_instances * SDF_INSTANCE_FLOATS;
         let vector_data_floats = max_vector_vertices * VECTOR_VERTEX_FLOATS;
@@ -180,6 +225,7 @@ impl ProtocolLayout {
         let buffer_total_bytes = buffer_total_floats * 4;
 
         Self {
+            version: PROTOCOL_VERSION,
             max_instances,
             max_effects_vertices,
             max_sounds,
@@ -209,6 +255,52 @@ impl ProtocolLayout {
         }
     }
 
+    /// Section name / offset / length triples, in buffer order.
+    fn regions(&self) -> [(&'static str, usize, usize); 9] {
+        [
+            ("header", 0, HEADER_FLOATS),
+            ("instances", self.instance_data_offset, self.instance_data_floats),
+            ("effects", self.effects_data_offset, self.effects_data_floats),
+            ("sounds", self.sound_data_offset, self.sound_data_floats),
+            ("events", self.event_data_offset, self.event_data_floats),
+            ("sdf", self.sdf_data_offset, self.sdf_data_floats),
+            ("vectors", self.vector_data_offset, self.vector_data_floats),
+            ("layer_batches", self.layer_batch_data_offset, self.layer_batch_data_floats),
+            ("lights", self.light_data_offset, self.light_data_floats),
+        ]
+    }
+
+    /// Panic if any section [offset, offset+len) overlaps another or extends
+    /// past `buffer_total_floats`. Layouts computed by [`new`](Self::new) are
+    /// contiguous by construction, but a miscomputed offset would silently
+    /// corrupt the neighboring section — the runner calls this in debug
+    /// builds during init to fail fast instead.
+    pub fn assert_non_overlapping(&self) {
+        let regions = self.regions();
+        for (i, &(name_a, off_a, len_a)) in regions.iter().enumerate() {
+            assert!(
+                off_a + len_a <= self.buffer_total_floats,
+                "protocol layout: {} section [{}, {}) extends past buffer end ({})",
+                name_a,
+                off_a,
+                off_a + len_a,
+                self.buffer_total_floats
+            );
+            for &(name_b, off_b, len_b) in &regions[i + 1..] {
+                assert!(
+                    off_a + len_a <= off_b || off_b + len_b <= off_a,
+                    "protocol layout: {} section [{}, {}) overlaps {} section [{}, {})",
+                    name_a,
+                    off_a,
+                    off_a + len_a,
+                    name_b,
+                    off_b,
+                    off_b + len_b
+                );
+            }
+        }
+    }
+
     /// Compute layout from a GameConfig.
     #[cfg(feature = "vectors")]
     pub fn from_config(config: &GameConfig) -> Self {
@@ -274,23 +366,23 @@ mod tests {
     fn custom_capacities_compute_correctly() {
         let layout = ProtocolLayout::new(256, 8192, 16, 64, 64, 4096, 8, 32);
 
-        assert_eq!(layout.instance_data_floats, 256 * 8);
+        assert_eq!(layout.instance_data_floats, 256 * 15);
         assert_eq!(layout.effects_data_floats, 8192 * 5);
-        assert_eq!(layout.sound_data_floats, 16);
+        assert_eq!(layout.sound_data_floats, 16 * 4);
         assert_eq!(layout.event_data_floats, 64 * 4);
-        assert_eq!(layout.sdf_data_floats, 64 * 12);
+        assert_eq!(layout.sdf_data_floats, 64 * 20);
         assert_eq!(layout.vector_data_floats, 4096 * 6);
-        assert_eq!(layout.layer_batch_data_floats, 8 * 4);
+        assert_eq!(layout.layer_batch_data_floats, 8 * 5);
         assert_eq!(layout.light_data_floats, 32 * 8);
 
         let expected_total = HEADER_FLOATS
-            + 256 * 8
+            + 256 * 15
             + 8192 * 5
-            + 16
+            + 16 * 4
             + 64 * 4
-            + 64 * 12
+            + 64 * 20
             + 4096 * 6
-            + 8 * 4
+            + 8 * 5
             + 32 * 8;
         assert_eq!(layout.buffer_total_floats, expected_total);
         assert_eq!(layout.buffer_total_bytes, expected_total * 4);
@@ -312,14 +404,16 @@ mod tests {
     }
 
     #[test]
-    fn header_size_is_28() {
-        assert_eq!(HEADER_FLOATS, 28);
+    fn header_size_is_33() {
+        assert_eq!(HEADER_FLOATS, 33);
         assert_eq!(HEADER_MAX_LAYER_BATCHES, 18);
         assert_eq!(HEADER_LAYER_BATCH_COUNT, 19);
         assert_eq!(HEADER_LAYER_BATCH_OFFSET, 20);
         assert_eq!(HEADER_MAX_LIGHTS, 22);
         assert_eq!(HEADER_LIGHT_COUNT, 23);
         assert_eq!(HEADER_AMBIENT_R, 24);
+        assert_eq!(HEADER_DIRTY_LAYER, 28);
+        assert_eq!(HEADER_DIRTY_MAX_Y, 32);
     }
 
     #[test]
@@ -329,7 +423,77 @@ mod tests {
     }
 
     #[test]
-    fn protocol_version_is_4() {
-        assert_eq!(PROTOCOL_VERSION, 4.0);
+    fn protocol_version_is_14() {
+        assert_eq!(PROTOCOL_VERSION, 14.0);
+    }
+
+    #[test]
+    fn layout_carries_protocol_version() {
+        let layout = ProtocolLayout::from_config(&GameConfig::default());
+        assert_eq!(layout.version, PROTOCOL_VERSION);
+    }
+
+    #[test]
+    fn layout_regions_do_not_overlap() {
+        let layout = ProtocolLayout::new(100, 200, 10, 20, 50, 100, 6, 16);
+        let regions = [
+            ("header", 0, HEADER_FLOATS),
+            ("instances", layout.instance_data_offset, layout.instance_data_floats),
+            ("effects", layout.effects_data_offset, layout.effects_data_floats),
+            ("sounds", layout.sound_data_offset, layout.sound_data_floats),
+            ("events", layout.event_data_offset, layout.event_data_floats),
+            ("sdf", layout.sdf_data_offset, layout.sdf_data_floats),
+            ("vectors", layout.vector_data_offset, layout.vector_data_floats),
+            ("layer_batches", layout.layer_batch_data_offset, layout.layer_batch_data_floats),
+            ("lights", layout.light_data_offset, layout.light_data_floats),
+        ];
+        for (i, &(name_a, off_a, len_a)) in regions.iter().enumerate() {
+            assert!(
+                off_a + len_a <= layout.buffer_total_floats,
+                "{} extends past the buffer end",
+                name_a
+            );
+            for &(name_b, off_b, len_b) in &regions[i + 1..] {
+                assert!(
+                    off_a + len_a <= off_b || off_b + len_b <= off_a,
+                    "{} overlaps {}",
+                    name_a,
+                    name_b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn computed_layouts_pass_the_overlap_checker() {
+        ProtocolLayout::new(100, 200, 10, 20, 50, 100, 6, 16).assert_non_overlapping();
+        ProtocolLayout::from_config(&GameConfig::default()).assert_non_overlapping();
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps")]
+    fn overlap_checker_catches_colliding_sections() {
+        let mut layout = ProtocolLayout::new(100, 200, 10, 20, 50, 100, 6, 16);
+        // Slide the sound section back into the effects section
+        layout.sound_data_offset = layout.effects_data_offset + 1;
+        layout.assert_non_overlapping();
+    }
+
+    #[test]
+    #[should_panic(expected = "extends past buffer end")]
+    fn overlap_checker_catches_sections_past_the_end() {
+        let mut layout = ProtocolLayout::new(100, 200, 10, 20, 50, 100, 6, 16);
+        layout.light_data_offset = layout.buffer_total_floats;
+        layout.assert_non_overlapping();
+    }
+
+    #[test]
+    fn sound_record_is_four_floats() {
+        assert_eq!(SOUND_EVENT_BYTES, SOUND_EVENT_FLOATS * 4);
+    }
+
+    #[test]
+    fn instance_floats_match_render_instance() {
+        assert_eq!(INSTANCE_FLOATS, crate::renderer::instance::RenderInstance::FLOATS);
     }
 }
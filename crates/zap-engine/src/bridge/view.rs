@@ -0,0 +1,157 @@
+//! Read-side view over a protocol buffer.
+//!
+//! `ProtocolLayout` defines where each section lives; `ProtocolView` wraps a
+//! buffer and exposes typed readers so native tests and tooling can assert
+//! contents without repeating offset arithmetic.
+
+use crate::renderer::instance::RenderInstance;
+use super::protocol::{
+    ProtocolLayout, HEADER_FLOATS, INSTANCE_FLOATS, LIGHT_FLOATS,
+    HEADER_INSTANCE_COUNT, HEADER_LIGHT_COUNT, HEADER_PROTOCOL_VERSION,
+};
+
+/// Borrowed, read-only view of a protocol buffer.
+///
+/// The buffer must be at least `layout.buffer_total_floats` long — shorter
+/// buffers indicate a layout mismatch and fail fast at construction.
+pub struct ProtocolView<'a> {
+    data: &'a [f32],
+    layout: ProtocolLayout,
+}
+
+impl<'a> ProtocolView<'a> {
+    /// Wrap a float buffer laid out according to `layout`.
+    pub fn new(data: &'a [f32], layout: ProtocolLayout) -> Self {
+        assert!(
+            data.len() >= layout.buffer_total_floats,
+            "buffer has {} floats, layout needs {}",
+            data.len(),
+            layout.buffer_total_floats
+        );
+        Self { data, layout }
+    }
+
+    /// Wrap a byte buffer (e.g. a SharedArrayBuffer snapshot).
+    pub fn from_bytes(bytes: &'a [u8], layout: ProtocolLayout) -> Self {
+        Self::new(bytemuck::cast_slice(bytes), layout)
+    }
+
+    /// The 28-float header section. Index with the `HEADER_*` constants.
+    pub fn header(&self) -> &[f32] {
+        &self.data[..HEADER_FLOATS]
+    }
+
+    /// Protocol version stamped into the header at init.
+    pub fn protocol_version(&self) -> f32 {
+        self.header()[HEADER_PROTOCOL_VERSION]
+    }
+
+    /// Number of render instances written this frame (from the header).
+    pub fn instance_count(&self) -> usize {
+        self.header()[HEADER_INSTANCE_COUNT] as usize
+    }
+
+    /// Typed read of render instance `i`. Panics if out of capacity.
+    pub fn instance(&self, i: usize) -> RenderInstance {
+        assert!(i < self.layout.max_instances, "instance {} out of capacity", i);
+        let start = self.layout.instance_data_offset + i * INSTANCE_FLOATS;
+        let floats = &self.data[start..start + INSTANCE_FLOATS];
+        *bytemuck::from_bytes(bytemuck::cast_slice(floats))
+    }
+
+    /// Number of point lights written this frame (from the header).
+    pub fn light_count(&self) -> usize {
+        self.header()[HEADER_LIGHT_COUNT] as usize
+    }
+
+    /// The 8 floats of light `i`: x, y, r, g, b, intensity, radius, layer_mask.
+    pub fn light(&self, i: usize) -> &[f32] {
+        assert!(i < self.layout.max_lights, "light {} out of capacity", i);
+        let start = self.layout.light_data_offset + i * LIGHT_FLOATS;
+        &self.data[start..start + LIGHT_FLOATS]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bridge::protocol::HEADER_FRAME_COUNTER;
+    use crate::api::types::EntityId;
+    use crate::components::entity::Entity;
+    use crate::components::sprite::SpriteComponent;
+    use crate::core::scene::Scene;
+    use crate::renderer::instance::RenderBuffer;
+    use crate::systems::render::build_render_buffer;
+    use glam::Vec2;
+
+    /// Assemble a buffer the way the worker does: header counts + sections.
+    fn write_buffer(layout: &ProtocolLayout, render: &RenderBuffer) -> Vec<f32> {
+        let mut buf = vec![0.0f32; layout.buffer_total_floats];
+        buf[HEADER_PROTOCOL_VERSION] = layout.version;
+        buf[HEADER_INSTANCE_COUNT] = render.instance_count() as f32;
+        let floats: &[f32] = bytemuck::cast_slice(&render.instances);
+        buf[layout.instance_data_offset..layout.instance_data_offset + floats.len()]
+            .copy_from_slice(floats);
+        buf
+    }
+
+    #[test]
+    fn view_agrees_with_render_buffer() {
+        let mut scene = Scene::new();
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(120.0, 340.0))
+                .with_scale(Vec2::splat(50.0))
+                .with_sprite(SpriteComponent {
+                    col: 3.0,
+                    ..Default::default()
+                }),
+        );
+        scene.spawn(
+            Entity::new(EntityId(2))
+                .with_pos(Vec2::new(-60.0, 25.0))
+                .with_scale(Vec2::splat(20.0))
+                .with_sprite(SpriteComponent {
+                    col: 7.0,
+                    ..Default::default()
+                }),
+        );
+
+        let mut render = RenderBuffer::new();
+        build_render_buffer(scene.iter(), &mut render);
+
+        let layout = ProtocolLayout::new(16, 64, 4, 4, 4, 16, 4, 4);
+        let buf = write_buffer(&layout, &render);
+        let view = ProtocolView::new(&buf, layout);
+
+        assert_eq!(view.protocol_version(), crate::bridge::protocol::PROTOCOL_VERSION);
+        assert_eq!(view.instance_count(), render.instances.len());
+        for (i, expected) in render.instances.iter().enumerate() {
+            let inst = view.instance(i);
+            assert_eq!(inst.x, expected.x);
+            assert_eq!(inst.y, expected.y);
+            assert_eq!(inst.sprite_col, expected.sprite_col);
+        }
+    }
+
+    #[test]
+    fn from_bytes_reads_header_fields() {
+        let layout = ProtocolLayout::new(4, 16, 2, 2, 2, 8, 2, 2);
+        let mut buf = vec![0.0f32; layout.buffer_total_floats];
+        buf[HEADER_FRAME_COUNTER] = 42.0;
+        let bytes: Vec<u8> = bytemuck::cast_slice(&buf).to_vec();
+
+        let view = ProtocolView::from_bytes(&bytes, layout);
+        assert_eq!(view.header()[HEADER_FRAME_COUNTER], 42.0);
+        assert_eq!(view.instance_count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of capacity")]
+    fn instance_read_beyond_capacity_panics() {
+        let layout = ProtocolLayout::new(4, 16, 2, 2, 2, 8, 2, 2);
+        let buf = vec![0.0f32; layout.buffer_total_floats];
+        let view = ProtocolView::new(&buf, layout);
+        view.instance(4);
+    }
+}
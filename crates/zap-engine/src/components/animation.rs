@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 /// Definition of a single animation sequence.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationDef {
     /// Frame indices as (col, row) pairs in the atlas.
     pub frames: Vec<(f32, f32)>,
@@ -40,6 +41,19 @@ impl AnimationDef {
         }
     }
 
+    /// Create from explicit atlas columns on a single row.
+    ///
+    /// Handy for flipbook strips whose frames aren't consecutive cells
+    /// (e.g. `&[0.0, 1.0, 2.0, 1.0]` for a ping-pong walk cycle).
+    pub fn sprite_frames(cols: &[f32], row: f32, fps: f32, looping: bool) -> Self {
+        let frames: Vec<(f32, f32)> = cols.iter().map(|&col| (col, row)).collect();
+        Self {
+            frames,
+            frame_duration: 1.0 / fps,
+            looping,
+        }
+    }
+
     /// Create from explicit frame list.
     pub fn from_frames(frames: Vec<(f32, f32)>, fps: f32, looping: bool) -> Self {
         Self {
@@ -62,6 +76,7 @@ impl AnimationDef {
 
 /// Animation state for an entity.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationComponent {
     /// Named animations available for this entity.
     pub animations: HashMap<String, AnimationDef>,
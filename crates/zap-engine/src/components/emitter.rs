@@ -2,15 +2,38 @@ use crate::systems::effects::SegmentColor;
 
 /// How the emitter releases particles.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EmissionMode {
     /// Emit particles continuously at a fixed rate.
     Continuous,
-    /// Emit particles in bursts.
-    Burst,
+    /// Emit `count` particles at each `delay` interval, stopping after
+    /// `repeats` bursts (0 = repeat forever). The first burst fires
+    /// immediately, so `repeats: 1` is a one-shot explosion.
+    Burst { count: u32, delay: f32, repeats: u32 },
+    /// Emit particles proportional to distance traveled since last frame.
+    /// Fast movement leaves a dense trail; a stationary emitter emits nothing.
+    Distance,
+}
+
+/// Where particles spawn relative to the emitter position.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmitterShape {
+    /// All particles spawn at the emitter position.
+    Point,
+    /// Particles spawn uniformly along the segment from `a` to `b`
+    /// (offsets from the emitter position) — rain, sparks off an edge.
+    Line { a: [f32; 2], b: [f32; 2] },
+    /// Particles spawn on a circle of `radius` with initial velocity
+    /// oriented radially outward — shockwaves.
+    Ring { radius: f32 },
+    /// Particles spawn uniformly inside a centered rectangle.
+    Rect { half_extents: [f32; 2] },
 }
 
 /// How particle colors are chosen.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParticleColorMode {
     /// Pick a random SegmentColor for each particle.
     Random,
@@ -22,21 +45,24 @@ pub enum ParticleColorMode {
 
 /// Component for auto-spawning particles from an entity's position.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmitterComponent {
     /// Whether the emitter is actively spawning.
     pub active: bool,
     /// Emission mode (continuous or burst).
     pub mode: EmissionMode,
+    /// Spawn shape around the emitter position.
+    pub shape: EmitterShape,
     /// Particles per second (Continuous mode).
     pub rate: f32,
-    /// Particles per burst (Burst mode).
-    pub burst_count: u32,
-    /// Seconds between bursts (0 = one-shot).
-    pub burst_interval: f32,
+    /// Particles per world unit traveled (Distance mode).
+    pub rate_per_unit: f32,
     /// Min/max initial speed magnitude.
     pub speed_range: (f32, f32),
     /// Particle visual width.
     pub width: f32,
+    /// Width at expiry; `None` keeps particles at `width` all life.
+    pub end_width: Option<f32>,
     /// Particle lifetime in seconds.
     pub lifetime: f32,
     /// How particle colors are chosen.
@@ -51,8 +77,10 @@ pub struct EmitterComponent {
     accumulator: f32,
     /// Internal timer for burst intervals.
     burst_timer: f32,
-    /// Whether the first burst has fired (for one-shot bursts).
-    burst_fired: bool,
+    /// Number of bursts fired so far (for the repeats limit).
+    bursts_fired: u32,
+    /// Last known emitter position (Distance mode). None until the first tick.
+    last_pos: Option<[f32; 2]>,
 }
 
 impl Default for EmitterComponent {
@@ -60,11 +88,12 @@ impl Default for EmitterComponent {
         Self {
             active: true,
             mode: EmissionMode::Continuous,
+            shape: EmitterShape::Point,
             rate: 10.0,
-            burst_count: 8,
-            burst_interval: 0.0,
+            rate_per_unit: 1.0,
             speed_range: (2.0, 8.0),
             width: 4.0,
+            end_width: None,
             lifetime: 1.0,
             color_mode: ParticleColorMode::Random,
             drag: 0.02,
@@ -72,7 +101,8 @@ impl Default for EmitterComponent {
             speed_factor: 0.8,
             accumulator: 0.0,
             burst_timer: 0.0,
-            burst_fired: false,
+            bursts_fired: 0,
+            last_pos: None,
         }
     }
 }
@@ -89,18 +119,18 @@ impl EmitterComponent {
         self
     }
 
-    pub fn with_rate(mut self, rate: f32) -> Self {
-        self.rate = rate;
+    pub fn with_shape(mut self, shape: EmitterShape) -> Self {
+        self.shape = shape;
         self
     }
 
-    pub fn with_burst_count(mut self, count: u32) -> Self {
-        self.burst_count = count;
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
         self
     }
 
-    pub fn with_burst_interval(mut self, interval: f32) -> Self {
-        self.burst_interval = interval;
+    pub fn with_rate_per_unit(mut self, rate_per_unit: f32) -> Self {
+        self.rate_per_unit = rate_per_unit;
         self
     }
 
@@ -114,6 +144,12 @@ impl EmitterComponent {
         self
     }
 
+    /// Shrink (or grow) particles toward this width as they die.
+    pub fn with_end_width(mut self, end_width: f32) -> Self {
+        self.end_width = Some(end_width);
+        self
+    }
+
     pub fn with_lifetime(mut self, lifetime: f32) -> Self {
         self.lifetime = lifetime;
         self
@@ -152,26 +188,56 @@ impl EmitterComponent {
                 self.accumulator -= count as f32;
                 count
             }
-            EmissionMode::Burst => {
-                if self.burst_interval <= 0.0 {
-                    // One-shot burst
-                    if !self.burst_fired {
-                        self.burst_fired = true;
-                        self.burst_count as usize
-                    } else {
-                        0
-                    }
+            EmissionMode::Burst { count, delay, repeats } => {
+                let (count, delay, repeats) = (*count, *delay, *repeats);
+                if repeats > 0 && self.bursts_fired >= repeats {
+                    return 0;
+                }
+                let fire = if self.bursts_fired == 0 {
+                    // First burst fires immediately
+                    true
                 } else {
                     self.burst_timer += dt;
-                    if self.burst_timer >= self.burst_interval {
-                        self.burst_timer -= self.burst_interval;
-                        self.burst_count as usize
+                    if self.burst_timer >= delay {
+                        self.burst_timer -= delay;
+                        true
                     } else {
-                        0
+                        false
                     }
+                };
+                if fire {
+                    self.bursts_fired += 1;
+                    count as usize
+                } else {
+                    0
                 }
             }
+            // Distance mode emits from movement, not time — see tick_moved()
+            EmissionMode::Distance => 0,
+        }
+    }
+
+    /// Advance a distance-based emitter given its current position.
+    /// Returns the number of particles to spawn for the movement since the
+    /// previous call. The first call only records the position (no spawn).
+    pub fn tick_moved(&mut self, pos: [f32; 2]) -> usize {
+        if !self.active {
+            self.last_pos = Some(pos);
+            return 0;
         }
+        let distance = match self.last_pos {
+            Some([lx, ly]) => {
+                let dx = pos[0] - lx;
+                let dy = pos[1] - ly;
+                (dx * dx + dy * dy).sqrt()
+            }
+            None => 0.0,
+        };
+        self.last_pos = Some(pos);
+        self.accumulator += self.rate_per_unit * distance;
+        let count = self.accumulator as usize;
+        self.accumulator -= count as f32;
+        count
     }
 }
 
@@ -191,13 +257,13 @@ mod tests {
     fn builder_pattern() {
         let e = EmitterComponent::new()
             .with_rate(50.0)
-            .with_mode(EmissionMode::Burst)
-            .with_burst_count(16)
-            .with_burst_interval(0.5)
+            .with_mode(EmissionMode::Burst { count: 16, delay: 0.5, repeats: 0 })
             .with_drag(0.05);
         assert_eq!(e.rate, 50.0);
-        assert_eq!(e.burst_count, 16);
-        assert_eq!(e.burst_interval, 0.5);
+        assert!(matches!(
+            e.mode,
+            EmissionMode::Burst { count: 16, repeats: 0, .. }
+        ));
         assert_eq!(e.drag, 0.05);
     }
 
@@ -210,23 +276,59 @@ mod tests {
     }
 
     #[test]
-    fn burst_one_shot() {
+    fn burst_single_repeat_fires_once_then_idles() {
         let mut e = EmitterComponent::new()
-            .with_mode(EmissionMode::Burst)
-            .with_burst_count(10)
-            .with_burst_interval(0.0);
+            .with_mode(EmissionMode::Burst { count: 10, delay: 0.5, repeats: 1 });
         assert_eq!(e.tick(0.016), 10);
-        // Second tick should yield 0 (one-shot)
-        assert_eq!(e.tick(0.016), 0);
+        // Exactly one burst: idle forever after
+        for _ in 0..100 {
+            assert_eq!(e.tick(0.016), 0);
+        }
+    }
+
+    #[test]
+    fn burst_repeats_limit_stops_emission() {
+        let mut e = EmitterComponent::new()
+            .with_mode(EmissionMode::Burst { count: 4, delay: 1.0, repeats: 3 });
+        let mut total = 0;
+        for _ in 0..50 {
+            total += e.tick(0.25);
+        }
+        // First burst immediate, then one per second: 3 bursts total
+        assert_eq!(total, 12);
+    }
+
+    #[test]
+    fn distance_mode_spawns_proportional_to_movement() {
+        let mut e = EmitterComponent::new()
+            .with_mode(EmissionMode::Distance)
+            .with_rate_per_unit(1.0);
+        // First call only records the position
+        assert_eq!(e.tick_moved([0.0, 0.0]), 0);
+        let short = e.tick_moved([10.0, 0.0]);
+        let long = e.tick_moved([30.0, 0.0]);
+        assert_eq!(short, 10);
+        assert_eq!(long, 20);
+    }
+
+    #[test]
+    fn distance_mode_stationary_spawns_none() {
+        let mut e = EmitterComponent::new()
+            .with_mode(EmissionMode::Distance)
+            .with_rate_per_unit(5.0);
+        e.tick_moved([50.0, 50.0]);
+        assert_eq!(e.tick_moved([50.0, 50.0]), 0);
+        // Time-based tick never spawns in Distance mode
+        assert_eq!(e.tick(1.0), 0);
     }
 
     #[test]
-    fn burst_repeating() {
+    fn burst_repeating_forever() {
         let mut e = EmitterComponent::new()
-            .with_mode(EmissionMode::Burst)
-            .with_burst_count(5)
-            .with_burst_interval(1.0);
-        // Not enough time yet
+            .with_mode(EmissionMode::Burst { count: 5, delay: 1.0, repeats: 0 });
+        // First burst fires immediately
+        assert_eq!(e.tick(0.016), 5);
+        // Not enough time yet for the next
         assert_eq!(e.tick(0.5), 0);
         // Now enough time
         assert_eq!(e.tick(0.6), 5);
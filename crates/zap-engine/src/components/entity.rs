@@ -11,6 +11,7 @@ use crate::core::physics::PhysicsBody;
 /// Fat Entity — a single struct with optional components.
 /// Designed for simplicity and rapid prototyping over ECS purity.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entity {
     /// Unique identifier.
     pub id: EntityId,
@@ -18,6 +19,11 @@ pub struct Entity {
     pub tag: String,
     /// Whether this entity is active (inactive entities are skipped).
     pub active: bool,
+    /// Whether this entity is drawn. Invisible entities are skipped by the
+    /// render/SDF packing but stay in the scene and keep their physics —
+    /// cheaper than despawning and respawning to toggle them.
+    #[cfg_attr(feature = "serde", serde(default = "default_visible"))]
+    pub visible: bool,
     /// Render layer — controls draw order (Background..UI). Default: Objects.
     pub layer: RenderLayer,
     /// Position in world space.
@@ -26,10 +32,16 @@ pub struct Entity {
     pub rotation: f32,
     /// Scale (world-space size). For sprites, this is the rendered size in world units.
     pub scale: Vec2,
+    /// Draw order within the entity's layer (higher draws on top).
+    /// Ties fall back to entity ID so rebuilt scenes render consistently.
+    pub z_order: f32,
     /// Sprite component (optional — entities without sprites are invisible).
     pub sprite: Option<SpriteComponent>,
     /// Physics body (optional — requires "physics" feature).
+    /// Skipped during serialization: Rapier handles are runtime-only and
+    /// must be reconstructed after a scene is deserialized.
     #[cfg(feature = "physics")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub body: Option<PhysicsBody>,
     /// Particle emitter (optional — auto-spawns particles at entity position).
     pub emitter: Option<EmitterComponent>,
@@ -46,10 +58,12 @@ impl Entity {
             id,
             tag: String::new(),
             active: true,
+            visible: true,
             layer: RenderLayer::default(),
             pos: Vec2::ZERO,
             rotation: 0.0,
             scale: Vec2::ONE,
+            z_order: 0.0,
             sprite: None,
             #[cfg(feature = "physics")]
             body: None,
@@ -86,11 +100,30 @@ impl Entity {
         self
     }
 
+    pub fn with_z_order(mut self, z_order: f32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
     pub fn with_sprite(mut self, sprite: SpriteComponent) -> Self {
         self.sprite = Some(sprite);
         self
     }
 
+    /// Set the sprite's RGB tint. Call after `with_sprite` — tinting an
+    /// entity without a sprite has no visible effect.
+    pub fn with_tint(mut self, r: f32, g: f32, b: f32) -> Self {
+        if let Some(sprite) = &mut self.sprite {
+            sprite.tint = [r, g, b];
+        }
+        self
+    }
+
     #[cfg(feature = "physics")]
     pub fn with_body(mut self, body: PhysicsBody) -> Self {
         self.body = Some(body);
@@ -111,4 +144,15 @@ impl Entity {
         self.animation = Some(animation);
         self
     }
+
+    /// Show or hide the entity without removing it from the scene.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+}
+
+#[cfg(feature = "serde")]
+fn default_visible() -> bool {
+    // Scenes serialized before `visible` existed stay visible on load
+    true
 }
@@ -1,10 +1,11 @@
 /// Render layer — controls draw order for entities.
 ///
-/// Layers are drawn back-to-front: Background first, UI last.
+/// Layers are drawn back-to-front: Background first, Overlay last.
 /// Within a layer, entities are grouped by atlas for batched rendering.
 /// Default layer is `Objects` — existing games work unchanged.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RenderLayer {
     Background = 0,
     Terrain = 1,
@@ -13,11 +14,13 @@ pub enum RenderLayer {
     Foreground = 3,
     VFX = 4,
     UI = 5,
+    /// Above everything, including UI — modal dialogs, transitions, debug HUD.
+    Overlay = 6,
 }
 
 impl RenderLayer {
     /// Total number of render layers.
-    pub const COUNT: usize = 6;
+    pub const COUNT: usize = 7;
 
     /// Convert from a u8 value to a RenderLayer.
     /// Returns None if the value is out of range.
@@ -29,6 +32,7 @@ impl RenderLayer {
             3 => Some(Self::Foreground),
             4 => Some(Self::VFX),
             5 => Some(Self::UI),
+            6 => Some(Self::Overlay),
             _ => None,
         }
     }
@@ -55,6 +59,7 @@ mod tests {
         assert!(RenderLayer::Objects < RenderLayer::Foreground);
         assert!(RenderLayer::Foreground < RenderLayer::VFX);
         assert!(RenderLayer::VFX < RenderLayer::UI);
+        assert!(RenderLayer::UI < RenderLayer::Overlay);
     }
 
     #[test]
@@ -63,11 +68,11 @@ mod tests {
             let layer = RenderLayer::from_u8(val).unwrap();
             assert_eq!(layer.as_u8(), val);
         }
-        assert!(RenderLayer::from_u8(6).is_none());
+        assert!(RenderLayer::from_u8(7).is_none());
     }
 
     #[test]
     fn count_is_correct() {
-        assert_eq!(RenderLayer::COUNT, 6);
+        assert_eq!(RenderLayer::COUNT, 7);
     }
 }
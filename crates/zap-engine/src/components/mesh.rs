@@ -1,5 +1,8 @@
+use glam::Vec2;
+
 /// RGB color for SDF rendering.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SDFColor {
     pub r: f32,
     pub g: f32,
@@ -18,8 +21,21 @@ impl Default for SDFColor {
     }
 }
 
+/// Boolean operation for combining two SDF shapes.
+///
+/// Discriminants are the wire op codes packed into the instance
+/// `shape_type` field (0 = plain primitive, no combine).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SdfOp {
+    Union = 1,
+    Subtract = 2,
+    Intersect = 3,
+}
+
 /// SDF shape primitive.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SDFShape {
     /// Sphere defined by radius. Used for atoms.
     Sphere { radius: f32 },
@@ -30,10 +46,26 @@ pub enum SDFShape {
     /// `radius` is the sphere-trace radius, `half_height` is the box half-height,
     /// `corner_radius` rounds the corners.
     RoundedBox { radius: f32, half_height: f32, corner_radius: f32 },
+    /// Axis-aligned box with independent half-extents. Used for UI chips.
+    /// `corner_radius` rounds the corners (0 = sharp).
+    Box { half_extents: Vec2, corner_radius: f32 },
+    /// Capsule between two endpoints in entity-local space.
+    /// `thickness` is the tube radius. Packed as a rotated capsule.
+    Segment { a: Vec2, b: Vec2, thickness: f32 },
+    /// Two shapes blended with a boolean op and smooth-min radius
+    /// `smoothing` (world units, 0 = hard edge). Children must be
+    /// primitives — the instance format carries one shape per side.
+    Combine {
+        op: SdfOp,
+        a: Box<SDFShape>,
+        b: Box<SDFShape>,
+        smoothing: f32,
+    },
 }
 
 /// Component for SDF-rendered meshes (raymarched spheres).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MeshComponent {
     pub shape: SDFShape,
     pub color: SDFColor,
@@ -46,6 +78,10 @@ pub struct MeshComponent {
     /// - RoundedBox: corner radius (passed via build_sdf_buffer)
     /// - Capsule: unused (0.0)
     pub extra: f32,
+    /// Outline ring width in world units (default 0.0 = no outline).
+    pub outline_width: f32,
+    /// Outline ring color (only used when `outline_width` > 0).
+    pub outline_color: SDFColor,
 }
 
 impl Default for MeshComponent {
@@ -56,6 +92,8 @@ impl Default for MeshComponent {
             shininess: 32.0,
             emissive: 0.0,
             extra: 0.0,
+            outline_width: 0.0,
+            outline_color: SDFColor::new(1.0, 1.0, 1.0),
         }
     }
 }
@@ -83,6 +121,7 @@ impl MeshComponent {
             shininess: 64.0,  // Glossy pool ball
             emissive: 0.0,
             extra: 1.0,  // Flag: enable stripe rendering
+            ..Default::default()
         }
     }
 
@@ -94,6 +133,7 @@ impl MeshComponent {
             shininess: 64.0,  // Glossy pool ball
             emissive: 0.0,
             extra: 0.0,  // Solid ball
+            ..Default::default()
         }
     }
 
@@ -107,6 +147,25 @@ impl MeshComponent {
         Self::new(SDFShape::RoundedBox { radius, half_height, corner_radius }, color)
     }
 
+    /// Convenience builder for a box mesh with independent half-extents.
+    /// (`box` is a reserved keyword, hence the raw identifier.)
+    pub fn r#box(half_extents: Vec2, corner_radius: f32, color: SDFColor) -> Self {
+        Self::new(SDFShape::Box { half_extents, corner_radius }, color)
+    }
+
+    /// Convenience builder for a segment mesh between two local-space points.
+    pub fn segment(a: Vec2, b: Vec2, thickness: f32, color: SDFColor) -> Self {
+        Self::new(SDFShape::Segment { a, b, thickness }, color)
+    }
+
+    /// Convenience builder for a blended pair of primitive shapes.
+    pub fn combine(op: SdfOp, a: SDFShape, b: SDFShape, smoothing: f32, color: SDFColor) -> Self {
+        Self::new(
+            SDFShape::Combine { op, a: Box::new(a), b: Box::new(b), smoothing },
+            color,
+        )
+    }
+
     pub fn with_shininess(mut self, shininess: f32) -> Self {
         self.shininess = shininess;
         self
@@ -116,6 +175,23 @@ impl MeshComponent {
         self.emissive = emissive;
         self
     }
+
+    /// Update the radius of a sphere mesh in place. Non-sphere shapes
+    /// are left untouched — callers animating atom sizes don't need to
+    /// check the shape variant first.
+    pub fn set_sphere_radius(&mut self, radius: f32) {
+        if let SDFShape::Sphere { radius: r } = &mut self.shape {
+            *r = radius;
+        }
+    }
+
+    /// Draw a ring of `width` world units at the shape boundary —
+    /// selection indicators without a separate vector pass.
+    pub fn with_outline(mut self, width: f32, color: SDFColor) -> Self {
+        self.outline_width = width;
+        self.outline_color = color;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -161,4 +237,60 @@ mod tests {
         assert_eq!(m.shininess, 64.0);
         assert_eq!(m.emissive, 0.8);
     }
+
+    #[test]
+    fn mesh_component_box_builder() {
+        let m = MeshComponent::r#box(Vec2::new(20.0, 8.0), 3.0, SDFColor::default());
+        match m.shape {
+            SDFShape::Box { half_extents, corner_radius } => {
+                assert_eq!(half_extents, Vec2::new(20.0, 8.0));
+                assert_eq!(corner_radius, 3.0);
+            }
+            _ => panic!("Expected Box"),
+        }
+    }
+
+    #[test]
+    fn mesh_component_segment_builder() {
+        let m = MeshComponent::segment(
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(10.0, 5.0),
+            2.0,
+            SDFColor::default(),
+        );
+        match m.shape {
+            SDFShape::Segment { a, b, thickness } => {
+                assert_eq!(a, Vec2::new(-10.0, 0.0));
+                assert_eq!(b, Vec2::new(10.0, 5.0));
+                assert_eq!(thickness, 2.0);
+            }
+            _ => panic!("Expected Segment"),
+        }
+    }
+
+    #[test]
+    fn set_sphere_radius_only_touches_spheres() {
+        let mut sphere = MeshComponent::sphere(10.0, SDFColor::default());
+        sphere.set_sphere_radius(25.0);
+        match sphere.shape {
+            SDFShape::Sphere { radius } => assert_eq!(radius, 25.0),
+            _ => panic!("Expected Sphere"),
+        }
+
+        let mut boxy = MeshComponent::r#box(Vec2::new(20.0, 8.0), 3.0, SDFColor::default());
+        boxy.set_sphere_radius(25.0);
+        match boxy.shape {
+            SDFShape::Box { half_extents, .. } => {
+                assert_eq!(half_extents, Vec2::new(20.0, 8.0));
+            }
+            _ => panic!("Expected Box"),
+        }
+
+        let mut seg = MeshComponent::segment(Vec2::ZERO, Vec2::X, 2.0, SDFColor::default());
+        seg.set_sphere_radius(25.0);
+        match seg.shape {
+            SDFShape::Segment { thickness, .. } => assert_eq!(thickness, 2.0),
+            _ => panic!("Expected Segment"),
+        }
+    }
 }
@@ -1,20 +1,33 @@
 /// Identifies which texture atlas a sprite belongs to.
 /// Index into the AssetManifest's atlas list.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AtlasId(pub u32);
 
 /// Blend mode for sprite rendering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BlendMode {
     /// Standard alpha blending (src-alpha, one-minus-src-alpha).
     #[default]
     Alpha,
     /// Additive blending for HDR glow effects (src-alpha, one).
     Additive,
+    /// Multiplicative blending for shadows and tinted glass (dst × src).
+    Multiply,
+}
+
+impl BlendMode {
+    /// Wire discriminant as a float (0, 1 or 2), packed into
+    /// `RenderInstance::blend`.
+    pub fn as_f32(self) -> f32 {
+        self as u32 as f32
+    }
 }
 
 /// Sprite component — defines how an entity appears visually.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpriteComponent {
     /// Which atlas this sprite belongs to.
     pub atlas: AtlasId,
@@ -26,6 +39,18 @@ pub struct SpriteComponent {
     pub cell_span: f32,
     /// Opacity (0.0 = invisible, 1.0 = opaque, >1.0 for HDR glow).
     pub alpha: f32,
+    /// Alpha discard threshold for cutout sprites (foliage, UI icons).
+    /// The shader discards texels with alpha below this value, avoiding
+    /// sorting artifacts from soft-edged translucency. 0.0 = no discard.
+    pub alpha_cutoff: f32,
+    /// RGB tint multiplied with the sampled texel (team colors, damage
+    /// flashes). `[1, 1, 1]` = untinted; values >1.0 push into HDR.
+    pub tint: [f32; 3],
+    /// 9-slice borders `[left, right, top, bottom]` as fractions of the
+    /// atlas cell. When set, the packer emits nine quads: corners keep
+    /// their aspect while edges and center stretch to the entity's scale.
+    /// `None` (the default) renders a single quad.
+    pub nine_slice: Option<[f32; 4]>,
     /// Blend mode for rendering.
     pub blend: BlendMode,
 }
@@ -38,6 +63,9 @@ impl Default for SpriteComponent {
             row: 0.0,
             cell_span: 1.0,
             alpha: 1.0,
+            alpha_cutoff: 0.0,
+            tint: [1.0, 1.0, 1.0],
+            nine_slice: None,
             blend: BlendMode::Alpha,
         }
     }
@@ -5,6 +5,8 @@
 
 use crate::components::layer::RenderLayer;
 use crate::components::sprite::AtlasId;
+#[cfg(feature = "physics")]
+use crate::core::physics::ColliderDesc;
 use crate::renderer::camera::Camera2D;
 use crate::renderer::instance::RenderInstance;
 use glam::Vec2;
@@ -200,6 +202,8 @@ impl TilemapComponent {
                         alpha: tile.alpha,
                         cell_span: 1.0,
                         atlas_row: tile.row,
+                        alpha_cutoff: 0.0,
+                        ..Default::default()
                     });
                 }
             }
@@ -225,6 +229,8 @@ impl TilemapComponent {
                         alpha: tile.alpha,
                         cell_span: 1.0,
                         atlas_row: tile.row,
+                        alpha_cutoff: 0.0,
+                        ..Default::default()
                     });
                 }
             }
@@ -233,6 +239,59 @@ impl TilemapComponent {
         instances
     }
 
+    /// Build a minimal set of cuboid colliders covering all solid
+    /// (non-empty) tiles. Adjacent tiles merge greedily: horizontal runs
+    /// first, then runs with identical extents stack across rows — a solid
+    /// row becomes one cuboid instead of `width` tiles.
+    /// Returns (world-space center, collider) pairs.
+    #[cfg(feature = "physics")]
+    pub fn build_colliders(&self) -> Vec<(Vec2, ColliderDesc)> {
+        // Horizontal runs per row: (start_x, len) keyed by row
+        let mut runs: Vec<(u32, u32, u32)> = Vec::new(); // (y, start_x, len)
+        for ty in 0..self.height {
+            let mut tx = 0;
+            while tx < self.width {
+                if self.get(tx, ty).is_none() {
+                    tx += 1;
+                    continue;
+                }
+                let start = tx;
+                while tx < self.width && self.get(tx, ty).is_some() {
+                    tx += 1;
+                }
+                runs.push((ty, start, tx - start));
+            }
+        }
+
+        // Stack runs with identical horizontal extents across adjacent rows
+        let mut merged: Vec<(u32, u32, u32, u32)> = Vec::new(); // (y, start_x, len, rows)
+        for (y, start, len) in runs {
+            let stacked = merged
+                .iter_mut()
+                .find(|(my, mstart, mlen, mrows)| {
+                    *mstart == start && *mlen == len && my + mrows == y
+                });
+            match stacked {
+                Some((_, _, _, mrows)) => *mrows += 1,
+                None => merged.push((y, start, len, 1)),
+            }
+        }
+
+        merged
+            .into_iter()
+            .map(|(y, start, len, rows)| {
+                let half_width = len as f32 * self.tile_size / 2.0;
+                let half_height = rows as f32 * self.tile_size / 2.0;
+                let center = self.origin
+                    + Vec2::new(
+                        start as f32 * self.tile_size + half_width,
+                        y as f32 * self.tile_size + half_height,
+                    );
+                (center, ColliderDesc::Cuboid { half_width, half_height })
+            })
+            .collect()
+    }
+
     /// Count of non-empty tiles.
     pub fn tile_count(&self) -> usize {
         self.tiles.iter().filter(|t| t.is_some()).count()
@@ -347,6 +406,48 @@ mod tests {
         assert_eq!(tm.tile_count(), 0);
     }
 
+    #[test]
+    #[cfg(feature = "physics")]
+    fn build_colliders_merges_solid_row_into_one_cuboid() {
+        let mut tm = TilemapComponent::new(8, 4, 32.0);
+        // Solid bottom row
+        tm.fill_rect(0, 0, 8, 1, Some(Tile::new(0.0, 0.0)));
+
+        let colliders = tm.build_colliders();
+        assert_eq!(colliders.len(), 1);
+
+        let (center, desc) = &colliders[0];
+        // Row spans 8 tiles × 32 units, centered at (128, 16)
+        assert_eq!(*center, Vec2::new(128.0, 16.0));
+        match desc {
+            ColliderDesc::Cuboid { half_width, half_height } => {
+                assert_eq!(*half_width, 128.0);
+                assert_eq!(*half_height, 16.0);
+            }
+            _ => panic!("Expected Cuboid"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "physics")]
+    fn build_colliders_stacks_matching_runs_and_splits_gaps() {
+        let mut tm = TilemapComponent::new(6, 6, 16.0);
+        // 2x2 solid block — one cuboid
+        tm.fill_rect(1, 1, 2, 2, Some(Tile::new(0.0, 0.0)));
+        // Separate single tile with a gap in between — its own cuboid
+        tm.set(5, 1, Some(Tile::new(0.0, 0.0)));
+
+        let colliders = tm.build_colliders();
+        assert_eq!(colliders.len(), 2);
+        match &colliders[0].1 {
+            ColliderDesc::Cuboid { half_width, half_height } => {
+                assert_eq!(*half_width, 16.0);
+                assert_eq!(*half_height, 16.0);
+            }
+            _ => panic!("Expected Cuboid"),
+        }
+    }
+
     #[test]
     fn tile_rotation_and_alpha() {
         let tile = Tile::new(1.0, 2.0)
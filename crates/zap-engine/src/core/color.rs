@@ -0,0 +1,99 @@
+//! Color utilities for procedural palettes.
+//!
+//! Games that spawn many similar items (flags, planets, teams) need colors
+//! that are easy to tell apart without hand-picking a palette.
+
+/// Golden ratio conjugate — spacing hues by this fraction of the color wheel
+/// produces maximally distinct hues for any number of items.
+const GOLDEN_RATIO_CONJUGATE: f32 = 0.618_034;
+
+/// Generate a visually distinct RGB color for item `index` out of `total`.
+///
+/// Hues are spaced by the golden ratio conjugate so consecutive indices land
+/// far apart on the color wheel. Saturation and value are kept high so colors
+/// read well on dark backgrounds. `total` nudges saturation slightly so large
+/// sets gain extra variation beyond hue alone.
+pub fn distinct_color(index: usize, total: usize) -> [f32; 3] {
+    let hue = (index as f32 * GOLDEN_RATIO_CONJUGATE).fract();
+    // Alternate saturation between two bands for large sets so neighbors on
+    // the wheel still differ; small sets stay fully saturated.
+    let saturation = if total > 12 && index % 2 == 1 { 0.65 } else { 0.85 };
+    let value = 0.95;
+    hsv_to_rgb(hue, saturation, value)
+}
+
+/// Convert HSV (all components in [0, 1]) to linear RGB.
+pub fn hsv_to_rgb(h: f32, s: f32, v: f32) -> [f32; 3] {
+    let h = h.fract() * 6.0;
+    let i = h.floor();
+    let f = h - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+    match i as u32 % 6 {
+        0 => [v, t, p],
+        1 => [q, v, p],
+        2 => [p, v, t],
+        3 => [p, q, v],
+        4 => [t, p, v],
+        _ => [v, p, q],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hue of an RGB color in [0, 1), for checking separation.
+    fn rgb_hue(rgb: [f32; 3]) -> f32 {
+        let [r, g, b] = rgb;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        if delta < 1e-6 {
+            return 0.0;
+        }
+        let h = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        h / 6.0
+    }
+
+    #[test]
+    fn successive_hues_are_well_separated() {
+        for i in 0..10 {
+            let a = rgb_hue(distinct_color(i, 10));
+            let b = rgb_hue(distinct_color(i + 1, 10));
+            // Circular hue distance
+            let d = (a - b).abs();
+            let d = d.min(1.0 - d);
+            assert!(d > 0.2, "hues {} and {} too close: {}", i, i + 1, d);
+        }
+    }
+
+    #[test]
+    fn brightness_is_sane() {
+        for i in 0..20 {
+            let [r, g, b] = distinct_color(i, 20);
+            let max = r.max(g).max(b);
+            assert!((0.0..=1.0).contains(&r));
+            assert!((0.0..=1.0).contains(&g));
+            assert!((0.0..=1.0).contains(&b));
+            // Value is fixed high — no muddy colors
+            assert!(max > 0.9, "color {} too dark: {:?}", i, [r, g, b]);
+        }
+    }
+
+    #[test]
+    fn hsv_primaries_round_trip() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [1.0, 0.0, 0.0]);
+        let g = hsv_to_rgb(1.0 / 3.0, 1.0, 1.0);
+        assert!((g[1] - 1.0).abs() < 0.001 && g[0] < 0.001);
+        let b = hsv_to_rgb(2.0 / 3.0, 1.0, 1.0);
+        assert!((b[2] - 1.0).abs() < 0.001 && b[1] < 0.001);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod color;
 pub mod scene;
 pub mod time;
+pub mod timer;
 
 #[cfg(feature = "physics")]
 pub mod physics;
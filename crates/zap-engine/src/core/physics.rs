@@ -47,22 +47,54 @@ impl BodyType {
 }
 
 /// Shape description for a collider.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum ColliderDesc {
     Ball { radius: f32 },
     Cuboid { half_width: f32, half_height: f32 },
     CapsuleY { half_height: f32, radius: f32 },
+    CapsuleX { half_width: f32, radius: f32 },
+    /// Cuboid with rounded corners — avoids dynamic bodies catching on
+    /// sharp cuboid edges (platforms, sliding characters).
+    RoundCuboid { half_width: f32, half_height: f32, border_radius: f32 },
+    /// Open chain of line segments in local space. Ideal for irregular level
+    /// geometry (terrain, table cushions) instead of stacking many cuboids.
+    Polyline { vertices: Vec<Vec2> },
+    /// Convex hull of the given local-space points.
+    /// Panics at body creation if the points are degenerate (fewer than
+    /// three non-collinear points).
+    ConvexHull { points: Vec<Vec2> },
 }
 
 impl ColliderDesc {
     fn build_collider(&self) -> ColliderBuilder {
-        match *self {
-            ColliderDesc::Ball { radius } => ColliderBuilder::ball(radius),
+        match self {
+            ColliderDesc::Ball { radius } => ColliderBuilder::ball(*radius),
             ColliderDesc::Cuboid { half_width, half_height } => {
-                ColliderBuilder::cuboid(half_width, half_height)
+                ColliderBuilder::cuboid(*half_width, *half_height)
             }
             ColliderDesc::CapsuleY { half_height, radius } => {
-                ColliderBuilder::capsule_y(half_height, radius)
+                ColliderBuilder::capsule_y(*half_height, *radius)
+            }
+            ColliderDesc::CapsuleX { half_width, radius } => {
+                ColliderBuilder::capsule_x(*half_width, *radius)
+            }
+            ColliderDesc::RoundCuboid { half_width, half_height, border_radius } => {
+                ColliderBuilder::round_cuboid(*half_width, *half_height, *border_radius)
+            }
+            ColliderDesc::Polyline { vertices } => {
+                let points = vertices
+                    .iter()
+                    .map(|v| nalgebra::Point2::new(v.x, v.y))
+                    .collect();
+                ColliderBuilder::polyline(points, None)
+            }
+            ColliderDesc::ConvexHull { points } => {
+                let points: Vec<_> = points
+                    .iter()
+                    .map(|v| nalgebra::Point2::new(v.x, v.y))
+                    .collect();
+                ColliderBuilder::convex_hull(&points)
+                    .expect("ConvexHull needs at least 3 non-collinear points")
             }
         }
     }
@@ -99,6 +131,15 @@ pub struct BodyDesc {
     pub collider: ColliderDesc,
     pub linear_damping: f32,
     pub angular_damping: f32,
+    /// Collision group membership bits. Default: all bits set.
+    pub collision_group: u32,
+    /// Collision filter bits — this body only interacts with bodies whose
+    /// membership intersects this mask (and vice versa). Default: all bits set.
+    pub collision_mask: u32,
+    /// When true, the collider detects overlaps without generating contact
+    /// forces — bodies pass through it but `CollisionPair` events still fire.
+    /// Useful for trigger zones (checkpoints, pickups).
+    pub sensor: bool,
 }
 
 impl BodyDesc {
@@ -115,6 +156,9 @@ impl BodyDesc {
             collider,
             linear_damping: 0.0,
             angular_damping: 0.0,
+            collision_group: u32::MAX,
+            collision_mask: u32::MAX,
+            sensor: false,
         }
     }
 
@@ -131,6 +175,9 @@ impl BodyDesc {
             collider,
             linear_damping: 0.0,
             angular_damping: 0.0,
+            collision_group: u32::MAX,
+            collision_mask: u32::MAX,
+            sensor: false,
         }
     }
 
@@ -176,6 +223,23 @@ impl BodyDesc {
         self.angular_damping = damping;
         self
     }
+
+    /// Set collision groups: `membership` bits say which groups this body
+    /// belongs to, `filter` bits say which groups it interacts with.
+    /// Two bodies collide only when each one's membership intersects the
+    /// other's filter (e.g. projectiles that pass through friendly units).
+    pub fn with_collision_groups(mut self, membership: u32, filter: u32) -> Self {
+        self.collision_group = membership;
+        self.collision_mask = filter;
+        self
+    }
+
+    /// Make the collider a sensor: overlaps are detected (events still fire)
+    /// but no contact forces are generated — bodies pass straight through.
+    pub fn with_sensor(mut self, sensor: bool) -> Self {
+        self.sensor = sensor;
+        self
+    }
 }
 
 /// Handle pair stored on an Entity, referencing Rapier internals.
@@ -204,23 +268,53 @@ pub enum JointDesc {
     },
     /// Allows free rotation around the anchor points (hinge joint in 2D).
     Revolute { anchor_a: Vec2, anchor_b: Vec2 },
+    /// Powered hinge: drives rotation toward `target_velocity` (radians/sec)
+    /// with at most `max_force` of torque (rotating platforms, car wheels).
+    RevoluteMotor {
+        anchor_a: Vec2,
+        anchor_b: Vec2,
+        target_velocity: f32,
+        max_force: f32,
+    },
+}
+
+/// Result of a shape cast: the first entity hit along a swept shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeCastHit {
+    /// The entity whose collider was hit first.
+    pub entity: EntityId,
+    /// Time of impact: the cast shape touches the collider at `from + dir * toi`.
+    pub toi: f32,
+    /// Witness point on the cast shape at the time of impact.
+    pub witness_a: Vec2,
+    /// Witness point on the hit collider at the time of impact.
+    pub witness_b: Vec2,
 }
 
 /// A collision event between two entities.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CollisionPair {
     pub entity_a: EntityId,
     pub entity_b: EntityId,
     /// `true` when the collision just started, `false` when it ended.
     pub started: bool,
+    /// World-space contact point of the deepest contact, for started events.
+    /// `None` for stopped events (no manifold exists) and sensor overlaps.
+    pub contact_point: Option<Vec2>,
+    /// World-space contact normal, paired with `contact_point`.
+    pub normal: Option<Vec2>,
 }
 
 // ---------------------------------------------------------------------------
 // WASM-safe event collector (no crossbeam)
 // ---------------------------------------------------------------------------
 
+/// A raw collision event paired with its deepest contact point and normal
+/// (`None` for stopped events and sensor overlaps).
+type CollisionRecord = (CollisionEvent, Option<(Vec2, Vec2)>);
+
 struct DirectEventCollector {
-    collisions: Mutex<Vec<CollisionEvent>>,
+    collisions: Mutex<Vec<CollisionRecord>>,
 }
 
 impl DirectEventCollector {
@@ -230,7 +324,7 @@ impl DirectEventCollector {
         }
     }
 
-    fn drain_collisions(&self) -> Vec<CollisionEvent> {
+    fn drain_collisions(&self) -> Vec<CollisionRecord> {
         std::mem::take(&mut *self.collisions.lock().unwrap())
     }
 }
@@ -239,11 +333,20 @@ impl EventHandler for DirectEventCollector {
     fn handle_collision_event(
         &self,
         _bodies: &RigidBodySet,
-        _colliders: &ColliderSet,
+        colliders: &ColliderSet,
         event: CollisionEvent,
-        _contact_pair: Option<&ContactPair>,
+        contact_pair: Option<&ContactPair>,
     ) {
-        self.collisions.lock().unwrap().push(event);
+        // Only started contact events carry meaningful contact data; a
+        // stopped event's pair may still hold a stale manifold, so skip it.
+        let contact = contact_pair.filter(|_| event.started()).and_then(|pair| {
+            let (manifold, deepest) = pair.find_deepest_contact()?;
+            let collider = colliders.get(pair.collider1)?;
+            let point = collider.position() * deepest.local_p1;
+            let normal = manifold.data.normal;
+            Some((Vec2::new(point.x, point.y), Vec2::new(normal.x, normal.y)))
+        });
+        self.collisions.lock().unwrap().push((event, contact));
     }
 
     fn handle_contact_force_event(
@@ -277,6 +380,9 @@ pub struct PhysicsWorld {
     ccd_solver: CCDSolver,
     query_pipeline: QueryPipeline,
     event_collector: DirectEventCollector,
+    /// When true, CCD is enabled on every dynamic body created,
+    /// regardless of the per-body `BodyDesc::ccd` flag.
+    ccd_for_dynamic: bool,
 }
 
 impl PhysicsWorld {
@@ -298,6 +404,7 @@ impl PhysicsWorld {
             ccd_solver: CCDSolver::new(),
             query_pipeline: QueryPipeline::new(),
             event_collector: DirectEventCollector::new(),
+            ccd_for_dynamic: false,
         }
     }
 
@@ -306,6 +413,13 @@ impl PhysicsWorld {
         self.integration_parameters.dt = dt;
     }
 
+    /// Enable CCD on every dynamic body created from now on.
+    /// Fast-moving games (pool, projectiles) can set this once via
+    /// `GameConfig::enable_ccd_for_dynamic` instead of per-body `with_ccd`.
+    pub fn set_ccd_for_dynamic(&mut self, enabled: bool) {
+        self.ccd_for_dynamic = enabled;
+    }
+
     /// Create a rigid body + collider and return handles.
     /// The EntityId is stored in the body's `user_data` for collision lookups.
     pub fn create_body(
@@ -314,6 +428,7 @@ impl PhysicsWorld {
         desc: &BodyDesc,
         material: ColliderMaterial,
     ) -> PhysicsBody {
+        let ccd = desc.ccd || (self.ccd_for_dynamic && desc.body_type == BodyType::Dynamic);
         let rb = RigidBodyBuilder::new(desc.body_type.to_rapier())
             .translation(nalgebra::Vector2::new(desc.position.x, desc.position.y))
             .rotation(desc.rotation)
@@ -324,7 +439,7 @@ impl PhysicsWorld {
             } else {
                 LockedAxes::empty()
             })
-            .ccd_enabled(desc.ccd)
+            .ccd_enabled(ccd)
             .linear_damping(desc.linear_damping)
             .angular_damping(desc.angular_damping)
             .user_data(entity_id.0 as u128)
@@ -338,6 +453,11 @@ impl PhysicsWorld {
             .restitution(material.restitution)
             .friction(material.friction)
             .density(material.density)
+            .collision_groups(InteractionGroups::new(
+                Group::from_bits_truncate(desc.collision_group),
+                Group::from_bits_truncate(desc.collision_mask),
+            ))
+            .sensor(desc.sensor)
             .active_events(ActiveEvents::COLLISION_EVENTS)
             .build();
 
@@ -382,7 +502,7 @@ impl PhysicsWorld {
         );
 
         // Drain collision events and resolve entity IDs from user_data
-        for event in self.event_collector.drain_collisions() {
+        for (event, contact) in self.event_collector.drain_collisions() {
             let (h1, h2, started) = match event {
                 CollisionEvent::Started(h1, h2, _) => (h1, h2, true),
                 CollisionEvent::Stopped(h1, h2, _) => (h1, h2, false),
@@ -397,6 +517,8 @@ impl PhysicsWorld {
                     entity_a: a,
                     entity_b: b,
                     started,
+                    contact_point: contact.map(|(point, _)| point),
+                    normal: contact.map(|(_, normal)| normal),
                 });
             }
         }
@@ -416,6 +538,18 @@ impl PhysicsWorld {
         }
     }
 
+    /// Apply an instantaneous impulse at a world-space point. Off-center
+    /// points impart spin as well as linear motion (cue english on a ball).
+    pub fn apply_impulse_at_point(&mut self, body: &PhysicsBody, impulse: Vec2, point: Vec2) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.apply_impulse_at_point(
+                vec2_to_na(impulse),
+                nalgebra::Point2::new(point.x, point.y),
+                true,
+            );
+        }
+    }
+
     /// Set the linear velocity of a body directly.
     pub fn set_velocity(&mut self, body: &PhysicsBody, vel: Vec2) {
         if let Some(rb) = self.bodies.get_mut(body.body_handle) {
@@ -431,6 +565,99 @@ impl PhysicsWorld {
             .unwrap_or(Vec2::ZERO)
     }
 
+    /// Set the angular velocity of a body directly (radians per second).
+    pub fn set_angular_velocity(&mut self, body: &PhysicsBody, omega: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.set_angvel(omega, true);
+        }
+    }
+
+    /// Get the current angular velocity of a body (radians per second).
+    pub fn angular_velocity(&self, body: &PhysicsBody) -> f32 {
+        self.bodies
+            .get(body.body_handle)
+            .map(|rb| rb.angvel())
+            .unwrap_or(0.0)
+    }
+
+    /// Apply an instantaneous torque impulse to a body (spins it).
+    pub fn apply_torque_impulse(&mut self, body: &PhysicsBody, torque: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.apply_torque_impulse(torque, true);
+        }
+    }
+
+    /// Set the linear damping of a live body (velocity decay per second).
+    /// Lets games change friction regimes at runtime (e.g. felt vs pocket).
+    pub fn set_linear_damping(&mut self, body: &PhysicsBody, damping: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.set_linear_damping(damping);
+        }
+    }
+
+    /// Set the angular damping of a live body (rotation decay per second).
+    pub fn set_angular_damping(&mut self, body: &PhysicsBody, damping: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.set_angular_damping(damping);
+        }
+    }
+
+    /// The total mass of a body (collider density plus any additional mass).
+    pub fn mass(&self, body: &PhysicsBody) -> f32 {
+        self.bodies
+            .get(body.body_handle)
+            .map(|rb| rb.mass())
+            .unwrap_or(0.0)
+    }
+
+    /// The world-space center of mass of a body.
+    pub fn center_of_mass(&self, body: &PhysicsBody) -> Vec2 {
+        self.bodies
+            .get(body.body_handle)
+            .map(|rb| Vec2::new(rb.center_of_mass().x, rb.center_of_mass().y))
+            .unwrap_or(Vec2::ZERO)
+    }
+
+    /// Add mass on top of the collider's density-derived mass.
+    /// A direct override is often cleaner than tuning
+    /// `ColliderMaterial::density` to hit a target mass.
+    pub fn set_additional_mass(&mut self, body: &PhysicsBody, mass: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.set_additional_mass(mass, true);
+        }
+    }
+
+    // -- Sleeping --
+
+    /// Allow or forbid a body from being put to sleep by the island solver.
+    /// Disabling also wakes the body if it was already asleep.
+    pub fn set_sleeping_enabled(&mut self, body: &PhysicsBody, enabled: bool) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            if enabled {
+                *rb.activation_mut() = RigidBodyActivation::default();
+            } else {
+                *rb.activation_mut() = RigidBodyActivation::cannot_sleep();
+                rb.wake_up(true);
+            }
+        }
+    }
+
+    /// Wake a sleeping body so it simulates again next step.
+    pub fn wake(&mut self, body: &PhysicsBody) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.wake_up(true);
+        }
+    }
+
+    /// Whether the island solver has put this body to sleep.
+    /// Cheaper than polling velocities for "everything stopped" checks.
+    pub fn is_sleeping(&self, body: &PhysicsBody) -> bool {
+        self.bodies
+            .get(body.body_handle)
+            .map(|rb| rb.is_sleeping())
+            .unwrap_or(false)
+    }
+
     /// Set position and rotation for a kinematic body.
     pub fn set_kinematic_position(&mut self, body: &PhysicsBody, pos: Vec2, rotation: f32) {
         if let Some(rb) = self.bodies.get_mut(body.body_handle) {
@@ -441,6 +668,17 @@ impl PhysicsWorld {
         }
     }
 
+    /// Set the velocity of a `KinematicVelocityBased` body (moving
+    /// platforms). The solver integrates the motion; dynamic bodies riding
+    /// the platform get pushed correctly, unlike teleporting via
+    /// [`set_kinematic_position`](Self::set_kinematic_position).
+    pub fn set_kinematic_velocity(&mut self, body: &PhysicsBody, linvel: Vec2, angvel: f32) {
+        if let Some(rb) = self.bodies.get_mut(body.body_handle) {
+            rb.set_linvel(vec2_to_na(linvel), true);
+            rb.set_angvel(angvel, true);
+        }
+    }
+
     /// Get the current position and rotation of a body.
     pub fn body_position(&self, body: &PhysicsBody) -> (Vec2, f32) {
         self.bodies
@@ -467,15 +705,119 @@ impl PhysicsWorld {
                 half_height: cuboid.half_extents.y,
             })
         } else if let Some(capsule) = shape.as_capsule() {
-            Some(ColliderDesc::CapsuleY {
-                half_height: capsule.half_height(),
-                radius: capsule.radius,
+            // Distinguish orientation from the internal segment direction
+            let seg = capsule.segment;
+            if (seg.b.x - seg.a.x).abs() > (seg.b.y - seg.a.y).abs() {
+                Some(ColliderDesc::CapsuleX {
+                    half_width: capsule.half_height(),
+                    radius: capsule.radius,
+                })
+            } else {
+                Some(ColliderDesc::CapsuleY {
+                    half_height: capsule.half_height(),
+                    radius: capsule.radius,
+                })
+            }
+        } else if let Some(polyline) = shape.as_polyline() {
+            Some(ColliderDesc::Polyline {
+                vertices: polyline
+                    .vertices()
+                    .iter()
+                    .map(|p| Vec2::new(p.x, p.y))
+                    .collect(),
             })
         } else {
-            None
+            shape.as_convex_polygon().map(|hull| ColliderDesc::ConvexHull {
+                points: hull.points().iter().map(|p| Vec2::new(p.x, p.y)).collect(),
+            })
         }
     }
 
+    // -- Spatial queries --
+
+    /// Sweep a shape from `from` along `dir` and return the first entity hit.
+    ///
+    /// `dir` is a velocity: the hit occurs at `from + dir * toi`, with
+    /// `toi <= max_toi`. Replaces hand-rolled tunneling checks for fast
+    /// movers (e.g. a pool ball heading for a pocket).
+    ///
+    /// Queries reflect the world as of the last `step_into` call.
+    pub fn cast_shape(
+        &self,
+        shape: &ColliderDesc,
+        from: Vec2,
+        dir: Vec2,
+        max_toi: f32,
+    ) -> Option<ShapeCastHit> {
+        let collider = shape.build_collider().build();
+        let shape_pos = nalgebra::Isometry2::translation(from.x, from.y);
+        let vel = vec2_to_na(dir);
+        let (handle, hit) = self.query_pipeline.cast_shape(
+            &self.bodies,
+            &self.colliders,
+            &shape_pos,
+            &vel,
+            collider.shape(),
+            rapier2d::parry::query::ShapeCastOptions::with_max_time_of_impact(max_toi),
+            QueryFilter::default(),
+        )?;
+        let entity = self.collider_to_entity(handle)?;
+        Some(ShapeCastHit {
+            entity,
+            toi: hit.time_of_impact,
+            witness_a: Vec2::new(hit.witness1.x, hit.witness1.y),
+            witness_b: Vec2::new(hit.witness2.x, hit.witness2.y),
+        })
+    }
+
+    /// Find the entity whose collider contains the given world-space point.
+    /// Returns the first match when colliders overlap. Replaces bespoke
+    /// click-to-select distance checks for games with physics bodies.
+    ///
+    /// Queries reflect the world as of the last `step_into` call.
+    pub fn point_query(&self, point: Vec2) -> Option<EntityId> {
+        let mut found = None;
+        self.query_pipeline.intersections_with_point(
+            &self.bodies,
+            &self.colliders,
+            &nalgebra::Point2::new(point.x, point.y),
+            QueryFilter::default(),
+            |handle| {
+                found = self.collider_to_entity(handle);
+                // Stop at the first resolvable entity
+                found.is_none()
+            },
+        );
+        found
+    }
+
+    /// Collect all entities whose colliders overlap a ball at `center`.
+    /// Each entity appears once even if it has multiple overlapping colliders.
+    /// Useful for area-of-effect queries (explosions, auras).
+    ///
+    /// Queries reflect the world as of the last `step_into` call.
+    pub fn intersect_ball(&self, center: Vec2, radius: f32) -> Vec<EntityId> {
+        let shape = rapier2d::parry::shape::Ball::new(radius);
+        let shape_pos = nalgebra::Isometry2::translation(center.x, center.y);
+        let mut entities: Vec<EntityId> = Vec::new();
+        self.query_pipeline.intersections_with_shape(
+            &self.bodies,
+            &self.colliders,
+            &shape_pos,
+            &shape,
+            QueryFilter::default(),
+            |handle| {
+                if let Some(entity) = self.collider_to_entity(handle) {
+                    if !entities.contains(&entity) {
+                        entities.push(entity);
+                    }
+                }
+                true
+            },
+        );
+        entities
+    }
+
     // -- Joint methods --
 
     /// Create a joint between two bodies. Returns a handle for later removal.
@@ -507,6 +849,14 @@ impl PhysicsWorld {
                     .build();
                 self.impulse_joints.insert(body_a.body_handle, body_b.body_handle, joint, true)
             }
+            JointDesc::RevoluteMotor { anchor_a, anchor_b, target_velocity, max_force } => {
+                let joint = RevoluteJointBuilder::new()
+                    .local_anchor1(nalgebra::Point2::new(anchor_a.x, anchor_a.y))
+                    .local_anchor2(nalgebra::Point2::new(anchor_b.x, anchor_b.y))
+                    .motor_velocity(*target_velocity, *max_force)
+                    .build();
+                self.impulse_joints.insert(body_a.body_handle, body_b.body_handle, joint, true)
+            }
         };
         JointHandle(handle)
     }
@@ -599,6 +949,45 @@ mod tests {
         assert!(vel.x > 0.0, "Velocity should be positive X: {:?}", vel);
     }
 
+    #[test]
+    fn kinematic_velocity_moves_by_velocity_times_dt() {
+        let mut world = PhysicsWorld::new(Vec2::new(0.0, 200.0));
+        let mut desc = BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 });
+        desc.body_type = BodyType::KinematicVelocityBased;
+        let body = world.create_body(EntityId(1), &desc, ColliderMaterial::default());
+
+        world.set_kinematic_velocity(&body, Vec2::new(60.0, -30.0), 0.5);
+        let dt = 1.0 / 60.0;
+        world.set_dt(dt);
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+
+        // Kinematic bodies ignore gravity and move exactly velocity × dt
+        let (pos, rot) = world.body_position(&body);
+        assert!((pos.x - 60.0 * dt).abs() < 1e-4);
+        assert!((pos.y - -30.0 * dt).abs() < 1e-4);
+        assert!((rot - 0.5 * dt).abs() < 1e-4);
+    }
+
+    #[test]
+    fn off_center_impulse_imparts_spin() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+
+        // Hitting through the center of mass: no spin
+        world.apply_impulse_at_point(&body, Vec2::new(100.0, 0.0), Vec2::ZERO);
+        assert!(world.angular_velocity(&body).abs() < 1e-6);
+        assert!(world.velocity(&body).x > 0.0);
+
+        // Hitting above center: same direction, now with spin
+        world.apply_impulse_at_point(&body, Vec2::new(100.0, 0.0), Vec2::new(0.0, 3.0));
+        assert!(world.angular_velocity(&body).abs() > 1e-3);
+    }
+
     #[test]
     fn set_velocity_directly() {
         let mut world = PhysicsWorld::new(Vec2::ZERO);
@@ -700,6 +1089,38 @@ mod tests {
         assert!(desc.ccd);
     }
 
+    #[test]
+    fn ccd_for_dynamic_applies_without_explicit_flag() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_ccd_for_dynamic(true);
+
+        let dynamic = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+        let fixed = world.create_body(
+            EntityId(2),
+            &BodyDesc::fixed(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+
+        assert!(world.bodies.get(dynamic.body_handle).unwrap().is_ccd_enabled());
+        // Only dynamic bodies are affected by the blanket flag
+        assert!(!world.bodies.get(fixed.body_handle).unwrap().is_ccd_enabled());
+    }
+
+    #[test]
+    fn ccd_disabled_by_default() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+        assert!(!world.bodies.get(body.body_handle).unwrap().is_ccd_enabled());
+    }
+
     #[test]
     fn collider_material_defaults() {
         let mat = ColliderMaterial::default();
@@ -758,6 +1179,495 @@ mod tests {
         }
     }
 
+    #[test]
+    fn additional_mass_changes_reported_mass() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+
+        let base_mass = world.mass(&body);
+        assert!(base_mass > 0.0, "density-derived mass should be positive");
+
+        world.set_additional_mass(&body, 100.0);
+        // Mass properties are recomputed during the next step
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+        let new_mass = world.mass(&body);
+        assert!(
+            (new_mass - (base_mass + 100.0)).abs() < 0.01,
+            "additional mass should stack: base={}, new={}",
+            base_mass,
+            new_mass
+        );
+
+        // Center of mass of a lone ball is its position
+        assert!(world.center_of_mass(&body).length() < 0.001);
+    }
+
+    #[test]
+    fn runtime_damping_slows_body_faster() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let damped = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_velocity(Vec2::new(100.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+        let free = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_position(Vec2::new(0.0, 500.0))
+                .with_velocity(Vec2::new(100.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+
+        world.set_linear_damping(&damped, 5.0);
+
+        let mut events = Vec::new();
+        for _ in 0..30 {
+            world.step_into(&mut events);
+        }
+
+        let damped_speed = world.velocity(&damped).length();
+        let free_speed = world.velocity(&free).length();
+        assert!(
+            damped_speed < free_speed * 0.5,
+            "damped body should slow much faster: damped={}, free={}",
+            damped_speed,
+            free_speed
+        );
+    }
+
+    #[test]
+    fn capsule_x_and_round_cuboid_build_and_report() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let capsule = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::CapsuleX {
+                half_width: 30.0,
+                radius: 8.0,
+            }),
+            ColliderMaterial::default(),
+        );
+        let round = world.create_body(
+            EntityId(2),
+            &BodyDesc::fixed(ColliderDesc::RoundCuboid {
+                half_width: 40.0,
+                half_height: 10.0,
+                border_radius: 4.0,
+            }),
+            ColliderMaterial::default(),
+        );
+        assert_eq!(world.body_count(), 2);
+
+        let shape = world.collider_shape(&capsule).expect("capsule-x should report");
+        match shape {
+            ColliderDesc::CapsuleX { half_width, radius } => {
+                assert!((half_width - 30.0).abs() < 0.001);
+                assert!((radius - 8.0).abs() < 0.001);
+            }
+            _ => panic!("expected CapsuleX, got {:?}", shape),
+        }
+
+        // Round cuboids aren't reconstructed — collider_shape returns None
+        assert!(world.collider_shape(&round).is_none());
+    }
+
+    #[test]
+    fn intersect_ball_collects_entities_in_radius() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        for (i, x) in [0.0f32, 40.0, 300.0].iter().enumerate() {
+            world.create_body(
+                EntityId(i as u32 + 1),
+                &BodyDesc::fixed(ColliderDesc::Ball { radius: 10.0 })
+                    .with_position(Vec2::new(*x, 0.0)),
+                ColliderMaterial::default(),
+            );
+        }
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+
+        let mut hit = world.intersect_ball(Vec2::ZERO, 60.0);
+        hit.sort_by_key(|id| id.0);
+        assert_eq!(hit, vec![EntityId(1), EntityId(2)]);
+
+        assert!(world.intersect_ball(Vec2::new(-500.0, 0.0), 20.0).is_empty());
+    }
+
+    #[test]
+    fn point_query_finds_containing_collider() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let _a = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::Cuboid {
+                half_width: 20.0,
+                half_height: 20.0,
+            }),
+            ColliderMaterial::default(),
+        );
+        let _b = world.create_body(
+            EntityId(2),
+            &BodyDesc::fixed(ColliderDesc::Cuboid {
+                half_width: 20.0,
+                half_height: 20.0,
+            })
+            .with_position(Vec2::new(100.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+
+        assert_eq!(world.point_query(Vec2::new(105.0, 5.0)), Some(EntityId(2)));
+        assert_eq!(world.point_query(Vec2::new(0.0, 0.0)), Some(EntityId(1)));
+        assert_eq!(world.point_query(Vec2::new(500.0, 500.0)), None);
+    }
+
+    #[test]
+    fn shape_cast_hits_wall_before_travel_distance() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let _wall = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::Cuboid {
+                half_width: 10.0,
+                half_height: 100.0,
+            })
+            .with_position(Vec2::new(200.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+        // Build the query pipeline acceleration structure
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+
+        // Unit-speed sweep: toi equals distance traveled
+        let hit = world
+            .cast_shape(
+                &ColliderDesc::Ball { radius: 10.0 },
+                Vec2::ZERO,
+                Vec2::new(1.0, 0.0),
+                400.0,
+            )
+            .expect("sweep should hit the wall");
+
+        assert_eq!(hit.entity, EntityId(1));
+        // Ball surface meets the wall face at x=190, so centers travel 180
+        assert!(hit.toi > 0.0, "toi should be nonzero: {}", hit.toi);
+        assert!((hit.toi - 180.0).abs() < 1.0, "toi should be ~180: {}", hit.toi);
+
+        // A sweep pointing away finds nothing
+        assert!(world
+            .cast_shape(
+                &ColliderDesc::Ball { radius: 10.0 },
+                Vec2::ZERO,
+                Vec2::new(-1.0, 0.0),
+                400.0,
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn motionless_body_falls_asleep_and_wakes() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+
+        assert!(!world.is_sleeping(&body));
+        let mut events = Vec::new();
+        // Rapier's default time-until-sleep is 2s; give it 5s to be safe
+        for _ in 0..300 {
+            world.step_into(&mut events);
+        }
+        assert!(world.is_sleeping(&body), "motionless body should sleep");
+
+        world.wake(&body);
+        assert!(!world.is_sleeping(&body), "wake() should reactivate the body");
+    }
+
+    #[test]
+    fn sleeping_can_be_disabled_per_body() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+        world.set_sleeping_enabled(&body, false);
+
+        let mut events = Vec::new();
+        for _ in 0..120 {
+            world.step_into(&mut events);
+        }
+        assert!(!world.is_sleeping(&body), "body with sleeping disabled must stay awake");
+    }
+
+    #[test]
+    fn angular_velocity_round_trips() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 }),
+            ColliderMaterial::default(),
+        );
+
+        assert_eq!(world.angular_velocity(&body), 0.0);
+        world.set_angular_velocity(&body, 3.5);
+        assert!((world.angular_velocity(&body) - 3.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn torque_impulse_spins_body() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Cuboid {
+                half_width: 20.0,
+                half_height: 5.0,
+            }),
+            ColliderMaterial::default(),
+        );
+
+        world.apply_torque_impulse(&body, 50000.0);
+        let mut events = Vec::new();
+        world.step_into(&mut events);
+
+        assert!(
+            world.angular_velocity(&body) > 0.0,
+            "torque impulse should spin the body: omega={}",
+            world.angular_velocity(&body)
+        );
+    }
+
+    #[test]
+    fn contact_point_lies_between_converging_balls() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let a = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 10.0 })
+                .with_position(Vec2::new(0.0, 0.0))
+                .with_velocity(Vec2::new(200.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+        let b = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 10.0 })
+                .with_position(Vec2::new(50.0, 0.0))
+                .with_velocity(Vec2::new(-200.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+
+        let mut events = Vec::new();
+        let mut contact = None;
+        for _ in 0..60 {
+            events.clear();
+            world.step_into(&mut events);
+            if let Some(pair) = events.iter().find(|e| e.started) {
+                contact = Some((pair.contact_point, pair.normal, world.body_position(&a).0, world.body_position(&b).0));
+                break;
+            }
+        }
+
+        let (point, normal, pos_a, pos_b) = contact.expect("balls should collide");
+        let point = point.expect("started contact should carry a contact point");
+        let normal = normal.expect("started contact should carry a normal");
+
+        // Contact point sits between the two centers (head-on along X)
+        assert!(point.x > pos_a.x && point.x < pos_b.x,
+            "point {} should be between centers {} and {}", point.x, pos_a.x, pos_b.x);
+        assert!(point.y.abs() < 1.0, "head-on contact stays on the X axis: {:?}", point);
+        // Normal is unit-length along the collision axis
+        assert!((normal.length() - 1.0).abs() < 0.001);
+        assert!(normal.x.abs() > 0.99, "normal should point along X: {:?}", normal);
+    }
+
+    #[test]
+    fn stopped_contact_has_no_contact_point() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let _a = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_velocity(Vec2::new(300.0, 0.0)),
+            ColliderMaterial { restitution: 0.9, ..Default::default() },
+        );
+        let _b = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_position(Vec2::new(40.0, 0.0)),
+            ColliderMaterial { restitution: 0.9, ..Default::default() },
+        );
+
+        let mut events = Vec::new();
+        for _ in 0..120 {
+            world.step_into(&mut events);
+        }
+
+        let stopped = events.iter().find(|e| !e.started).expect("contact should end");
+        assert!(stopped.contact_point.is_none());
+        assert!(stopped.normal.is_none());
+    }
+
+    #[test]
+    fn sensor_fires_events_without_blocking() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        // Static trigger zone in the ball's path
+        let _zone = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::Cuboid {
+                half_width: 10.0,
+                half_height: 50.0,
+            })
+            .with_position(Vec2::new(100.0, 0.0))
+            .with_sensor(true),
+            ColliderMaterial::default(),
+        );
+        let ball = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_velocity(Vec2::new(300.0, 0.0)),
+            ColliderMaterial::default(),
+        );
+
+        let mut events = Vec::new();
+        for _ in 0..120 {
+            world.step_into(&mut events);
+        }
+
+        assert!(
+            events.iter().any(|e| e.started),
+            "sensor overlap should produce a start event"
+        );
+        assert!(
+            events.iter().any(|e| !e.started),
+            "leaving the sensor should produce a stop event"
+        );
+        // The ball passed straight through the zone
+        let (pos, _) = world.body_position(&ball);
+        assert!(pos.x > 110.0, "ball should not be blocked: x={}", pos.x);
+    }
+
+    #[test]
+    fn collision_groups_filter_contacts() {
+        const PROJECTILES: u32 = 1 << 0;
+        const FRIENDLIES: u32 = 1 << 1;
+        const ENEMIES: u32 = 1 << 2;
+
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        // Projectile flying right, ignoring friendly units
+        let _projectile = world.create_body(
+            EntityId(1),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 5.0 })
+                .with_velocity(Vec2::new(300.0, 0.0))
+                .with_collision_groups(PROJECTILES, ENEMIES),
+            ColliderMaterial::default(),
+        );
+        let _friendly = world.create_body(
+            EntityId(2),
+            &BodyDesc::fixed(ColliderDesc::Ball { radius: 10.0 })
+                .with_position(Vec2::new(60.0, 0.0))
+                .with_collision_groups(FRIENDLIES, u32::MAX),
+            ColliderMaterial::default(),
+        );
+        let _enemy = world.create_body(
+            EntityId(3),
+            &BodyDesc::fixed(ColliderDesc::Ball { radius: 10.0 })
+                .with_position(Vec2::new(140.0, 0.0))
+                .with_collision_groups(ENEMIES, u32::MAX),
+            ColliderMaterial::default(),
+        );
+
+        let mut events = Vec::new();
+        for _ in 0..60 {
+            world.step_into(&mut events);
+        }
+
+        let started: Vec<_> = events.iter().filter(|e| e.started).collect();
+        assert_eq!(started.len(), 1, "only the enemy contact should fire: {:?}", started);
+        let ids = [started[0].entity_a, started[0].entity_b];
+        assert!(ids.contains(&EntityId(1)));
+        assert!(ids.contains(&EntityId(3)));
+    }
+
+    #[test]
+    fn ball_rests_inside_polyline_triangle() {
+        let mut world = PhysicsWorld::new(Vec2::new(0.0, 200.0));
+        world.set_dt(1.0 / 60.0);
+
+        // V-shaped container: walls sloping down to a vertex at (0, 60)
+        let _walls = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::Polyline {
+                vertices: vec![
+                    Vec2::new(-60.0, 0.0),
+                    Vec2::new(0.0, 60.0),
+                    Vec2::new(60.0, 0.0),
+                ],
+            }),
+            ColliderMaterial::default(),
+        );
+        let ball = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Ball { radius: 10.0 })
+                .with_position(Vec2::new(5.0, -40.0)),
+            ColliderMaterial { restitution: 0.0, ..Default::default() },
+        );
+
+        let mut events = Vec::new();
+        for _ in 0..300 {
+            world.step_into(&mut events);
+        }
+
+        let (pos, _) = world.body_position(&ball);
+        assert!(pos.y > 0.0, "Ball should fall into the V: y={}", pos.y);
+        assert!(pos.y < 60.0, "Ball should be held above the vertex: y={}", pos.y);
+        let vel = world.velocity(&ball);
+        assert!(vel.length() < 5.0, "Ball should come to rest: vel={:?}", vel);
+    }
+
+    #[test]
+    fn convex_hull_collider_round_trips_shape() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        let body = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::ConvexHull {
+                points: vec![
+                    Vec2::new(-20.0, -10.0),
+                    Vec2::new(20.0, -10.0),
+                    Vec2::new(0.0, 25.0),
+                ],
+            }),
+            ColliderMaterial::default(),
+        );
+
+        let shape = world.collider_shape(&body).expect("hull should have shape");
+        match shape {
+            ColliderDesc::ConvexHull { points } => assert_eq!(points.len(), 3),
+            _ => panic!("expected ConvexHull, got {:?}", shape),
+        }
+    }
+
     #[test]
     fn create_and_remove_joint() {
         let mut world = PhysicsWorld::new(Vec2::ZERO);
@@ -907,6 +1817,45 @@ mod tests {
         assert!(pos_b.y > 105.0, "Body B should swing down: y={}", pos_b.y);
     }
 
+    #[test]
+    fn revolute_motor_spins_toward_target_velocity() {
+        let mut world = PhysicsWorld::new(Vec2::ZERO);
+        world.set_dt(1.0 / 60.0);
+
+        let anchor = world.create_body(
+            EntityId(1),
+            &BodyDesc::fixed(ColliderDesc::Ball { radius: 2.0 }),
+            ColliderMaterial::default(),
+        );
+        let platform = world.create_body(
+            EntityId(2),
+            &BodyDesc::dynamic(ColliderDesc::Cuboid {
+                half_width: 30.0,
+                half_height: 5.0,
+            }),
+            ColliderMaterial::default(),
+        );
+
+        world.create_joint(&anchor, &platform, &JointDesc::RevoluteMotor {
+            anchor_a: Vec2::ZERO,
+            anchor_b: Vec2::ZERO,
+            target_velocity: 4.0,
+            max_force: 1.0e8,
+        });
+
+        let mut events = Vec::new();
+        for _ in 0..60 {
+            world.step_into(&mut events);
+        }
+
+        let omega = world.angular_velocity(&platform);
+        assert!(
+            omega > 2.0,
+            "motor should spin the platform toward 4 rad/s: omega={}",
+            omega
+        );
+    }
+
     #[test]
     fn multiple_bodies_independent() {
         let mut world = PhysicsWorld::new(Vec2::ZERO);
@@ -1,5 +1,6 @@
 use crate::api::types::EntityId;
 use crate::components::entity::Entity;
+use glam::Vec2;
 use std::collections::HashMap;
 
 /// Entity storage with O(1) ID lookups via HashMap index.
@@ -9,6 +10,13 @@ pub struct Scene {
     entities: Vec<Entity>,
     /// Maps EntityId → index in entities Vec for O(1) lookup
     id_index: HashMap<EntityId, usize>,
+    /// Maps tag → EntityIds carrying it, so tag lookups avoid full scans.
+    /// Kept in sync on spawn/despawn/clear; tags reassigned through a
+    /// mutable entity reference after spawn are not re-indexed.
+    tag_index: HashMap<String, Vec<EntityId>>,
+    /// Currently selected entities, in selection order.
+    /// Drives highlights/selection rings and UI uniformly across games.
+    selection: Vec<EntityId>,
 }
 
 impl Scene {
@@ -16,6 +24,8 @@ impl Scene {
         Self {
             entities: Vec::with_capacity(256),
             id_index: HashMap::with_capacity(256),
+            tag_index: HashMap::new(),
+            selection: Vec::new(),
         }
     }
 
@@ -24,27 +34,41 @@ impl Scene {
         Self {
             entities: Vec::with_capacity(capacity),
             id_index: HashMap::with_capacity(capacity),
+            tag_index: HashMap::new(),
+            selection: Vec::new(),
         }
     }
 
-    /// Add an entity to the scene.
+    /// Add an entity to the scene. Entities are kept sorted by ascending
+    /// EntityId, so iteration order is deterministic regardless of spawn
+    /// order. Spawning with monotonically increasing IDs (the `next_id`
+    /// pattern) appends in O(1); out-of-order IDs shift later entries.
     pub fn spawn(&mut self, entity: Entity) {
         let id = entity.id;
-        let idx = self.entities.len();
-        self.entities.push(entity);
-        self.id_index.insert(id, idx);
+        let idx = self.entities.partition_point(|e| e.id < id);
+        self.tag_index.entry(entity.tag.clone()).or_default().push(id);
+        self.entities.insert(idx, entity);
+        for i in idx..self.entities.len() {
+            self.id_index.insert(self.entities[i].id, i);
+        }
     }
 
     /// Remove an entity by ID. Returns the removed entity if found.
-    /// Uses swap_remove for O(1) removal, which changes the order of entities.
+    /// Preserves the ascending-ID order of the remaining entities.
     pub fn despawn(&mut self, id: EntityId) -> Option<Entity> {
         if let Some(&idx) = self.id_index.get(&id) {
             self.id_index.remove(&id);
-            let removed = self.entities.swap_remove(idx);
-            // Update the index of the entity that was swapped into this position
-            if idx < self.entities.len() {
-                let swapped_id = self.entities[idx].id;
-                self.id_index.insert(swapped_id, idx);
+            self.deselect(id);
+            let removed = self.entities.remove(idx);
+            // Re-index the entities shifted down by the removal
+            for i in idx..self.entities.len() {
+                self.id_index.insert(self.entities[i].id, i);
+            }
+            if let Some(ids) = self.tag_index.get_mut(&removed.tag) {
+                ids.retain(|&tagged| tagged != id);
+                if ids.is_empty() {
+                    self.tag_index.remove(&removed.tag);
+                }
             }
             Some(removed)
         } else {
@@ -66,29 +90,89 @@ impl Scene {
         }
     }
 
-    /// Iterate over all entities.
+    /// Iterate over all entities in ascending EntityId order.
+    ///
+    /// The ordering is deterministic across runs and stable across spawns
+    /// and despawns — rendering and logic that depend on iteration order
+    /// behave identically every frame.
     pub fn iter(&self) -> impl Iterator<Item = &Entity> {
         self.entities.iter()
     }
 
-    /// Iterate over all entities mutably.
+    /// Enumerate all entities as `(EntityId, &Entity)` pairs, in ascending
+    /// EntityId order (see [`Scene::iter`]).
+    pub fn entities(&self) -> impl Iterator<Item = (EntityId, &Entity)> {
+        self.entities.iter().map(|e| (e.id, e))
+    }
+
+    /// Iterate over all entities mutably, in ascending EntityId order.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Entity> {
         self.entities.iter_mut()
     }
 
+    /// Iterate over all entities with the given tag.
+    /// O(1) + matches via the tag index — no full scene scan.
+    pub fn find_by_tag(&self, tag: &str) -> impl Iterator<Item = &Entity> {
+        self.tag_index
+            .get(tag)
+            .map(|ids| ids.as_slice())
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|&id| self.get(id))
+    }
+
     /// Find the first entity with the given tag.
-    pub fn find_by_tag(&self, tag: &str) -> Option<&Entity> {
-        self.entities.iter().find(|e| e.tag == tag)
+    pub fn first_by_tag(&self, tag: &str) -> Option<&Entity> {
+        self.find_by_tag(tag).next()
     }
 
     /// Find the first entity with the given tag (mutable).
-    pub fn find_by_tag_mut(&mut self, tag: &str) -> Option<&mut Entity> {
-        self.entities.iter_mut().find(|e| e.tag == tag)
+    pub fn first_by_tag_mut(&mut self, tag: &str) -> Option<&mut Entity> {
+        let id = *self.tag_index.get(tag)?.first()?;
+        self.get_mut(id)
     }
 
     /// Find all entities with the given tag.
     pub fn find_all_by_tag(&self, tag: &str) -> Vec<&Entity> {
-        self.entities.iter().filter(|e| e.tag == tag).collect()
+        self.find_by_tag(tag).collect()
+    }
+
+    /// Collect the IDs of all entities whose AABB (position ± scale/2)
+    /// overlaps the given world-space rect. Culling/picking primitive for
+    /// games without the `physics` feature — a linear scan, which beats a
+    /// spatial index for the scene sizes this engine targets (positions
+    /// change every frame, so any grid would be rebuilt per query anyway).
+    pub fn entities_in_rect(&self, min: Vec2, max: Vec2) -> Vec<EntityId> {
+        self.find(|e| {
+            let half = e.scale / 2.0;
+            e.pos.x + half.x >= min.x
+                && e.pos.x - half.x <= max.x
+                && e.pos.y + half.y >= min.y
+                && e.pos.y - half.y <= max.y
+        })
+    }
+
+    /// Collect the IDs of all entities matching a predicate.
+    ///
+    /// Expresses scans like "all entities below y=500" without manual loops.
+    pub fn find<F>(&self, mut f: F) -> Vec<EntityId>
+    where
+        F: FnMut(&Entity) -> bool,
+    {
+        self.entities
+            .iter()
+            .filter(|e| f(e))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    /// Whether any entity matches the predicate ("any enemy alive").
+    /// Short-circuits on the first match.
+    pub fn any<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&Entity) -> bool,
+    {
+        self.entities.iter().any(f)
     }
 
     /// Retain only entities matching the predicate. Preserves order.
@@ -111,9 +195,17 @@ impl Scene {
     /// Called after operations that invalidate indices (retain, etc).
     fn rebuild_index(&mut self) {
         self.id_index.clear();
+        self.tag_index.clear();
         for (idx, entity) in self.entities.iter().enumerate() {
             self.id_index.insert(entity.id, idx);
+            self.tag_index
+                .entry(entity.tag.clone())
+                .or_default()
+                .push(entity.id);
         }
+        // Drop selection entries for entities that no longer exist
+        let id_index = &self.id_index;
+        self.selection.retain(|id| id_index.contains_key(id));
     }
 
     /// Number of entities in the scene.
@@ -130,12 +222,83 @@ impl Scene {
     pub fn clear(&mut self) {
         self.entities.clear();
         self.id_index.clear();
+        self.tag_index.clear();
+        self.selection.clear();
+    }
+
+    // -- Selection --
+
+    /// Add an entity to the selection set. No-op if the entity does not
+    /// exist or is already selected.
+    pub fn select(&mut self, id: EntityId) {
+        if self.contains(id) && !self.is_selected(id) {
+            self.selection.push(id);
+        }
+    }
+
+    /// Remove an entity from the selection set.
+    pub fn deselect(&mut self, id: EntityId) {
+        self.selection.retain(|&s| s != id);
+    }
+
+    /// Clear the selection set.
+    pub fn deselect_all(&mut self) {
+        self.selection.clear();
+    }
+
+    /// Whether an entity is currently selected.
+    pub fn is_selected(&self, id: EntityId) -> bool {
+        self.selection.contains(&id)
+    }
+
+    /// The selected entities, in selection order.
+    /// Despawned entities are removed from the selection automatically.
+    pub fn selected(&self) -> &[EntityId] {
+        &self.selection
     }
 
     /// Check if an entity with the given ID exists. O(1).
     pub fn contains(&self, id: EntityId) -> bool {
         self.id_index.contains_key(&id)
     }
+
+    /// World-space AABB enclosing all entities (position ± scale/2).
+    /// Returns (min, max), or None if the scene is empty.
+    /// Useful for auto-framing a camera around the whole scene.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        let mut iter = self.entities.iter();
+        let first = iter.next()?;
+        let mut min = first.pos - first.scale / 2.0;
+        let mut max = first.pos + first.scale / 2.0;
+        for e in iter {
+            min = min.min(e.pos - e.scale / 2.0);
+            max = max.max(e.pos + e.scale / 2.0);
+        }
+        Some((min, max))
+    }
+}
+
+// -- Serialization (level editors, replays) --
+
+#[cfg(feature = "serde")]
+impl Scene {
+    /// Serialize all entities to a JSON string. Physics bodies are skipped
+    /// (Rapier handles are runtime-only) — respawn them after loading.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.entities)
+            .expect("Entity serialization is infallible")
+    }
+
+    /// Rebuild a scene from a JSON string produced by [`Scene::to_json`].
+    /// Indexes are reconstructed on spawn; selection is not persisted.
+    pub fn from_json(json: &str) -> Result<Scene, String> {
+        let entities: Vec<Entity> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let mut scene = Scene::with_capacity(entities.len());
+        for entity in entities {
+            scene.spawn(entity);
+        }
+        Ok(scene)
+    }
 }
 
 impl Default for Scene {
@@ -169,11 +332,219 @@ mod tests {
     }
 
     #[test]
-    fn find_by_tag() {
+    fn find_returns_matching_subset() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_pos(Vec2::new(0.0, 100.0)));
+        scene.spawn(Entity::new(EntityId(2)).with_pos(Vec2::new(0.0, 600.0)));
+        scene.spawn(Entity::new(EntityId(3)).with_pos(Vec2::new(0.0, 550.0)));
+
+        let below = scene.find(|e| e.pos.y > 500.0);
+        assert_eq!(below, vec![EntityId(2), EntityId(3)]);
+
+        assert!(scene.any(|e| e.pos.y > 500.0));
+        assert!(!scene.any(|e| e.pos.y < 0.0));
+    }
+
+    #[test]
+    fn entities_enumerate_in_ascending_id_order() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(3)));
+        scene.spawn(Entity::new(EntityId(1)));
+        scene.spawn(Entity::new(EntityId(2)));
+
+        // Out-of-order spawns still iterate in ascending-ID order
+        let ids: Vec<EntityId> = scene.entities().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec![EntityId(1), EntityId(2), EntityId(3)]);
+
+        // Repeated calls and mutable iteration must not perturb the ordering
+        for e in scene.iter_mut() {
+            e.pos += Vec2::ONE;
+        }
+        let ids_after: Vec<EntityId> = scene.entities().map(|(id, _)| id).collect();
+        assert_eq!(ids, ids_after);
+        assert_eq!(scene.entities().count(), scene.len());
+    }
+
+    #[test]
+    fn despawn_preserves_ascending_id_order() {
+        let mut scene = Scene::new();
+        for id in [5, 2, 9, 1, 7] {
+            scene.spawn(Entity::new(EntityId(id)));
+        }
+        scene.despawn(EntityId(2));
+
+        let ids: Vec<EntityId> = scene.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![EntityId(1), EntityId(5), EntityId(7), EntityId(9)]);
+        // Index lookups still resolve after the shift
+        assert_eq!(scene.get(EntityId(9)).unwrap().id, EntityId(9));
+    }
+
+    #[test]
+    fn bounds_enclose_extreme_entities() {
+        let mut scene = Scene::new();
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(-100.0, 50.0))
+                .with_scale(Vec2::new(10.0, 10.0)),
+        );
+        scene.spawn(
+            Entity::new(EntityId(2))
+                .with_pos(Vec2::new(200.0, -30.0))
+                .with_scale(Vec2::new(20.0, 20.0)),
+        );
+        let (min, max) = scene.bounds().unwrap();
+        assert_eq!(min, Vec2::new(-105.0, -40.0));
+        assert_eq!(max, Vec2::new(210.0, 55.0));
+    }
+
+    #[test]
+    fn bounds_empty_scene_is_none() {
+        let scene = Scene::new();
+        assert!(scene.bounds().is_none());
+    }
+
+    #[test]
+    fn select_and_deselect_update_membership() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)));
+        scene.spawn(Entity::new(EntityId(2)));
+
+        scene.select(EntityId(1));
+        scene.select(EntityId(2));
+        scene.select(EntityId(2)); // duplicate select is a no-op
+        scene.select(EntityId(99)); // unknown entity is a no-op
+
+        assert!(scene.is_selected(EntityId(1)));
+        assert!(scene.is_selected(EntityId(2)));
+        assert_eq!(scene.selected(), &[EntityId(1), EntityId(2)]);
+
+        scene.deselect(EntityId(1));
+        assert!(!scene.is_selected(EntityId(1)));
+        assert_eq!(scene.selected(), &[EntityId(2)]);
+    }
+
+    #[test]
+    fn despawn_removes_from_selection() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)));
+        scene.select(EntityId(1));
+        scene.despawn(EntityId(1));
+        assert!(!scene.is_selected(EntityId(1)));
+        assert!(scene.selected().is_empty());
+    }
+
+    #[test]
+    fn entities_in_rect_returns_only_overlapping() {
+        let mut scene = Scene::new();
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(50.0, 50.0))
+                .with_scale(Vec2::new(10.0, 10.0)),
+        );
+        scene.spawn(
+            Entity::new(EntityId(2))
+                .with_pos(Vec2::new(500.0, 500.0))
+                .with_scale(Vec2::new(10.0, 10.0)),
+        );
+        // Outside on center but overlapping via its extent
+        scene.spawn(
+            Entity::new(EntityId(3))
+                .with_pos(Vec2::new(105.0, 50.0))
+                .with_scale(Vec2::new(20.0, 20.0)),
+        );
+
+        let hits = scene.entities_in_rect(Vec2::ZERO, Vec2::new(100.0, 100.0));
+        assert_eq!(hits, vec![EntityId(1), EntityId(3)]);
+    }
+
+    #[test]
+    fn first_by_tag() {
         let mut scene = Scene::new();
         scene.spawn(Entity::new(EntityId(1)).with_tag("hero"));
         scene.spawn(Entity::new(EntityId(2)).with_tag("enemy"));
-        let hero = scene.find_by_tag("hero").unwrap();
+        let hero = scene.first_by_tag("hero").unwrap();
         assert_eq!(hero.id, EntityId(1));
     }
+
+    #[test]
+    fn find_by_tag_returns_all_matches() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_tag("ball"));
+        scene.spawn(Entity::new(EntityId(2)).with_tag("cue"));
+        scene.spawn(Entity::new(EntityId(3)).with_tag("ball"));
+
+        let balls: Vec<EntityId> = scene.find_by_tag("ball").map(|e| e.id).collect();
+        assert_eq!(balls, vec![EntityId(1), EntityId(3)]);
+        assert!(scene.find_by_tag("pocket").next().is_none());
+    }
+
+    #[test]
+    fn despawn_updates_tag_index() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_tag("ball"));
+        scene.spawn(Entity::new(EntityId(2)).with_tag("ball"));
+
+        scene.despawn(EntityId(1));
+        let balls: Vec<EntityId> = scene.find_by_tag("ball").map(|e| e.id).collect();
+        assert_eq!(balls, vec![EntityId(2)]);
+    }
+
+    #[test]
+    fn clear_empties_tag_index() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_tag("ball"));
+        scene.clear();
+        assert!(scene.find_by_tag("ball").next().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn json_round_trip_preserves_entities() {
+        use crate::components::layer::RenderLayer;
+        use crate::components::mesh::{MeshComponent, SDFColor};
+        use crate::components::sprite::SpriteComponent;
+
+        let mut scene = Scene::new();
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_tag("hero")
+                .with_pos(Vec2::new(10.0, 20.0))
+                .with_layer(RenderLayer::UI)
+                .with_sprite(SpriteComponent::default()),
+        );
+        scene.spawn(
+            Entity::new(EntityId(2))
+                .with_tag("atom")
+                .with_rotation(1.5)
+                .with_mesh(MeshComponent::sphere(8.0, SDFColor::new(1.0, 0.0, 0.0))),
+        );
+
+        let json = scene.to_json();
+        let restored = Scene::from_json(&json).unwrap();
+
+        assert_eq!(restored.len(), 2);
+        let hero = restored.get(EntityId(1)).unwrap();
+        assert_eq!(hero.tag, "hero");
+        assert_eq!(hero.pos, Vec2::new(10.0, 20.0));
+        assert_eq!(hero.layer, RenderLayer::UI);
+        assert!(hero.sprite.is_some());
+
+        let atom = restored.first_by_tag("atom").unwrap();
+        assert_eq!(atom.id, EntityId(2));
+        assert_eq!(atom.rotation, 1.5);
+        assert!(atom.mesh.is_some());
+
+        assert!(Scene::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn despawn_by_tag_rebuilds_tag_index() {
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_tag("ball"));
+        scene.spawn(Entity::new(EntityId(2)).with_tag("cue"));
+
+        scene.despawn_by_tag("ball");
+        assert!(scene.find_by_tag("ball").next().is_none());
+        assert_eq!(scene.first_by_tag("cue").unwrap().id, EntityId(2));
+    }
 }
@@ -5,21 +5,34 @@ pub struct FixedTimestep {
     dt: f32,
     /// Accumulated time from variable frame deltas.
     accumulator: f32,
+    /// Maximum catch-up steps per frame (spiral-of-death guard).
+    max_steps: u32,
 }
 
+/// Default catch-up cap: at 60Hz this absorbs a ~167ms stall before dropping time.
+pub const DEFAULT_MAX_CATCH_UP_STEPS: u32 = 10;
+
 impl FixedTimestep {
     pub fn new(dt: f32) -> Self {
         Self {
             dt,
             accumulator: 0.0,
+            max_steps: DEFAULT_MAX_CATCH_UP_STEPS,
         }
     }
 
+    /// Set the maximum number of catch-up steps per frame.
+    /// Excess accumulated time beyond `max_steps * dt` is dropped.
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
     /// Add frame time to the accumulator. Returns the number of fixed steps to run.
     pub fn accumulate(&mut self, frame_dt: f32) -> u32 {
         self.accumulator += frame_dt;
-        // Cap to prevent spiral of death (max 10 steps per frame)
-        self.accumulator = self.accumulator.min(self.dt * 10.0);
+        // Cap to prevent spiral of death
+        self.accumulator = self.accumulator.min(self.dt * self.max_steps as f32);
         let steps = (self.accumulator / self.dt) as u32;
         self.accumulator -= steps as f32 * self.dt;
         steps
@@ -63,6 +76,38 @@ mod tests {
         assert_eq!(steps, 10);
     }
 
+    #[test]
+    fn uneven_frame_deltas_yield_expected_update_count() {
+        // Drive the accumulator the way GameRunner::tick does, with uneven
+        // frame pacing; total steps must match the accumulated time.
+        let dt = 1.0 / 60.0;
+        let mut ts = FixedTimestep::new(dt);
+        let frames = [0.012, 0.021, 0.009, 0.033, 0.016, 0.005, 0.040];
+
+        let mut steps = 0;
+        for frame_dt in frames {
+            steps += ts.accumulate(frame_dt);
+        }
+
+        let total: f32 = frames.iter().sum();
+        let expected = (total / dt) as u32;
+        // The remainder stays in the accumulator, so steps is within one of
+        // the ideal count and never exceeds it
+        assert!(steps == expected || steps + 1 == expected,
+            "steps={}, expected ~{}", steps, expected);
+        assert!(ts.alpha() < 1.0);
+    }
+
+    #[test]
+    fn custom_cap_bounds_catch_up() {
+        let mut ts = FixedTimestep::new(1.0 / 60.0).with_max_steps(3);
+        let steps = ts.accumulate(1.0); // 60 frames worth, capped at 3
+        assert_eq!(steps, 3);
+        // Dropped time must not leak into the next frame
+        let steps = ts.accumulate(0.0);
+        assert_eq!(steps, 0);
+    }
+
     #[test]
     fn alpha_is_between_zero_and_one() {
         let mut ts = FixedTimestep::new(1.0 / 60.0);
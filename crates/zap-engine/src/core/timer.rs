@@ -0,0 +1,184 @@
+//! Countdown timer for gameplay state transitions.
+//!
+//! Centralizes the ubiquitous "celebrate for 2 seconds, then reset" pattern
+//! that games otherwise reimplement with ad-hoc f32 accumulators.
+
+/// A countdown timer driven by `tick(dt)`.
+///
+/// One-shot timers report finished exactly once; repeating timers
+/// re-arm themselves each time the duration elapses, carrying over
+/// any excess dt so long frames don't drift the period.
+#[derive(Debug, Clone)]
+pub struct Timer {
+    duration: f32,
+    elapsed: f32,
+    repeating: bool,
+    running: bool,
+}
+
+impl Timer {
+    /// Create a stopped one-shot timer. Call `start()` to arm it.
+    pub fn new() -> Self {
+        Self {
+            duration: 0.0,
+            elapsed: 0.0,
+            repeating: false,
+            running: false,
+        }
+    }
+
+    /// Create a timer already running for `duration` seconds.
+    pub fn one_shot(duration: f32) -> Self {
+        let mut timer = Self::new();
+        timer.start(duration);
+        timer
+    }
+
+    /// Create a repeating timer that fires every `duration` seconds.
+    pub fn repeating(duration: f32) -> Self {
+        let mut timer = Self::new();
+        timer.repeating = true;
+        timer.start(duration);
+        timer
+    }
+
+    /// Arm the timer for `duration` seconds, resetting any elapsed time.
+    pub fn start(&mut self, duration: f32) {
+        self.duration = duration.max(0.0);
+        self.elapsed = 0.0;
+        self.running = true;
+    }
+
+    /// Stop the timer without firing. `progress()` resets to 0.
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.elapsed = 0.0;
+    }
+
+    /// Advance the timer. Returns `true` on the tick where the duration
+    /// elapses (every period for repeating timers, exactly once otherwise).
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if !self.running {
+            return false;
+        }
+        self.elapsed += dt;
+        if self.elapsed < self.duration {
+            return false;
+        }
+        if self.repeating {
+            // Carry over excess so the period doesn't drift on long frames
+            self.elapsed -= self.duration;
+        } else {
+            self.running = false;
+            self.elapsed = self.duration;
+        }
+        true
+    }
+
+    /// Fraction of the duration elapsed, clamped to [0, 1].
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Whether the timer is currently counting down.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Seconds remaining until the timer fires (0 when stopped or finished).
+    pub fn remaining(&self) -> f32 {
+        if !self.running {
+            return 0.0;
+        }
+        (self.duration - self.elapsed).max(0.0)
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_shot_fires_exactly_once() {
+        let mut timer = Timer::one_shot(1.0);
+        let dt = 1.0 / 60.0;
+        let mut fires = 0;
+        let mut elapsed = 0.0;
+        for _ in 0..120 {
+            elapsed += dt;
+            if timer.tick(dt) {
+                fires += 1;
+                // Should fire on the first tick at or past 1 second
+                assert!(elapsed >= 1.0 && elapsed < 1.0 + 2.0 * dt);
+            }
+        }
+        assert_eq!(fires, 1);
+        assert!(!timer.is_running());
+    }
+
+    #[test]
+    fn progress_ramps_zero_to_one() {
+        let mut timer = Timer::one_shot(1.0);
+        assert_eq!(timer.progress(), 0.0);
+
+        timer.tick(0.25);
+        assert!((timer.progress() - 0.25).abs() < 0.001);
+
+        timer.tick(0.5);
+        assert!((timer.progress() - 0.75).abs() < 0.001);
+
+        timer.tick(0.5);
+        assert_eq!(timer.progress(), 1.0);
+    }
+
+    #[test]
+    fn repeating_fires_every_period() {
+        let mut timer = Timer::repeating(0.5);
+        let dt = 0.1;
+        let mut fires = 0;
+        for _ in 0..20 {
+            if timer.tick(dt) {
+                fires += 1;
+            }
+        }
+        // 2 seconds at 0.5s period = 4 fires
+        assert_eq!(fires, 4);
+        assert!(timer.is_running());
+    }
+
+    #[test]
+    fn repeating_carries_over_excess_dt() {
+        let mut timer = Timer::repeating(1.0);
+        // One huge frame of 1.4s: fires once, keeps 0.4s toward next period
+        assert!(timer.tick(1.4));
+        assert!((timer.progress() - 0.4).abs() < 0.001);
+        // 0.6s more completes the second period
+        assert!(timer.tick(0.6));
+    }
+
+    #[test]
+    fn stopped_timer_does_not_fire() {
+        let mut timer = Timer::one_shot(1.0);
+        timer.stop();
+        assert!(!timer.tick(10.0));
+        assert_eq!(timer.progress(), 0.0);
+        assert_eq!(timer.remaining(), 0.0);
+    }
+
+    #[test]
+    fn remaining_counts_down() {
+        let mut timer = Timer::one_shot(2.0);
+        assert!((timer.remaining() - 2.0).abs() < 0.001);
+        timer.tick(0.5);
+        assert!((timer.remaining() - 1.5).abs() < 0.001);
+    }
+}
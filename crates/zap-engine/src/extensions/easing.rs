@@ -29,6 +29,12 @@ pub enum Easing {
     QuartOut,
     /// Very strong slow start and end.
     QuartInOut,
+    /// Strongest polynomial slow start.
+    QuintIn,
+    /// Strongest polynomial slow end.
+    QuintOut,
+    /// Strongest polynomial slow start and end.
+    QuintInOut,
     /// Sine wave easing (smooth).
     SineIn,
     SineOut,
@@ -37,14 +43,26 @@ pub enum Easing {
     ExpoIn,
     ExpoOut,
     ExpoInOut,
+    /// Circular easing (quarter-arc velocity profile).
+    CircIn,
+    CircOut,
+    CircInOut,
     /// Overshoot then settle.
     BackIn,
     BackOut,
     BackInOut,
+    /// Bouncy start.
+    BounceIn,
     /// Bouncy finish.
     BounceOut,
+    /// Bouncy start and finish.
+    BounceInOut,
+    /// Elastic spring at the start.
+    ElasticIn,
     /// Elastic spring.
     ElasticOut,
+    /// Elastic spring at both ends.
+    ElasticInOut,
 }
 
 impl Easing {
@@ -89,6 +107,17 @@ impl Easing {
                 }
             }
 
+            // Quintic
+            Easing::QuintIn => t * t * t * t * t,
+            Easing::QuintOut => 1.0 - (1.0 - t).powi(5),
+            Easing::QuintInOut => {
+                if t < 0.5 {
+                    16.0 * t * t * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(5) / 2.0
+                }
+            }
+
             // Sine
             Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
             Easing::SineOut => (t * PI / 2.0).sin(),
@@ -113,6 +142,17 @@ impl Easing {
                 }
             }
 
+            // Circular
+            Easing::CircIn => 1.0 - (1.0 - t * t).sqrt(),
+            Easing::CircOut => (1.0 - (t - 1.0).powi(2)).sqrt(),
+            Easing::CircInOut => {
+                if t < 0.5 {
+                    (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
+                } else {
+                    ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+                }
+            }
+
             // Back (overshoot)
             Easing::BackIn => {
                 const C1: f32 = 1.70158;
@@ -135,9 +175,27 @@ impl Easing {
             }
 
             // Bounce
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
             Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => {
+                if t < 0.5 {
+                    (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+                } else {
+                    (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+                }
+            }
 
             // Elastic
+            Easing::ElasticIn => {
+                const C4: f32 = (2.0 * PI) / 3.0;
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else {
+                    -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * C4).sin()
+                }
+            }
             Easing::ElasticOut => {
                 const C4: f32 = (2.0 * PI) / 3.0;
                 if t == 0.0 {
@@ -148,6 +206,18 @@ impl Easing {
                     2.0_f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * C4).sin() + 1.0
                 }
             }
+            Easing::ElasticInOut => {
+                const C5: f32 = (2.0 * PI) / 4.5;
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else if t < 0.5 {
+                    -(2.0_f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * C5).sin()) / 2.0
+                } else {
+                    2.0_f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * C5).sin() / 2.0 + 1.0
+                }
+            }
         }
     }
 }
@@ -222,6 +292,48 @@ mod tests {
         assert!(early > 0.3, "BackOut should overshoot");
     }
 
+    #[test]
+    fn all_curves_hit_their_endpoints() {
+        let all = [
+            Easing::Linear,
+            Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut,
+            Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut,
+            Easing::QuartIn, Easing::QuartOut, Easing::QuartInOut,
+            Easing::QuintIn, Easing::QuintOut, Easing::QuintInOut,
+            Easing::SineIn, Easing::SineOut, Easing::SineInOut,
+            Easing::ExpoIn, Easing::ExpoOut, Easing::ExpoInOut,
+            Easing::CircIn, Easing::CircOut, Easing::CircInOut,
+            Easing::BackIn, Easing::BackOut, Easing::BackInOut,
+            Easing::BounceIn, Easing::BounceOut, Easing::BounceInOut,
+            Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut,
+        ];
+        for easing in all {
+            assert!(easing.apply(0.0).abs() < 0.001, "{easing:?} at t=0");
+            assert!((easing.apply(1.0) - 1.0).abs() < 0.001, "{easing:?} at t=1");
+        }
+    }
+
+    #[test]
+    fn circ_in_pinned_value() {
+        // circ_in(0.5) = 1 - sqrt(1 - 0.25) = 1 - sqrt(0.75)
+        let expected = 1.0 - 0.75_f32.sqrt();
+        assert!((Easing::CircIn.apply(0.5) - expected).abs() < 0.001);
+    }
+
+    #[test]
+    fn bounce_in_mirrors_bounce_out() {
+        for t in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            let mirrored = 1.0 - Easing::BounceOut.apply(1.0 - t);
+            assert!((Easing::BounceIn.apply(t) - mirrored).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn elastic_in_undershoots_below_zero() {
+        let undershot = (1..10).any(|i| Easing::ElasticIn.apply(i as f32 / 10.0) < 0.0);
+        assert!(undershot, "ElasticIn should dip below 0 before settling");
+    }
+
     #[test]
     fn ease_interpolates() {
         let result = ease(100.0, 200.0, 0.5, Easing::Linear);
@@ -0,0 +1,164 @@
+// extensions/smooth.rs
+//
+// Critically damped spring smoothing for scalar and Vec2 values.
+// Avoids the overshoot of an underdamped spring and the frame-rate
+// dependence of naive `value += (target - value) * k` lerp.
+// Useful for camera follow, zoom levels, and UI value displays.
+
+use glam::Vec2;
+
+/// A scalar value that eases toward a target with critically damped
+/// spring dynamics. Call `update(target, dt)` once per frame.
+///
+/// `half_life` is the time (in seconds) for the remaining distance to
+/// roughly halve — smaller values track the target more tightly.
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothValue {
+    value: f32,
+    velocity: f32,
+    half_life: f32,
+}
+
+impl SmoothValue {
+    /// Create a smoother starting at `value` with the given half-life.
+    pub fn new(value: f32, half_life: f32) -> Self {
+        Self {
+            value,
+            velocity: 0.0,
+            half_life: half_life.max(1e-5),
+        }
+    }
+
+    /// Advance toward `target` by `dt` seconds. Returns the new value.
+    ///
+    /// Uses the exact closed-form integration of a critically damped
+    /// spring, so the result is stable for any dt (no explosion on
+    /// long frames) and never overshoots a stationary target.
+    pub fn update(&mut self, target: f32, dt: f32) -> f32 {
+        // Critically damped spring: omega chosen so the error roughly
+        // halves every `half_life` seconds.
+        let omega = 2.0 / self.half_life;
+        let x = omega * dt;
+        // Padé approximant of e^-x — accurate and always in (0, 1]
+        let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+        let change = self.value - target;
+        let temp = (self.velocity + omega * change) * dt;
+        self.velocity = (self.velocity - omega * temp) * exp;
+        self.value = target + (change + temp) * exp;
+        self.value
+    }
+
+    /// Current smoothed value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Jump directly to `value`, zeroing velocity (no transition).
+    pub fn snap_to(&mut self, value: f32) {
+        self.value = value;
+        self.velocity = 0.0;
+    }
+
+    /// Change the smoothing half-life without disturbing the current state.
+    pub fn set_half_life(&mut self, half_life: f32) {
+        self.half_life = half_life.max(1e-5);
+    }
+}
+
+/// Vec2 variant of [`SmoothValue`] for positions (camera follow, cursors).
+#[derive(Debug, Clone, Copy)]
+pub struct SmoothVec2 {
+    x: SmoothValue,
+    y: SmoothValue,
+}
+
+impl SmoothVec2 {
+    /// Create a smoother starting at `value` with the given half-life.
+    pub fn new(value: Vec2, half_life: f32) -> Self {
+        Self {
+            x: SmoothValue::new(value.x, half_life),
+            y: SmoothValue::new(value.y, half_life),
+        }
+    }
+
+    /// Advance toward `target` by `dt` seconds. Returns the new value.
+    pub fn update(&mut self, target: Vec2, dt: f32) -> Vec2 {
+        Vec2::new(self.x.update(target.x, dt), self.y.update(target.y, dt))
+    }
+
+    /// Current smoothed value.
+    pub fn value(&self) -> Vec2 {
+        Vec2::new(self.x.value(), self.y.value())
+    }
+
+    /// Jump directly to `value`, zeroing velocity (no transition).
+    pub fn snap_to(&mut self, value: Vec2) {
+        self.x.snap_to(value.x);
+        self.y.snap_to(value.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converges_to_step_target_without_overshoot() {
+        let mut smooth = SmoothValue::new(0.0, 0.1);
+        let dt = 1.0 / 60.0;
+        let mut prev = 0.0;
+        for _ in 0..300 {
+            let v = smooth.update(100.0, dt);
+            // Monotonic approach — never overshoots the target
+            assert!(v >= prev, "value regressed: {} -> {}", prev, v);
+            assert!(v <= 100.0 + 0.001, "overshoot: {}", v);
+            prev = v;
+        }
+        // 5 seconds at 0.1s half-life — should be essentially converged
+        assert!((smooth.value() - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn large_dt_is_stable() {
+        let mut smooth = SmoothValue::new(0.0, 0.05);
+        // One pathological 2-second frame must not explode past the target
+        let v = smooth.update(10.0, 2.0);
+        assert!((0.0..=10.001).contains(&v), "unstable: {}", v);
+    }
+
+    #[test]
+    fn snap_to_jumps_immediately() {
+        let mut smooth = SmoothValue::new(0.0, 0.1);
+        smooth.update(100.0, 0.016);
+        smooth.snap_to(42.0);
+        assert_eq!(smooth.value(), 42.0);
+        // Velocity was zeroed — next update moves toward target, not past it
+        let v = smooth.update(42.0, 0.016);
+        assert!((v - 42.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn smooth_vec2_tracks_both_axes() {
+        let mut smooth = SmoothVec2::new(Vec2::ZERO, 0.05);
+        let dt = 1.0 / 60.0;
+        for _ in 0..300 {
+            smooth.update(Vec2::new(50.0, -30.0), dt);
+        }
+        let v = smooth.value();
+        assert!((v.x - 50.0).abs() < 0.1);
+        assert!((v.y + 30.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn tighter_half_life_converges_faster() {
+        let mut slow = SmoothValue::new(0.0, 0.5);
+        let mut fast = SmoothValue::new(0.0, 0.05);
+        let dt = 1.0 / 60.0;
+        for _ in 0..30 {
+            slow.update(100.0, dt);
+            fast.update(100.0, dt);
+        }
+        assert!(fast.value() > slow.value());
+    }
+}
@@ -53,6 +53,21 @@ impl LocalTransform {
         self.scale = scale;
         self
     }
+
+    /// Compose a child transform under this one (same math as `propagate`):
+    /// the child's offset is rotated and scaled into this transform's space.
+    fn compose(&self, child: &LocalTransform) -> LocalTransform {
+        let (sin_r, cos_r) = self.rotation.sin_cos();
+        let rotated_offset = Vec2::new(
+            child.offset.x * cos_r - child.offset.y * sin_r,
+            child.offset.x * sin_r + child.offset.y * cos_r,
+        );
+        LocalTransform {
+            offset: self.offset + rotated_offset * self.scale,
+            rotation: self.rotation + child.rotation,
+            scale: self.scale * child.scale,
+        }
+    }
 }
 
 /// Node in the transform hierarchy.
@@ -112,7 +127,22 @@ impl TransformGraph {
     }
 
     /// Set the parent of an entity. Pass `None` to make it a root.
-    pub fn set_parent(&mut self, child: EntityId, parent: Option<EntityId>) {
+    /// The child's local transform is untouched — its world transform
+    /// changes to be relative to the new parent (see
+    /// [`TransformGraph::set_parent_keep_world`] for the alternative).
+    /// Returns false (leaving the hierarchy unchanged) if the new parent
+    /// would create a cycle.
+    pub fn set_parent(&mut self, child: EntityId, parent: Option<EntityId>) -> bool {
+        if let Some(p) = parent {
+            if self.would_create_cycle(child, p) {
+                log::warn!(
+                    "TransformGraph: rejected set_parent({:?} -> {:?}) — would create a cycle",
+                    child, p
+                );
+                return false;
+            }
+        }
+
         // Ensure both exist
         self.nodes.entry(child).or_default();
         if let Some(p) = parent {
@@ -148,6 +178,63 @@ impl TransformGraph {
         }
 
         self.dirty = true;
+        true
+    }
+
+    /// Reparent an entity while preserving its current world transform:
+    /// the local transform is recomputed relative to the new parent.
+    /// Returns false (leaving the hierarchy unchanged) on cycles.
+    pub fn set_parent_keep_world(&mut self, child: EntityId, new_parent: Option<EntityId>) -> bool {
+        let world = self.world_transform(child);
+        if !self.set_parent(child, new_parent) {
+            return false;
+        }
+
+        // Invert the new parent's world transform to recover the local one
+        let parent_world = match new_parent {
+            Some(p) => self.world_transform(p),
+            None => LocalTransform::default(),
+        };
+        let (sin_r, cos_r) = (-parent_world.rotation).sin_cos();
+        let delta = world.offset - parent_world.offset;
+        let unrotated = Vec2::new(
+            delta.x * cos_r - delta.y * sin_r,
+            delta.x * sin_r + delta.y * cos_r,
+        );
+        self.set_local(
+            child,
+            LocalTransform {
+                offset: unrotated / parent_world.scale,
+                rotation: world.rotation - parent_world.rotation,
+                scale: world.scale / parent_world.scale,
+            },
+        );
+        true
+    }
+
+    /// Whether making `parent` the parent of `child` would close a loop —
+    /// true when `child` is `parent` or one of its ancestors.
+    fn would_create_cycle(&self, child: EntityId, parent: EntityId) -> bool {
+        let mut current = Some(parent);
+        while let Some(id) = current {
+            if id == child {
+                return true;
+            }
+            current = self.nodes.get(&id).and_then(|n| n.parent);
+        }
+        false
+    }
+
+    /// Compute an entity's world transform by composing up the parent
+    /// chain. Unregistered entities return the identity transform.
+    pub fn world_transform(&self, id: EntityId) -> LocalTransform {
+        let Some(node) = self.nodes.get(&id) else {
+            return LocalTransform::default();
+        };
+        match node.parent {
+            None => node.local,
+            Some(parent) => self.world_transform(parent).compose(&node.local),
+        }
     }
 
     /// Set the local transform for an entity.
@@ -259,6 +346,22 @@ impl TransformGraph {
         }
     }
 
+    /// Write each mapped node's world transform onto a scene entity.
+    /// Unlike [`TransformGraph::propagate`], the graph's node IDs don't
+    /// have to match scene EntityIds — `mapping` pairs a graph node with
+    /// the entity it drives, bridging the extension and core without
+    /// coupling them. Nodes without a scene entity are skipped.
+    pub fn apply_to_scene(&self, scene: &mut Scene, mapping: &[(EntityId, EntityId)]) {
+        for &(node, entity_id) in mapping {
+            let world = self.world_transform(node);
+            if let Some(entity) = scene.get_mut(entity_id) {
+                entity.pos = world.offset;
+                entity.rotation = world.rotation;
+                entity.scale = world.scale;
+            }
+        }
+    }
+
     /// Check if the hierarchy has pending changes.
     pub fn is_dirty(&self) -> bool {
         self.dirty
@@ -327,6 +430,90 @@ mod tests {
         assert_eq!(child_entity.pos, Vec2::new(150.0, 100.0));
     }
 
+    #[test]
+    fn world_transform_composes_parent_chain() {
+        let mut graph = TransformGraph::new();
+        let parent = EntityId(1);
+        let child = EntityId(2);
+
+        graph.register_with(
+            parent,
+            LocalTransform::new()
+                .with_offset(Vec2::new(100.0, 0.0))
+                .with_rotation(std::f32::consts::FRAC_PI_2),
+        );
+        graph.register_with(child, LocalTransform::new().with_offset(Vec2::new(10.0, 0.0)));
+        graph.set_parent(child, Some(parent));
+
+        // Child offset (10, 0) rotated 90° = (0, 10), translated by parent
+        let world = graph.world_transform(child);
+        assert!((world.offset.x - 100.0).abs() < 0.001);
+        assert!((world.offset.y - 10.0).abs() < 0.001);
+        assert!((world.rotation - std::f32::consts::FRAC_PI_2).abs() < 0.001);
+    }
+
+    #[test]
+    fn set_parent_keep_world_preserves_world_position() {
+        let mut graph = TransformGraph::new();
+        let parent = EntityId(1);
+        let child = EntityId(2);
+
+        graph.register_with(
+            parent,
+            LocalTransform::new()
+                .with_offset(Vec2::new(100.0, 100.0))
+                .with_rotation(std::f32::consts::FRAC_PI_2),
+        );
+        graph.register_with(child, LocalTransform::new().with_offset(Vec2::new(50.0, 20.0)));
+
+        let before = graph.world_transform(child);
+        assert!(graph.set_parent_keep_world(child, Some(parent)));
+        let after = graph.world_transform(child);
+
+        assert!((before.offset - after.offset).length() < 0.001);
+        assert!((before.rotation - after.rotation).abs() < 0.001);
+
+        // The entity lands at the same world position after propagation
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(parent));
+        scene.spawn(Entity::new(child));
+        graph.propagate(&mut scene);
+        assert!((scene.get(child).unwrap().pos - before.offset).length() < 0.001);
+    }
+
+    #[test]
+    fn apply_to_scene_writes_world_transform_to_mapped_entity() {
+        let mut graph = TransformGraph::new();
+        let parent = EntityId(1);
+        let child = EntityId(2);
+
+        graph.register_with(parent, LocalTransform::new().with_offset(Vec2::new(100.0, 100.0)));
+        graph.register_with(child, LocalTransform::new().with_offset(Vec2::new(50.0, 0.0)));
+        graph.set_parent(child, Some(parent));
+
+        // Graph node IDs map onto unrelated scene entities
+        let mut scene = Scene::new();
+        let target = EntityId(42);
+        scene.spawn(Entity::new(target));
+
+        graph.apply_to_scene(&mut scene, &[(child, target)]);
+        assert_eq!(scene.get(target).unwrap().pos, Vec2::new(150.0, 100.0));
+    }
+
+    #[test]
+    fn set_parent_rejects_cycles() {
+        let mut graph = TransformGraph::new();
+        let a = EntityId(1);
+        let b = EntityId(2);
+
+        graph.register(a);
+        graph.register(b);
+        assert!(graph.set_parent(b, Some(a)));
+        assert!(!graph.set_parent(a, Some(b)), "a -> b -> a is a cycle");
+        assert!(!graph.set_parent(a, Some(a)), "self-parenting is a cycle");
+        assert_eq!(graph.get_parent(a), None);
+    }
+
     #[test]
     fn remove_orphans_children() {
         let mut graph = TransformGraph::new();
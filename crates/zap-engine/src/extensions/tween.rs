@@ -204,13 +204,35 @@ impl Tween {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TweenId(pub u32);
 
+/// A spring completes once it is both within this distance of its target
+/// and moving slower than the rest velocity (world units / units-per-second).
+const SPRING_REST_DISTANCE: f32 = 0.01;
+const SPRING_REST_VELOCITY: f32 = 0.01;
+
+/// Physically integrated position spring. Unlike duration-based tweens,
+/// a spring has no fixed end time — it accelerates toward the target and
+/// settles when close and slow enough.
+#[derive(Debug, Clone)]
+struct Spring {
+    target: Vec2,
+    velocity: Vec2,
+    /// Spring constant — acceleration per unit of displacement.
+    stiffness: f32,
+    /// Velocity damping. `2.0 * stiffness.sqrt()` is critically damped
+    /// (fastest settle without overshoot); less than that overshoots.
+    damping: f32,
+}
+
 /// Manages all active tweens.
 #[derive(Debug, Default)]
 pub struct TweenState {
     tweens: HashMap<TweenId, (EntityId, Tween)>,
+    springs: HashMap<TweenId, (EntityId, Spring)>,
     next_id: u32,
     /// Completed tween events to be polled.
     completed_events: Vec<u32>,
+    /// Handles of tweens that finished since the last `take_completed`.
+    completed_ids: Vec<TweenId>,
 }
 
 impl TweenState {
@@ -226,14 +248,36 @@ impl TweenState {
         id
     }
 
-    /// Remove a tween by handle.
+    /// Animate an entity's position toward `target` with a spring.
+    /// Coexists with easing tweens and completes (reporting through
+    /// [`TweenState::take_completed`]) once the entity is near the target
+    /// and nearly at rest. Returns a handle for later control.
+    pub fn spring(
+        &mut self,
+        entity: EntityId,
+        target: Vec2,
+        stiffness: f32,
+        damping: f32,
+        initial_velocity: Vec2,
+    ) -> TweenId {
+        let id = TweenId(self.next_id);
+        self.next_id += 1;
+        self.springs.insert(
+            id,
+            (entity, Spring { target, velocity: initial_velocity, stiffness, damping }),
+        );
+        id
+    }
+
+    /// Remove a tween or spring by handle.
     pub fn remove(&mut self, id: TweenId) -> bool {
-        self.tweens.remove(&id).is_some()
+        self.tweens.remove(&id).is_some() || self.springs.remove(&id).is_some()
     }
 
-    /// Remove all tweens for an entity.
+    /// Remove all tweens and springs for an entity.
     pub fn remove_entity(&mut self, entity: EntityId) {
         self.tweens.retain(|_, (e, _)| *e != entity);
+        self.springs.retain(|_, (e, _)| *e != entity);
     }
 
     /// Pause a tween.
@@ -352,9 +396,34 @@ impl TweenState {
             }
         }
 
-        let count = completed.len();
+        // Integrate springs (semi-implicit Euler: stable at game dt)
+        let mut settled = Vec::new();
+        for (&id, (entity_id, spring)) in self.springs.iter_mut() {
+            if let Some(entity) = scene.get_mut(*entity_id) {
+                let displacement = spring.target - entity.pos;
+                let accel = displacement * spring.stiffness - spring.velocity * spring.damping;
+                spring.velocity += accel * dt;
+                entity.pos += spring.velocity * dt;
+
+                if (spring.target - entity.pos).length() < SPRING_REST_DISTANCE
+                    && spring.velocity.length() < SPRING_REST_VELOCITY
+                {
+                    entity.pos = spring.target;
+                    settled.push(id);
+                }
+            } else {
+                // Entity despawned — drop the spring silently
+                settled.push(id);
+            }
+        }
+        let count = completed.len() + settled.len();
         for id in completed {
             self.tweens.remove(&id);
+            self.completed_ids.push(id);
+        }
+        for id in settled {
+            self.springs.remove(&id);
+            self.completed_ids.push(id);
         }
 
         count
@@ -365,20 +434,29 @@ impl TweenState {
         self.completed_events.drain(..)
     }
 
-    /// Number of active tweens.
+    /// Take the handles of tweens that finished since the last call.
+    /// Lets `update` react to completions without polling individual
+    /// handles or registering closures.
+    pub fn take_completed(&mut self) -> Vec<TweenId> {
+        std::mem::take(&mut self.completed_ids)
+    }
+
+    /// Number of active tweens and springs.
     pub fn len(&self) -> usize {
-        self.tweens.len()
+        self.tweens.len() + self.springs.len()
     }
 
-    /// Whether there are no active tweens.
+    /// Whether there are no active tweens or springs.
     pub fn is_empty(&self) -> bool {
-        self.tweens.is_empty()
+        self.tweens.is_empty() && self.springs.is_empty()
     }
 
-    /// Clear all tweens.
+    /// Clear all tweens and springs.
     pub fn clear(&mut self) {
         self.tweens.clear();
+        self.springs.clear();
         self.completed_events.clear();
+        self.completed_ids.clear();
     }
 }
 
@@ -461,6 +539,99 @@ mod tests {
         assert!((e.pos.x - 0.0).abs() < 0.01);
     }
 
+    #[test]
+    fn underdamped_spring_overshoots_critically_damped_does_not() {
+        let stiffness = 400.0_f32;
+        let critical_damping = 2.0 * stiffness.sqrt();
+        let dt = 1.0 / 240.0;
+        let target = Vec2::new(100.0, 0.0);
+
+        let simulate = |damping: f32| -> (bool, f32) {
+            let mut tweens = TweenState::new();
+            let mut scene = Scene::new();
+            let id = EntityId(1);
+            scene.spawn(Entity::new(id).with_pos(Vec2::ZERO));
+            tweens.spring(id, target, stiffness, damping, Vec2::ZERO);
+
+            let mut overshot = false;
+            for _ in 0..(240 * 10) {
+                tweens.tick(dt, &mut scene);
+                if scene.get(id).unwrap().pos.x > target.x + 0.001 {
+                    overshot = true;
+                }
+                if tweens.is_empty() {
+                    break;
+                }
+            }
+            (overshot, scene.get(id).unwrap().pos.x)
+        };
+
+        let (overshot, final_x) = simulate(critical_damping / 8.0);
+        assert!(overshot, "Underdamped spring should overshoot");
+        assert!((final_x - target.x).abs() < 0.1);
+
+        let (overshot, final_x) = simulate(critical_damping);
+        assert!(!overshot, "Critically damped spring should not overshoot");
+        assert!((final_x - target.x).abs() < 0.1);
+    }
+
+    #[test]
+    fn spring_settles_and_reports_completion() {
+        let mut tweens = TweenState::new();
+        let mut scene = Scene::new();
+        let id = EntityId(1);
+        scene.spawn(Entity::new(id).with_pos(Vec2::ZERO));
+
+        let stiffness = 400.0_f32;
+        let handle = tweens.spring(
+            id,
+            Vec2::new(50.0, 0.0),
+            stiffness,
+            2.0 * stiffness.sqrt(),
+            Vec2::ZERO,
+        );
+        assert_eq!(tweens.len(), 1);
+
+        let dt = 1.0 / 240.0;
+        for _ in 0..(240 * 10) {
+            tweens.tick(dt, &mut scene);
+            if tweens.is_empty() {
+                break;
+            }
+        }
+        assert!(tweens.is_empty());
+        assert_eq!(scene.get(id).unwrap().pos, Vec2::new(50.0, 0.0));
+        assert_eq!(tweens.take_completed(), vec![handle]);
+    }
+
+    #[test]
+    fn take_completed_reports_finished_tweens_once() {
+        let mut tweens = TweenState::new();
+        let mut scene = Scene::new();
+        let id = EntityId(1);
+
+        scene.spawn(Entity::new(id));
+        let handle = tweens.add(id, Tween::position(
+            Vec2::ZERO,
+            Vec2::new(100.0, 0.0),
+            1.0,
+            Easing::Linear,
+        ));
+        // Looping tweens never report completion
+        tweens.add(id, Tween::rotation(0.0, 1.0, 1.0, Easing::Linear)
+            .with_loop(TweenLoop::Loop));
+
+        tweens.tick(0.5, &mut scene);
+        assert!(tweens.take_completed().is_empty());
+
+        tweens.tick(0.5, &mut scene);
+        assert_eq!(tweens.take_completed(), vec![handle]);
+
+        // Subsequent calls report nothing
+        tweens.tick(1.0, &mut scene);
+        assert!(tweens.take_completed().is_empty());
+    }
+
     #[test]
     fn remove_entity_tweens() {
         let mut tweens = TweenState::new();
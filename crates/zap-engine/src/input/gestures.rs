@@ -0,0 +1,244 @@
+// input/gestures.rs
+//
+// Gesture recognition over the raw pointer event stream.
+// Recognizers fold pointer events plus a clock and synthesize
+// higher-level InputEvents (e.g., DoubleTap) back into the queue.
+
+use crate::input::queue::InputEvent;
+
+/// Thresholds for double-tap detection.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleTapConfig {
+    /// Maximum time between the two taps, in seconds.
+    pub max_delay: f32,
+    /// Maximum distance between the two tap positions, in world units.
+    pub max_distance: f32,
+}
+
+impl Default for DoubleTapConfig {
+    fn default() -> Self {
+        Self {
+            max_delay: 0.3,
+            max_distance: 30.0,
+        }
+    }
+}
+
+/// Detects two quick taps near the same point and synthesizes an
+/// [`InputEvent::DoubleTap`]. Opt in via
+/// [`InputQueue::enable_double_tap`](crate::input::queue::InputQueue::enable_double_tap);
+/// the queue then feeds pointer-down events through the recognizer
+/// automatically.
+#[derive(Debug, Default)]
+pub struct DoubleTapRecognizer {
+    config: DoubleTapConfig,
+    /// Time and position of the most recent unpaired tap.
+    last_tap: Option<(f32, f32, f32)>,
+}
+
+impl DoubleTapRecognizer {
+    pub fn new(config: DoubleTapConfig) -> Self {
+        Self {
+            config,
+            last_tap: None,
+        }
+    }
+
+    /// Feed an event at time `now` (seconds, monotonic). Returns a
+    /// synthesized double-tap event when the second qualifying tap lands.
+    pub fn on_event(&mut self, event: &InputEvent, now: f32) -> Option<InputEvent> {
+        let (x, y) = match event {
+            InputEvent::PointerDown { x, y, .. } => (*x, *y),
+            _ => return None,
+        };
+
+        if let Some((t, px, py)) = self.last_tap {
+            let dx = x - px;
+            let dy = y - py;
+            let close = (dx * dx + dy * dy).sqrt() <= self.config.max_distance;
+            if now - t <= self.config.max_delay && close {
+                self.last_tap = None;
+                return Some(InputEvent::DoubleTap { x, y });
+            }
+        }
+        self.last_tap = Some((now, x, y));
+        None
+    }
+}
+
+/// Thresholds for drag detection.
+#[derive(Debug, Clone, Copy)]
+pub struct DragConfig {
+    /// Minimum distance from the pointer-down position before a drag
+    /// starts, in world units. Keeps taps from registering as drags.
+    pub threshold: f32,
+}
+
+impl Default for DragConfig {
+    fn default() -> Self {
+        Self { threshold: 5.0 }
+    }
+}
+
+/// Tracks a pointer from down to up and synthesizes
+/// [`InputEvent::DragStart`]/[`DragUpdate`](InputEvent::DragUpdate)/
+/// [`DragEnd`](InputEvent::DragEnd) once movement exceeds the threshold.
+/// Opt in via [`InputQueue::enable_drag`](crate::input::queue::InputQueue::enable_drag)
+/// so games stop hand-rolling `DRAG_THRESHOLD` checks.
+#[derive(Debug, Default)]
+pub struct DragRecognizer {
+    config: DragConfig,
+    /// Per-pointer tracking: (start_x, start_y, last_x, last_y, dragging).
+    pointers: Vec<(u32, f32, f32, f32, f32, bool)>,
+}
+
+impl DragRecognizer {
+    pub fn new(config: DragConfig) -> Self {
+        Self {
+            config,
+            pointers: Vec::new(),
+        }
+    }
+
+    /// Feed an event. Returns a synthesized drag event when the pointer
+    /// crosses the threshold, keeps moving while dragging, or releases.
+    pub fn on_event(&mut self, event: &InputEvent) -> Option<InputEvent> {
+        match *event {
+            InputEvent::PointerDown { pointer_id, x, y } => {
+                self.pointers.retain(|p| p.0 != pointer_id);
+                self.pointers.push((pointer_id, x, y, x, y, false));
+                None
+            }
+            InputEvent::PointerMove { pointer_id, x, y } => {
+                let p = self.pointers.iter_mut().find(|p| p.0 == pointer_id)?;
+                let (_, start_x, start_y, last_x, last_y, dragging) = *p;
+                p.3 = x;
+                p.4 = y;
+                if dragging {
+                    Some(InputEvent::DragUpdate {
+                        pointer_id,
+                        x,
+                        y,
+                        dx: x - last_x,
+                        dy: y - last_y,
+                    })
+                } else {
+                    let dx = x - start_x;
+                    let dy = y - start_y;
+                    if (dx * dx + dy * dy).sqrt() > self.config.threshold {
+                        p.5 = true;
+                        Some(InputEvent::DragStart {
+                            pointer_id,
+                            x: start_x,
+                            y: start_y,
+                        })
+                    } else {
+                        None
+                    }
+                }
+            }
+            InputEvent::PointerUp { pointer_id, x, y } => {
+                let idx = self.pointers.iter().position(|p| p.0 == pointer_id)?;
+                let dragging = self.pointers[idx].5;
+                self.pointers.remove(idx);
+                dragging.then_some(InputEvent::DragEnd { pointer_id, x, y })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::queue::InputQueue;
+
+    fn tap(x: f32, y: f32) -> InputEvent {
+        InputEvent::PointerDown { pointer_id: 0, x, y }
+    }
+
+    #[test]
+    fn two_quick_taps_near_same_point_double_tap() {
+        let mut q = InputQueue::new();
+        q.enable_double_tap(DoubleTapConfig::default());
+
+        q.push(tap(100.0, 100.0));
+        q.advance_clock(0.1);
+        q.push(tap(105.0, 98.0));
+
+        let events = q.drain();
+        assert!(
+            events.iter().any(|e| matches!(e, InputEvent::DoubleTap { x, y } if *x == 105.0 && *y == 98.0)),
+            "expected a synthesized DoubleTap, got {events:?}"
+        );
+    }
+
+    #[test]
+    fn slow_or_far_taps_do_not_double_tap() {
+        let mut q = InputQueue::new();
+        q.enable_double_tap(DoubleTapConfig::default());
+
+        // Too slow
+        q.push(tap(100.0, 100.0));
+        q.advance_clock(1.0);
+        q.push(tap(100.0, 100.0));
+
+        // Too far
+        q.advance_clock(0.05);
+        q.push(tap(500.0, 500.0));
+
+        let events = q.drain();
+        assert!(
+            !events.iter().any(|e| matches!(e, InputEvent::DoubleTap { .. })),
+            "no DoubleTap expected, got {events:?}"
+        );
+    }
+
+    #[test]
+    fn drag_starts_past_threshold_and_ends_on_release() {
+        let mut q = InputQueue::new();
+        q.enable_drag(DragConfig { threshold: 5.0 });
+
+        q.push(InputEvent::PointerDown { pointer_id: 0, x: 100.0, y: 100.0 });
+        // Below threshold — still a potential tap
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 103.0, y: 100.0 });
+        assert!(
+            !q.iter().any(|e| matches!(e, InputEvent::DragStart { .. })),
+            "no DragStart below the threshold"
+        );
+
+        // Past threshold — drag starts at the original down position
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 110.0, y: 100.0 });
+        assert!(q.iter().any(|e| matches!(
+            e,
+            InputEvent::DragStart { pointer_id: 0, x, y } if *x == 100.0 && *y == 100.0
+        )));
+
+        // Further movement reports deltas
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 115.0, y: 102.0 });
+        assert!(q.iter().any(|e| matches!(
+            e,
+            InputEvent::DragUpdate { dx, dy, .. } if *dx == 5.0 && *dy == 2.0
+        )));
+
+        q.push(InputEvent::PointerUp { pointer_id: 0, x: 115.0, y: 102.0 });
+        assert!(q.iter().any(|e| matches!(e, InputEvent::DragEnd { pointer_id: 0, .. })));
+    }
+
+    #[test]
+    fn tap_without_movement_is_not_a_drag() {
+        let mut rec = DragRecognizer::new(DragConfig::default());
+        assert!(rec.on_event(&tap(50.0, 50.0)).is_none());
+        let up = InputEvent::PointerUp { pointer_id: 0, x: 50.0, y: 50.0 };
+        assert!(rec.on_event(&up).is_none(), "no DragEnd for a plain tap");
+    }
+
+    #[test]
+    fn third_tap_starts_a_fresh_pair() {
+        let mut rec = DoubleTapRecognizer::new(DoubleTapConfig::default());
+        assert!(rec.on_event(&tap(0.0, 0.0), 0.0).is_none());
+        assert!(rec.on_event(&tap(0.0, 0.0), 0.1).is_some());
+        // The pair was consumed — the next tap is a first tap again
+        assert!(rec.on_event(&tap(0.0, 0.0), 0.2).is_none());
+    }
+}
@@ -1 +1,3 @@
+pub mod gestures;
 pub mod queue;
+pub mod state;
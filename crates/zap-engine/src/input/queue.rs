@@ -1,38 +1,117 @@
+use crate::input::gestures::{DoubleTapConfig, DoubleTapRecognizer, DragConfig, DragRecognizer};
+
 /// Input event types the engine understands.
 /// Generic — no game-specific semantics.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputEvent {
     /// A touch/click began at world coordinates (x, y).
-    PointerDown { x: f32, y: f32 },
+    /// `pointer_id` distinguishes simultaneous touches (multi-touch);
+    /// single-pointer input always uses id 0.
+    PointerDown { pointer_id: u32, x: f32, y: f32 },
     /// A touch/click ended at world coordinates (x, y).
-    PointerUp { x: f32, y: f32 },
+    PointerUp { pointer_id: u32, x: f32, y: f32 },
     /// A touch/cursor moved to world coordinates (x, y).
-    PointerMove { x: f32, y: f32 },
+    PointerMove { pointer_id: u32, x: f32, y: f32 },
     /// A key was pressed.
     KeyDown { key_code: u32 },
     /// A key was released.
     KeyUp { key_code: u32 },
+    /// An analog stick/trigger moved on gamepad `index`.
+    /// `axis` follows the standard gamepad mapping; `value` is in [-1, 1].
+    GamepadAxis { index: u32, axis: u32, value: f32 },
+    /// A button changed state on gamepad `index`.
+    GamepadButton { index: u32, button: u32, pressed: bool },
     /// A custom event from the UI layer (React buttons, etc.).
     /// `kind` identifies the event type; `a`, `b`, `c` carry arbitrary data.
     Custom { kind: u32, a: f32, b: f32, c: f32 },
+    /// Two quick taps near the same point. Synthesized by the opt-in
+    /// double-tap recognizer (`enable_double_tap`) — never sent by the host.
+    DoubleTap { x: f32, y: f32 },
+    /// A drag began; `x`/`y` is the original pointer-down position.
+    /// Synthesized by the opt-in drag recognizer (`enable_drag`) once
+    /// movement exceeds its threshold — never sent by the host.
+    DragStart { pointer_id: u32, x: f32, y: f32 },
+    /// The dragging pointer moved; `dx`/`dy` is the movement since the
+    /// previous drag event. Synthesized by the drag recognizer.
+    DragUpdate { pointer_id: u32, x: f32, y: f32, dx: f32, dy: f32 },
+    /// The dragging pointer was released. Synthesized by the drag recognizer.
+    DragEnd { pointer_id: u32, x: f32, y: f32 },
 }
 
 /// A queue of input events.
 /// JS writes events into the queue; Rust reads and drains them each frame.
 pub struct InputQueue {
     events: Vec<InputEvent>,
+    /// Opt-in gesture recognition (see `gestures.rs`).
+    double_tap: Option<DoubleTapRecognizer>,
+    drag: Option<DragRecognizer>,
+    /// Monotonic clock in seconds, advanced by the runner each tick.
+    /// Only used to timestamp events for gesture recognition.
+    clock: f32,
 }
 
 impl InputQueue {
     pub fn new() -> Self {
         Self {
             events: Vec::with_capacity(32),
+            double_tap: None,
+            drag: None,
+            clock: 0.0,
         }
     }
 
+    /// Opt in to double-tap recognition. Qualifying pointer-down pairs
+    /// synthesize an extra [`InputEvent::DoubleTap`] into the queue.
+    pub fn enable_double_tap(&mut self, config: DoubleTapConfig) {
+        self.double_tap = Some(DoubleTapRecognizer::new(config));
+    }
+
+    /// Opt in to drag recognition. Pointer movement past the threshold
+    /// synthesizes [`InputEvent::DragStart`]/`DragUpdate`/`DragEnd` events
+    /// into the queue alongside the raw pointer events.
+    pub fn enable_drag(&mut self, config: DragConfig) {
+        self.drag = Some(DragRecognizer::new(config));
+    }
+
+    /// Advance the gesture clock by `dt` seconds. The runner calls this
+    /// once per frame; queues without recognizers can ignore it.
+    pub fn advance_clock(&mut self, dt: f32) {
+        self.clock += dt;
+    }
+
     /// Push a new input event (called from JS via wasm-bindgen).
+    /// Enabled gesture recognizers see each event and may append a
+    /// synthesized gesture event right after the raw one.
     pub fn push(&mut self, event: InputEvent) {
+        let double_tap = self
+            .double_tap
+            .as_mut()
+            .and_then(|r| r.on_event(&event, self.clock));
+        let drag = self.drag.as_mut().and_then(|r| r.on_event(&event));
         self.events.push(event);
+        if let Some(gesture) = double_tap {
+            self.events.push(gesture);
+        }
+        if let Some(gesture) = drag {
+            self.events.push(gesture);
+        }
+    }
+
+    /// Split off the first `n` events into a new queue, leaving the rest
+    /// pending. Used by the runner to cap events per fixed step; the
+    /// returned queue carries no gesture recognizers (they already ran on
+    /// push).
+    pub fn split_front(&mut self, n: usize) -> InputQueue {
+        let n = n.min(self.events.len());
+        let rest = self.events.split_off(n);
+        let front = std::mem::replace(&mut self.events, rest);
+        InputQueue {
+            events: front,
+            double_tap: None,
+            drag: None,
+            clock: self.clock,
+        }
     }
 
     /// Drain all pending events. Returns a Vec and clears the queue.
@@ -40,6 +119,41 @@ impl InputQueue {
         std::mem::take(&mut self.events)
     }
 
+    /// Collapse redundant `PointerMove` events, keeping only the most
+    /// recent move per pointer id. High-frequency pointers can deliver
+    /// dozens of moves per frame and games only care about the latest
+    /// position. Down/up ordering is preserved — a move is only dropped
+    /// when a later move for the same pointer supersedes it with no
+    /// down/up for that pointer in between.
+    pub fn coalesce_moves(&mut self) {
+        let mut keep = vec![true; self.events.len()];
+        // Scan backwards: a move is superseded once a later move for the
+        // same id has been seen, until a down/up for that id intervenes.
+        let mut superseded: Vec<u32> = Vec::new();
+        for (i, event) in self.events.iter().enumerate().rev() {
+            match event {
+                InputEvent::PointerMove { pointer_id, .. } => {
+                    if superseded.contains(pointer_id) {
+                        keep[i] = false;
+                    } else {
+                        superseded.push(*pointer_id);
+                    }
+                }
+                InputEvent::PointerDown { pointer_id, .. }
+                | InputEvent::PointerUp { pointer_id, .. } => {
+                    superseded.retain(|id| id != pointer_id);
+                }
+                _ => {}
+            }
+        }
+        let mut i = 0;
+        self.events.retain(|_| {
+            let k = keep[i];
+            i += 1;
+            k
+        });
+    }
+
     /// Iterate over pending events without consuming them.
     pub fn iter(&self) -> impl Iterator<Item = &InputEvent> {
         self.events.iter()
@@ -69,7 +183,7 @@ mod tests {
     #[test]
     fn push_and_drain() {
         let mut q = InputQueue::new();
-        q.push(InputEvent::PointerDown { x: 10.0, y: 20.0 });
+        q.push(InputEvent::PointerDown { pointer_id: 0, x: 10.0, y: 20.0 });
         q.push(InputEvent::KeyDown { key_code: 32 });
         assert_eq!(q.len(), 2);
         let events = q.drain();
@@ -93,4 +207,114 @@ mod tests {
             _ => panic!("Expected Custom event"),
         }
     }
+
+    #[test]
+    fn split_front_takes_only_the_first_events() {
+        let mut q = InputQueue::new();
+        for key_code in 0..5 {
+            q.push(InputEvent::KeyDown { key_code });
+        }
+        let front = q.split_front(3);
+        assert_eq!(front.len(), 3);
+        assert_eq!(q.len(), 2);
+        match q.iter().next().unwrap() {
+            InputEvent::KeyDown { key_code } => assert_eq!(*key_code, 3),
+            _ => panic!("Expected KeyDown"),
+        }
+        // Splitting more than remain empties the queue without panicking
+        assert_eq!(q.split_front(10).len(), 2);
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn coalesce_keeps_only_latest_move() {
+        let mut q = InputQueue::new();
+        for i in 0..100 {
+            q.push(InputEvent::PointerMove { pointer_id: 0, x: i as f32, y: 0.0 });
+        }
+        q.coalesce_moves();
+        assert_eq!(q.len(), 1);
+        match q.drain()[0] {
+            InputEvent::PointerMove { pointer_id, x, y } => {
+                assert_eq!(pointer_id, 0);
+                assert_eq!(x, 99.0);
+                assert_eq!(y, 0.0);
+            }
+            _ => panic!("Expected PointerMove"),
+        }
+    }
+
+    #[test]
+    fn coalesce_respects_pointer_ids_and_down_up_barriers() {
+        let mut q = InputQueue::new();
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 1.0, y: 0.0 });
+        q.push(InputEvent::PointerMove { pointer_id: 1, x: 2.0, y: 0.0 });
+        q.push(InputEvent::PointerUp { pointer_id: 0, x: 1.0, y: 0.0 });
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 3.0, y: 0.0 });
+        q.coalesce_moves();
+        // The up event shields the first move for pointer 0; pointer 1's
+        // move is untouched.
+        assert_eq!(q.len(), 4);
+    }
+
+    #[test]
+    fn gamepad_events_round_trip() {
+        let mut q = InputQueue::new();
+        q.push(InputEvent::GamepadAxis { index: 0, axis: 1, value: -0.5 });
+        q.push(InputEvent::GamepadButton { index: 0, button: 3, pressed: true });
+
+        let mut iter = q.iter();
+        match iter.next().unwrap() {
+            InputEvent::GamepadAxis { index, axis, value } => {
+                assert_eq!(*index, 0);
+                assert_eq!(*axis, 1);
+                assert_eq!(*value, -0.5);
+            }
+            _ => panic!("Expected GamepadAxis event"),
+        }
+        match iter.next().unwrap() {
+            InputEvent::GamepadButton { index, button, pressed } => {
+                assert_eq!(*index, 0);
+                assert_eq!(*button, 3);
+                assert!(*pressed);
+            }
+            _ => panic!("Expected GamepadButton event"),
+        }
+    }
+
+    #[test]
+    fn interleaved_pointer_streams_keep_their_ids() {
+        let mut q = InputQueue::new();
+        // Two fingers moving at the same time (pinch gesture)
+        q.push(InputEvent::PointerDown { pointer_id: 0, x: 100.0, y: 200.0 });
+        q.push(InputEvent::PointerDown { pointer_id: 1, x: 300.0, y: 200.0 });
+        q.push(InputEvent::PointerMove { pointer_id: 0, x: 90.0, y: 200.0 });
+        q.push(InputEvent::PointerMove { pointer_id: 1, x: 310.0, y: 200.0 });
+        q.push(InputEvent::PointerUp { pointer_id: 1, x: 310.0, y: 200.0 });
+
+        let events = q.drain();
+        let finger_0: Vec<_> = events
+            .iter()
+            .filter(|e| matches!(e,
+                InputEvent::PointerDown { pointer_id: 0, .. }
+                | InputEvent::PointerMove { pointer_id: 0, .. }
+                | InputEvent::PointerUp { pointer_id: 0, .. }))
+            .collect();
+        let finger_1: Vec<_> = events
+            .iter()
+            .filter(|e| matches!(e,
+                InputEvent::PointerDown { pointer_id: 1, .. }
+                | InputEvent::PointerMove { pointer_id: 1, .. }
+                | InputEvent::PointerUp { pointer_id: 1, .. }))
+            .collect();
+        assert_eq!(finger_0.len(), 2);
+        assert_eq!(finger_1.len(), 3);
+        match finger_1[1] {
+            InputEvent::PointerMove { pointer_id, x, .. } => {
+                assert_eq!(*pointer_id, 1);
+                assert_eq!(*x, 310.0);
+            }
+            _ => panic!("Expected PointerMove for finger 1"),
+        }
+    }
 }
@@ -0,0 +1,114 @@
+// input/state.rs
+//
+// Polling-style input state, folded from the event stream each frame.
+// Games that prefer "is the key down right now?" over handling
+// KeyDown/KeyUp pairs read this instead of the InputQueue.
+
+use std::collections::HashSet;
+
+use crate::input::queue::{InputEvent, InputQueue};
+
+/// Currently-held keys and pointers, maintained across frames.
+///
+/// The runner folds each frame's `InputQueue` into this state before
+/// calling `Game::update`, so games get polling semantics alongside the
+/// event stream (available via `EngineContext::input_state`).
+#[derive(Debug, Default)]
+pub struct InputState {
+    keys_down: HashSet<u32>,
+    pointers_down: HashSet<u32>,
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold a single event into the held state.
+    pub fn apply(&mut self, event: &InputEvent) {
+        match event {
+            InputEvent::KeyDown { key_code } => {
+                self.keys_down.insert(*key_code);
+            }
+            InputEvent::KeyUp { key_code } => {
+                self.keys_down.remove(key_code);
+            }
+            InputEvent::PointerDown { pointer_id, .. } => {
+                self.pointers_down.insert(*pointer_id);
+            }
+            InputEvent::PointerUp { pointer_id, .. } => {
+                self.pointers_down.remove(pointer_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Fold all pending events from a queue without consuming them.
+    pub fn fold(&mut self, queue: &InputQueue) {
+        for event in queue.iter() {
+            self.apply(event);
+        }
+    }
+
+    /// Check if a key is currently held.
+    pub fn is_key_down(&self, key_code: u32) -> bool {
+        self.keys_down.contains(&key_code)
+    }
+
+    /// Check if a pointer (touch/mouse button) is currently down.
+    pub fn is_pointer_down(&self, pointer_id: u32) -> bool {
+        self.pointers_down.contains(&pointer_id)
+    }
+
+    /// Reset all held state (e.g., when the canvas loses focus and
+    /// release events will never arrive).
+    pub fn clear(&mut self) {
+        self.keys_down.clear();
+        self.pointers_down.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_reads_down_until_key_up() {
+        let mut state = InputState::new();
+        let mut q = InputQueue::new();
+
+        q.push(InputEvent::KeyDown { key_code: 32 });
+        state.fold(&q);
+        q.drain();
+        assert!(state.is_key_down(32));
+        assert!(!state.is_key_down(33));
+
+        // Held across frames with no new events
+        state.fold(&q);
+        assert!(state.is_key_down(32));
+
+        q.push(InputEvent::KeyUp { key_code: 32 });
+        state.fold(&q);
+        assert!(!state.is_key_down(32));
+    }
+
+    #[test]
+    fn pointer_tracked_per_id() {
+        let mut state = InputState::new();
+        state.apply(&InputEvent::PointerDown { pointer_id: 0, x: 1.0, y: 2.0 });
+        state.apply(&InputEvent::PointerDown { pointer_id: 1, x: 3.0, y: 4.0 });
+        state.apply(&InputEvent::PointerUp { pointer_id: 0, x: 1.0, y: 2.0 });
+        assert!(!state.is_pointer_down(0));
+        assert!(state.is_pointer_down(1));
+    }
+
+    #[test]
+    fn clear_releases_everything() {
+        let mut state = InputState::new();
+        state.apply(&InputEvent::KeyDown { key_code: 65 });
+        state.apply(&InputEvent::PointerDown { pointer_id: 0, x: 0.0, y: 0.0 });
+        state.clear();
+        assert!(!state.is_key_down(65));
+        assert!(!state.is_pointer_down(0));
+    }
+}
@@ -9,29 +9,34 @@ pub mod assets;
 pub mod extensions;
 
 // Re-export key types at crate root for convenience
-pub use api::game::{Game, GameConfig, EngineContext, RenderContext, BakeState};
+pub use api::game::{Game, GameConfig, EngineContext, RenderContext, BakeState, DirtyRegion, FrameData};
 pub use api::types::{EntityId, SoundEvent, GameEvent};
 pub use components::entity::Entity;
 pub use components::layer::RenderLayer;
 pub use components::sprite::{SpriteComponent, AtlasId, BlendMode};
+pub use core::color::{distinct_color, hsv_to_rgb};
 pub use core::scene::Scene;
 pub use core::time::FixedTimestep;
+pub use core::timer::Timer;
 pub use renderer::instance::{RenderInstance, RenderBuffer};
 pub use renderer::camera::Camera2D;
+pub use input::gestures::{DoubleTapConfig, DoubleTapRecognizer, DragConfig, DragRecognizer};
 pub use input::queue::{InputEvent, InputQueue};
+pub use input::state::InputState;
 pub use assets::manifest::AssetManifest;
 pub use assets::registry::SpriteRegistry;
 pub use bridge::protocol::ProtocolLayout;
+pub use bridge::view::ProtocolView;
 pub use systems::effects::{EffectsState, ElectricArc, Particle, SegmentColor, DebugLine};
 pub use systems::render::LayerBatch;
-pub use systems::text::FontConfig;
-pub use systems::lighting::{PointLight, LightState};
+pub use systems::text::{FontConfig, TextAlign};
+pub use systems::lighting::{DirectionalLight, LayerMask, LightState, PointLight, PulseParams};
 pub use bridge::protocol::{LIGHT_FLOATS, DEFAULT_MAX_LIGHTS};
 #[cfg(feature = "physics")]
 pub use systems::debug::debug_draw_colliders;
 pub use components::animation::{AnimationComponent, AnimationDef};
-pub use components::emitter::{EmitterComponent, EmissionMode, ParticleColorMode};
-pub use components::mesh::{MeshComponent, SDFShape, SDFColor};
+pub use components::emitter::{EmitterComponent, EmitterShape, EmissionMode, ParticleColorMode};
+pub use components::mesh::{MeshComponent, SDFShape, SDFColor, SdfOp};
 pub use components::tilemap::{TilemapComponent, Tile};
 pub use renderer::sdf_instance::{SDFInstance, SDFBuffer};
 pub use systems::animation::tick_animations;
@@ -40,7 +45,7 @@ pub use systems::animation::tick_animations;
 pub use core::physics::{
     PhysicsWorld, PhysicsBody, BodyDesc, BodyType,
     ColliderDesc, ColliderMaterial, CollisionPair,
-    JointHandle, JointDesc,
+    JointHandle, JointDesc, ShapeCastHit,
 };
 
 #[cfg(feature = "vectors")]
@@ -49,6 +54,7 @@ pub use systems::vector::{VectorState, VectorVertex, VectorColor};
 // Extensions — decoupled optional systems
 pub use extensions::{
     Easing, lerp, lerp_vec2, ease, ease_vec2,
+    SmoothValue, SmoothVec2,
     TransformGraph, LocalTransform,
     TweenState, Tween, TweenId, TweenTarget, TweenLoop,
 };
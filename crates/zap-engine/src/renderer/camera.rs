@@ -1,6 +1,19 @@
 use bytemuck::{Pod, Zeroable};
 use glam::{Mat4, Vec2};
 
+use crate::systems::effects::Rng;
+
+/// A single active screen shake. Overlapping shakes accumulate.
+#[derive(Debug, Clone, Copy)]
+struct Shake {
+    /// Maximum offset in world units at full strength.
+    intensity: f32,
+    /// Total duration in seconds.
+    duration: f32,
+    /// Time left in seconds; the shake decays linearly to zero.
+    remaining: f32,
+}
+
 /// Orthographic camera for 2D rendering.
 /// Produces a projection matrix mapping world units to clip space.
 pub struct Camera2D {
@@ -14,6 +27,17 @@ pub struct Camera2D {
     pub bounds: Option<[f32; 4]>,
     /// Smoothing factor for camera follow (0.0 = instant, 1.0 = never moves).
     pub smoothing: f32,
+    /// Zoom factor (1.0 = normal, 2.0 = magnified 2x, 0.5 = zoomed out).
+    pub zoom: f32,
+    /// Physical viewport size in pixels (set by `resize`), used for
+    /// world/screen coordinate conversion.
+    pub viewport: [f32; 2],
+    /// Active screen shakes.
+    shakes: Vec<Shake>,
+    /// Current shake offset in world units, updated by `tick_shake`.
+    shake_offset: [f32; 2],
+    /// Deterministic RNG driving shake offsets.
+    rng: Rng,
 }
 
 /// GPU-side uniform data for the camera.
@@ -31,18 +55,25 @@ impl Camera2D {
             center: [0.0, 0.0],
             bounds: None,
             smoothing: 0.0,
+            zoom: 1.0,
+            viewport: [width, height],
+            shakes: Vec::new(),
+            shake_offset: [0.0, 0.0],
+            rng: Rng::new(0x5EED),
         }
     }
 
     /// Build an orthographic projection matrix.
-    /// Origin at center, Y-up, Z in [0, 1].
+    /// Origin at center, Y-up, Z in [0, 1]. Zoom shrinks the visible extent.
     pub fn projection_matrix(&self) -> Mat4 {
-        let half_w = self.width / 2.0;
-        let half_h = self.height / 2.0;
-        let left = self.center[0] - half_w;
-        let right = self.center[0] + half_w;
-        let bottom = self.center[1] - half_h;
-        let top = self.center[1] + half_h;
+        let half_w = self.width / self.zoom / 2.0;
+        let half_h = self.height / self.zoom / 2.0;
+        let cx = self.center[0] + self.shake_offset[0];
+        let cy = self.center[1] + self.shake_offset[1];
+        let left = cx - half_w;
+        let right = cx + half_w;
+        let bottom = cy - half_h;
+        let top = cy + half_h;
         Mat4::orthographic_rh(left, right, bottom, top, 0.0, 1.0)
     }
 
@@ -66,6 +97,32 @@ impl Camera2D {
         let scale = horiz_ratio.min(vert_ratio);
         self.width = viewport_width / scale;
         self.height = viewport_height / scale;
+        self.viewport = [viewport_width, viewport_height];
+    }
+
+    /// Set the zoom factor. Values <= 0 are clamped to a small positive epsilon.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.max(1e-4);
+    }
+
+    /// Convert a world-space point to screen pixels (origin top-left, Y down).
+    pub fn world_to_screen(&self, world: Vec2) -> Vec2 {
+        let scale_x = self.viewport[0] * self.zoom / self.width;
+        let scale_y = self.viewport[1] * self.zoom / self.height;
+        Vec2::new(
+            self.viewport[0] / 2.0 + (world.x - self.center[0]) * scale_x,
+            self.viewport[1] / 2.0 - (world.y - self.center[1]) * scale_y,
+        )
+    }
+
+    /// Convert screen pixels (origin top-left, Y down) to a world-space point.
+    pub fn screen_to_world(&self, screen: Vec2) -> Vec2 {
+        let scale_x = self.viewport[0] * self.zoom / self.width;
+        let scale_y = self.viewport[1] * self.zoom / self.height;
+        Vec2::new(
+            self.center[0] + (screen.x - self.viewport[0] / 2.0) / scale_x,
+            self.center[1] - (screen.y - self.viewport[1] / 2.0) / scale_y,
+        )
     }
 
     /// Set world bounds for camera clamping.
@@ -130,6 +187,45 @@ impl Camera2D {
         }
     }
 
+    /// Start a screen shake. Overlapping shakes accumulate.
+    ///
+    /// # Arguments
+    /// - `intensity` — maximum offset in world units at full strength
+    /// - `duration` — seconds until the shake decays to zero
+    pub fn add_shake(&mut self, intensity: f32, duration: f32) {
+        if intensity <= 0.0 || duration <= 0.0 {
+            return;
+        }
+        self.shakes.push(Shake {
+            intensity,
+            duration,
+            remaining: duration,
+        });
+    }
+
+    /// Advance active shakes and recompute the projection offset.
+    /// Called by the runner each frame; safe to call with no active shakes.
+    pub fn tick_shake(&mut self, dt: f32) {
+        for shake in &mut self.shakes {
+            shake.remaining -= dt;
+        }
+        self.shakes.retain(|s| s.remaining > 0.0);
+
+        let mut offset = [0.0, 0.0];
+        for shake in &self.shakes {
+            // Linear decay toward zero over the shake's duration
+            let strength = shake.intensity * (shake.remaining / shake.duration);
+            offset[0] += self.rng.next_signed_unit() * strength;
+            offset[1] += self.rng.next_signed_unit() * strength;
+        }
+        self.shake_offset = offset;
+    }
+
+    /// Current shake offset in world units (zero when no shake is active).
+    pub fn shake_offset(&self) -> Vec2 {
+        Vec2::new(self.shake_offset[0], self.shake_offset[1])
+    }
+
     /// Check if a world-space point is visible in the viewport.
     pub fn is_visible(&self, point: Vec2) -> bool {
         let half_w = self.width / 2.0;
@@ -233,6 +329,18 @@ mod tests {
         assert!(cam.center[1] > 0.0 && cam.center[1] < 100.0);
     }
 
+    #[test]
+    fn follow_toward_out_of_bounds_target_clamps() {
+        let mut cam = Camera2D::new(100.0, 100.0);
+        cam.set_bounds(0.0, 0.0, 500.0, 400.0);
+        cam.set_smoothing(0.0);
+
+        // Snap-follow a target beyond the world edge: the view must stay inside
+        cam.follow(Vec2::new(2000.0, -300.0), 0.016);
+        assert!((cam.center[0] - 450.0).abs() < 1e-6);
+        assert!((cam.center[1] - 50.0).abs() < 1e-6);
+    }
+
     #[test]
     fn is_visible_detects_points_in_view() {
         let mut cam = Camera2D::new(100.0, 100.0);
@@ -260,6 +368,104 @@ mod tests {
         assert!(!cam.is_rect_visible(Vec2::new(-50.0, 50.0), Vec2::new(10.0, 10.0)));
     }
 
+    #[test]
+    fn world_screen_round_trips_across_zooms_and_offsets() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        cam.resize(1920.0, 1080.0, 800.0, 600.0);
+
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(123.4, -56.7),
+            Vec2::new(-400.0, 300.0),
+        ];
+        for zoom in [0.5, 1.0, 2.5] {
+            cam.set_zoom(zoom);
+            for center in [[0.0, 0.0], [250.0, -180.0]] {
+                cam.center = center;
+                for p in points {
+                    let round_trip = cam.screen_to_world(cam.world_to_screen(p));
+                    assert!(
+                        (round_trip - p).length() < 1e-3,
+                        "zoom={} center={:?}: {} -> {}",
+                        zoom, center, p, round_trip
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn screen_center_maps_to_camera_center() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        cam.center = [100.0, 50.0];
+        cam.set_zoom(2.0);
+
+        let world = cam.screen_to_world(Vec2::new(400.0, 300.0));
+        assert!((world.x - 100.0).abs() < 1e-4);
+        assert!((world.y - 50.0).abs() < 1e-4);
+
+        // Screen Y grows downward: a point above center in world space
+        // lands higher (smaller y) on screen
+        let screen = cam.world_to_screen(Vec2::new(100.0, 100.0));
+        assert!(screen.y < 300.0);
+    }
+
+    #[test]
+    fn zoom_shrinks_the_projected_extent() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        cam.set_zoom(2.0);
+        let cols = cam.projection_matrix().to_cols_array_2d();
+        // Ortho x scale = 2 / visible_width; at zoom 2 the visible width halves
+        assert!((cols[0][0] - 2.0 / 400.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn shake_decays_to_exactly_zero() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        cam.add_shake(10.0, 0.5);
+
+        let mut last_peak = f32::MAX;
+        // Sample the peak magnitude over thirds of the duration: the decay
+        // envelope must shrink even though individual offsets are random
+        for _ in 0..3 {
+            let mut peak = 0.0f32;
+            for _ in 0..10 {
+                cam.tick_shake(0.5 / 30.0);
+                peak = peak.max(cam.shake_offset().length());
+            }
+            assert!(peak < last_peak, "shake envelope must decay: {} vs {}", peak, last_peak);
+            last_peak = peak;
+        }
+
+        // Past the duration the offset is exactly zero
+        cam.tick_shake(0.1);
+        assert_eq!(cam.shake_offset(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn overlapping_shakes_accumulate() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        cam.add_shake(5.0, 1.0);
+        cam.add_shake(5.0, 1.0);
+        cam.tick_shake(0.01);
+        // Two shakes can offset up to ~10 units combined; a single one caps at 5.
+        // Just confirm both are active and contributing.
+        assert_eq!(cam.shakes.len(), 2);
+        assert!(cam.shake_offset().length() <= 10.0 * std::f32::consts::SQRT_2);
+    }
+
+    #[test]
+    fn shake_offsets_projection_center() {
+        let mut cam = Camera2D::new(800.0, 600.0);
+        let baseline = cam.projection_matrix().to_cols_array_2d();
+        cam.add_shake(20.0, 1.0);
+        cam.tick_shake(0.01);
+        let shaken = cam.projection_matrix().to_cols_array_2d();
+        // The translation column moves with the shake offset
+        assert!(cam.shake_offset().length() > 0.0);
+        assert_ne!(baseline[3], shaken[3]);
+    }
+
     #[test]
     fn clear_bounds_allows_free_movement() {
         let mut cam = Camera2D::new(100.0, 100.0);
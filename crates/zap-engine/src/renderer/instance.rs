@@ -1,12 +1,12 @@
 use bytemuck::{Pod, Zeroable};
 
 /// Per-instance render data written to SharedArrayBuffer for the TypeScript renderer.
-/// Must match the TypeScript protocol: 8 floats = 32 bytes stride.
+/// Must match the TypeScript protocol: 15 floats = 60 bytes stride.
 ///
 /// The `scale` field is the world-space rendered size in game units.
 /// (Games write the actual size, e.g. 50.0 for a 50-unit tile.)
 #[repr(C)]
-#[derive(Debug, Clone, Copy, Default, Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
 pub struct RenderInstance {
     /// X position in world space.
     pub x: f32,
@@ -24,22 +24,72 @@ pub struct RenderInstance {
     pub cell_span: f32,
     /// Atlas row.
     pub atlas_row: f32,
+    /// Alpha discard threshold (0.0 = no discard). Texels with alpha below
+    /// this value are discarded by the shader (cutout rendering).
+    pub alpha_cutoff: f32,
+    /// Red tint multiplier (1.0 = untinted).
+    pub tint_r: f32,
+    /// Green tint multiplier (1.0 = untinted).
+    pub tint_g: f32,
+    /// Blue tint multiplier (1.0 = untinted).
+    pub tint_b: f32,
+    /// World-space rendered height. 0.0 = square (use `scale`).
+    pub scale_y: f32,
+    /// Vertical UV cell span. 0.0 = square (use `cell_span`).
+    /// Fractional values address sub-cell regions (9-slice).
+    pub cell_span_y: f32,
+    /// Blend mode discriminant (0 = alpha, 1 = additive, 2 = multiply).
+    /// See `BlendMode::as_f32`.
+    pub blend: f32,
 }
 
 impl RenderInstance {
-    pub const FLOATS: usize = 8;
+    pub const FLOATS: usize = 15;
     pub const STRIDE_BYTES: usize = Self::FLOATS * 4;
 }
 
+impl Default for RenderInstance {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            rotation: 0.0,
+            scale: 0.0,
+            sprite_col: 0.0,
+            alpha: 0.0,
+            cell_span: 0.0,
+            atlas_row: 0.0,
+            alpha_cutoff: 0.0,
+            // Identity white — a zeroed tint would render sprites black
+            tint_r: 1.0,
+            tint_g: 1.0,
+            tint_b: 1.0,
+            scale_y: 0.0,
+            cell_span_y: 0.0,
+            blend: 0.0,
+        }
+    }
+}
+
 /// Render buffer containing all sprite instances and metadata.
+///
+/// Double-buffered: systems write into the back buffer (`instances`) each
+/// frame, then the runner calls [`swap`](Self::swap) to publish it. The
+/// `front_*` accessors always describe the last published frame, so the
+/// pointer handed to JS never observes a half-built frame even if the read
+/// races the next rebuild.
 pub struct RenderBuffer {
-    /// Sprite instances to be rendered, ordered by blend mode:
+    /// Sprite instances being built this frame, ordered by blend mode:
     /// alpha-blended instances first, then additive instances after `atlas_split`.
     pub instances: Vec<RenderInstance>,
     /// Index where the atlas/blend mode split occurs.
     /// Instances [0..atlas_split) use atlas 0 (alpha blend),
     /// instances [atlas_split..] use atlas 1+ or additive blend.
     pub atlas_split: u32,
+    /// Last published frame, exposed through the `front_*` accessors.
+    front: Vec<RenderInstance>,
+    /// Atlas split of the last published frame.
+    front_atlas_split: u32,
 }
 
 impl RenderBuffer {
@@ -51,6 +101,8 @@ impl RenderBuffer {
         Self {
             instances: Vec::with_capacity(max_instances),
             atlas_split: 0,
+            front: Vec::with_capacity(max_instances),
+            front_atlas_split: 0,
         }
     }
 
@@ -71,10 +123,33 @@ impl RenderBuffer {
         self.instances.len() as u32
     }
 
-    /// Raw pointer to instance data for SharedArrayBuffer reads.
+    /// Raw pointer to the instance data being built this frame.
     pub fn instances_ptr(&self) -> *const f32 {
         self.instances.as_ptr() as *const f32
     }
+
+    /// Publish the frame built since the last swap: the back buffer becomes
+    /// the front buffer and the old front becomes the scratch for the next
+    /// rebuild. Called by the runner once the frame is complete.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.instances, &mut self.front);
+        self.front_atlas_split = self.atlas_split;
+    }
+
+    /// Raw pointer to the last published frame, for SharedArrayBuffer reads.
+    pub fn front_ptr(&self) -> *const f32 {
+        self.front.as_ptr() as *const f32
+    }
+
+    /// Instance count of the last published frame.
+    pub fn front_count(&self) -> u32 {
+        self.front.len() as u32
+    }
+
+    /// Atlas split of the last published frame.
+    pub fn front_atlas_split(&self) -> u32 {
+        self.front_atlas_split
+    }
 }
 
 impl Default for RenderBuffer {
@@ -88,9 +163,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn render_instance_is_8_floats() {
-        assert_eq!(std::mem::size_of::<RenderInstance>(), 32);
-        assert_eq!(RenderInstance::FLOATS, 8);
+    fn render_instance_is_15_floats() {
+        assert_eq!(std::mem::size_of::<RenderInstance>(), 60);
+        assert_eq!(RenderInstance::FLOATS, 15);
     }
 
     #[test]
@@ -100,4 +175,32 @@ mod tests {
         buf.push(RenderInstance::default());
         assert_eq!(buf.instance_count(), 2);
     }
+
+    #[test]
+    fn back_buffer_writes_are_invisible_until_swap() {
+        let mut buf = RenderBuffer::new();
+        buf.push(RenderInstance { x: 1.0, ..Default::default() });
+        buf.set_atlas_split(1);
+        buf.swap();
+        assert_eq!(buf.front_count(), 1);
+        assert_eq!(buf.front_atlas_split(), 1);
+
+        // Rebuild the next frame; the published frame must stay intact
+        buf.clear();
+        buf.push(RenderInstance { x: 2.0, ..Default::default() });
+        buf.push(RenderInstance { x: 3.0, ..Default::default() });
+        buf.set_atlas_split(2);
+        unsafe {
+            assert_eq!(*buf.front_ptr(), 1.0);
+        }
+        assert_eq!(buf.front_count(), 1);
+        assert_eq!(buf.front_atlas_split(), 1);
+
+        buf.swap();
+        unsafe {
+            assert_eq!(*buf.front_ptr(), 2.0);
+        }
+        assert_eq!(buf.front_count(), 2);
+        assert_eq!(buf.front_atlas_split(), 2);
+    }
 }
@@ -2,7 +2,7 @@ use bytemuck::{Pod, Zeroable};
 
 /// Per-instance SDF render data for the molecule pipeline.
 /// Written to SharedArrayBuffer for the TypeScript SDF renderer.
-/// 12 floats = 48 bytes per instance.
+/// 20 floats = 80 bytes per instance.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Default, Pod, Zeroable)]
 pub struct SDFInstance {
@@ -15,22 +15,43 @@ pub struct SDFInstance {
     pub b: f32,
     pub shininess: f32,
     pub emissive: f32,
-    /// SDF shape type: 0.0 = Sphere, 1.0 = Capsule, 2.0 = RoundedBox.
+    /// SDF shape type: 0.0 = Sphere, 1.0 = Capsule, 2.0 = RoundedBox,
+    /// 3.0 = Box, 4.0 = Segment (rendered as a rotated capsule).
+    /// Combined shapes pack `shape_a + (shape_b << 3) + (op << 6)` —
+    /// op 0 keeps plain primitives readable as before.
     pub shape_type: f32,
     /// Cylinder half-length (Capsule) or box half-height (RoundedBox). 0.0 for Sphere.
     pub half_height: f32,
     /// Corner radius (RoundedBox only). 0.0 for Sphere/Capsule.
     pub extra: f32,
+    /// Second shape's radius (Combine only). 0.0 otherwise.
+    pub radius_b: f32,
+    /// Second shape's half-height (Combine only).
+    pub half_height_b: f32,
+    /// Second shape's corner radius (Combine only).
+    pub extra_b: f32,
+    /// Smooth-min blend radius in world units (Combine only).
+    pub smoothing: f32,
+    /// Outline ring width in world units. 0.0 = no outline.
+    pub outline_width: f32,
+    pub outline_r: f32,
+    pub outline_g: f32,
+    pub outline_b: f32,
 }
 
 impl SDFInstance {
-    pub const FLOATS: usize = 12;
+    pub const FLOATS: usize = 20;
     pub const STRIDE_BYTES: usize = Self::FLOATS * 4;
 }
 
 /// Buffer of SDF instances for the molecule rendering pipeline.
+///
+/// Double-buffered like `RenderBuffer`: systems write into the back buffer,
+/// the runner calls [`swap`](Self::swap) once the frame is complete, and the
+/// `front_*` accessors expose the last published frame to JS.
 pub struct SDFBuffer {
     instances: Vec<SDFInstance>,
+    front: Vec<SDFInstance>,
 }
 
 impl SDFBuffer {
@@ -41,6 +62,7 @@ impl SDFBuffer {
     pub fn with_capacity(max: usize) -> Self {
         Self {
             instances: Vec::with_capacity(max),
+            front: Vec::with_capacity(max),
         }
     }
 
@@ -56,9 +78,25 @@ impl SDFBuffer {
         self.instances.len()
     }
 
+    /// Raw pointer to the instance data being built this frame.
     pub fn instances_ptr(&self) -> *const f32 {
         self.instances.as_ptr() as *const f32
     }
+
+    /// Publish the frame built since the last swap. Called by the runner.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.instances, &mut self.front);
+    }
+
+    /// Raw pointer to the last published frame, for SharedArrayBuffer reads.
+    pub fn front_ptr(&self) -> *const f32 {
+        self.front.as_ptr() as *const f32
+    }
+
+    /// Instance count of the last published frame.
+    pub fn front_count(&self) -> usize {
+        self.front.len()
+    }
 }
 
 impl Default for SDFBuffer {
@@ -72,9 +110,9 @@ mod tests {
     use super::*;
 
     #[test]
-    fn sdf_instance_is_48_bytes() {
-        assert_eq!(std::mem::size_of::<SDFInstance>(), 48);
-        assert_eq!(SDFInstance::FLOATS, 12);
+    fn sdf_instance_is_80_bytes() {
+        assert_eq!(std::mem::size_of::<SDFInstance>(), 80);
+        assert_eq!(SDFInstance::FLOATS, 20);
     }
 
     #[test]
@@ -85,6 +123,29 @@ mod tests {
         assert_eq!(buf.instance_count(), 2);
     }
 
+    #[test]
+    fn back_buffer_writes_are_invisible_until_swap() {
+        let mut buf = SDFBuffer::new();
+        buf.push(SDFInstance { radius: 1.0, ..Default::default() });
+        buf.swap();
+        assert_eq!(buf.front_count(), 1);
+
+        // Rebuild the next frame; the published frame must stay intact
+        buf.clear();
+        buf.push(SDFInstance { radius: 2.0, ..Default::default() });
+        buf.push(SDFInstance { radius: 3.0, ..Default::default() });
+        unsafe {
+            assert_eq!(*buf.front_ptr().add(2), 1.0); // radius at offset 2
+        }
+        assert_eq!(buf.front_count(), 1);
+
+        buf.swap();
+        unsafe {
+            assert_eq!(*buf.front_ptr().add(2), 2.0);
+        }
+        assert_eq!(buf.front_count(), 2);
+    }
+
     #[test]
     fn sdf_instance_capsule_encoding() {
         let inst = SDFInstance {
@@ -100,8 +161,9 @@ mod tests {
             shape_type: 1.0,
             half_height: 15.0,
             extra: 0.0,
+            ..Default::default()
         };
-        let floats: &[f32; 12] = bytemuck::cast_ref(&inst);
+        let floats: &[f32; 20] = bytemuck::cast_ref(&inst);
         assert_eq!(floats[9], 1.0);  // shape_type at offset 9
         assert_eq!(floats[10], 15.0); // half_height at offset 10
         assert_eq!(floats[11], 0.0);  // extra at offset 11
@@ -122,8 +184,9 @@ mod tests {
             shape_type: 2.0,
             half_height: 10.0,
             extra: 3.0,
+            ..Default::default()
         };
-        let floats: &[f32; 12] = bytemuck::cast_ref(&inst);
+        let floats: &[f32; 20] = bytemuck::cast_ref(&inst);
         assert_eq!(floats[9], 2.0);  // shape_type
         assert_eq!(floats[10], 10.0); // half_height
         assert_eq!(floats[11], 3.0);  // extra (corner_radius)
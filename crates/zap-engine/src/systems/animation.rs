@@ -59,6 +59,35 @@ mod tests {
         assert_eq!(scene.get(EntityId(1)).unwrap().sprite.as_ref().unwrap().col, 1.0);
     }
 
+    #[test]
+    fn sprite_frames_advance_and_wrap() {
+        let mut scene = Scene::new();
+
+        // Non-consecutive columns on row 2, 10fps, looping
+        let anim = AnimationComponent::single(
+            "spin",
+            AnimationDef::sprite_frames(&[3.0, 5.0, 7.0], 2.0, 10.0, true),
+        );
+
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_sprite(SpriteComponent::default())
+                .with_animation(anim),
+        );
+
+        // Cross the first frame boundary
+        tick_animations(&mut scene, 0.15);
+        let sprite = scene.get(EntityId(1)).unwrap().sprite.clone().unwrap();
+        assert_eq!(sprite.col, 5.0);
+        assert_eq!(sprite.row, 2.0);
+
+        // Two more boundaries: frame 2, then wrap back to frame 0
+        tick_animations(&mut scene, 0.1);
+        tick_animations(&mut scene, 0.1);
+        let sprite = scene.get(EntityId(1)).unwrap().sprite.clone().unwrap();
+        assert_eq!(sprite.col, 3.0);
+    }
+
     #[test]
     fn animation_loops() {
         let mut scene = Scene::new();
@@ -1,14 +1,102 @@
-//! Debug rendering — opt-in collider visualization via the effects pipeline.
+//! Debug rendering — opt-in shape and collider visualization via the
+//! effects pipeline.
 //!
-//! Call `debug_draw_colliders()` from your `Game::update()` to see hitboxes.
+//! `draw_circle`/`draw_rect`/`draw_cross` append primitives to
+//! `EffectsState::debug_lines` for marking effect bounds, light radii, or
+//! any point of interest. `debug_draw_colliders()` (physics feature) builds
+//! on the same outlines to show hitboxes.
 //! Debug lines are rendered as additive glow strips (same pipeline as arcs).
 
+use crate::systems::effects::{EffectsState, SegmentColor};
+use glam::Vec2;
+
 #[cfg(feature = "physics")]
 use crate::core::physics::{ColliderDesc, PhysicsWorld};
 #[cfg(feature = "physics")]
 use crate::core::scene::Scene;
-#[cfg(feature = "physics")]
-use crate::systems::effects::{EffectsState, SegmentColor};
+
+/// Segments used to approximate a circle outline.
+const CIRCLE_SEGMENTS: usize = 24;
+
+/// Append a closed circle outline to the debug lines.
+/// Does not clear existing lines — call `EffectsState::clear_debug` once per
+/// frame before redrawing.
+pub fn draw_circle(
+    effects: &mut EffectsState,
+    center: Vec2,
+    radius: f32,
+    line_width: f32,
+    color: SegmentColor,
+) {
+    effects.add_debug_line(circle_outline(center.x, center.y, 0.0, radius), line_width, color);
+}
+
+/// Append a closed (optionally rotated) rectangle outline to the debug lines.
+pub fn draw_rect(
+    effects: &mut EffectsState,
+    center: Vec2,
+    half_extents: Vec2,
+    rotation: f32,
+    line_width: f32,
+    color: SegmentColor,
+) {
+    effects.add_debug_line(
+        rect_outline(center.x, center.y, rotation, half_extents.x, half_extents.y),
+        line_width,
+        color,
+    );
+}
+
+/// Append an axis-aligned cross marking a point (two lines of length `size`).
+pub fn draw_cross(
+    effects: &mut EffectsState,
+    center: Vec2,
+    size: f32,
+    line_width: f32,
+    color: SegmentColor,
+) {
+    let half = size / 2.0;
+    effects.add_debug_line(
+        vec![[center.x - half, center.y], [center.x + half, center.y]],
+        line_width,
+        color,
+    );
+    effects.add_debug_line(
+        vec![[center.x, center.y - half], [center.x, center.y + half]],
+        line_width,
+        color,
+    );
+}
+
+/// Closed circle outline centered at (`cx`, `cy`); `rot` shifts the start
+/// point, which only matters when the outline is visibly segmented.
+fn circle_outline(cx: f32, cy: f32, rot: f32, radius: f32) -> Vec<[f32; 2]> {
+    let mut points = Vec::with_capacity(CIRCLE_SEGMENTS + 1);
+    for i in 0..=CIRCLE_SEGMENTS {
+        let angle = rot + (i as f32 / CIRCLE_SEGMENTS as f32) * std::f32::consts::TAU;
+        points.push([cx + angle.cos() * radius, cy + angle.sin() * radius]);
+    }
+    points
+}
+
+/// Closed rotated-rectangle outline (4 corners + close).
+fn rect_outline(cx: f32, cy: f32, rot: f32, half_width: f32, half_height: f32) -> Vec<[f32; 2]> {
+    let cos_r = rot.cos();
+    let sin_r = rot.sin();
+    let corners: [[f32; 2]; 4] = [
+        [-half_width, -half_height],
+        [half_width, -half_height],
+        [half_width, half_height],
+        [-half_width, half_height],
+    ];
+    let mut points = Vec::with_capacity(5);
+    for [lx, ly] in &corners {
+        points.push([cx + lx * cos_r - ly * sin_r, cy + lx * sin_r + ly * cos_r]);
+    }
+    // Close the loop
+    points.push(points[0]);
+    points
+}
 
 /// Draw wireframe outlines for all physics colliders in the scene.
 ///
@@ -52,42 +140,71 @@ pub fn debug_draw_colliders(
 #[cfg(feature = "physics")]
 fn collider_outline(cx: f32, cy: f32, rot: f32, shape: &ColliderDesc) -> Vec<[f32; 2]> {
     match *shape {
-        ColliderDesc::Ball { radius } => {
-            // 24-segment circle
-            let segments = 24;
-            let mut points = Vec::with_capacity(segments + 1);
-            for i in 0..=segments {
-                let angle = rot + (i as f32 / segments as f32) * std::f32::consts::TAU;
-                points.push([
-                    cx + angle.cos() * radius,
-                    cy + angle.sin() * radius,
-                ]);
+        ColliderDesc::Polyline { ref vertices } => {
+            // Local-space chain, transformed to world space (not closed)
+            let cos_r = rot.cos();
+            let sin_r = rot.sin();
+            vertices
+                .iter()
+                .map(|v| {
+                    [
+                        cx + v.x * cos_r - v.y * sin_r,
+                        cy + v.x * sin_r + v.y * cos_r,
+                    ]
+                })
+                .collect()
+        }
+        ColliderDesc::ConvexHull { ref points } => {
+            // Hull outline, closed back to the first point
+            let cos_r = rot.cos();
+            let sin_r = rot.sin();
+            let mut outline: Vec<[f32; 2]> = points
+                .iter()
+                .map(|v| {
+                    [
+                        cx + v.x * cos_r - v.y * sin_r,
+                        cy + v.x * sin_r + v.y * cos_r,
+                    ]
+                })
+                .collect();
+            if let Some(&first) = outline.first() {
+                outline.push(first);
             }
-            points
+            outline
         }
+        ColliderDesc::Ball { radius } => circle_outline(cx, cy, rot, radius),
         ColliderDesc::Cuboid {
             half_width,
             half_height,
+        } => rect_outline(cx, cy, rot, half_width, half_height),
+        ColliderDesc::CapsuleX { half_width, radius } => {
+            // An X-aligned capsule is a Y-aligned one rotated by -90°
+            collider_outline(
+                cx,
+                cy,
+                rot - std::f32::consts::FRAC_PI_2,
+                &ColliderDesc::CapsuleY {
+                    half_height: half_width,
+                    radius,
+                },
+            )
+        }
+        ColliderDesc::RoundCuboid {
+            half_width,
+            half_height,
+            border_radius,
         } => {
-            // Rotated rectangle (4 corners + close)
-            let cos_r = rot.cos();
-            let sin_r = rot.sin();
-            let corners: [[f32; 2]; 4] = [
-                [-half_width, -half_height],
-                [half_width, -half_height],
-                [half_width, half_height],
-                [-half_width, half_height],
-            ];
-            let mut points = Vec::with_capacity(5);
-            for [lx, ly] in &corners {
-                points.push([
-                    cx + lx * cos_r - ly * sin_r,
-                    cy + lx * sin_r + ly * cos_r,
-                ]);
-            }
-            // Close the loop
-            points.push(points[0]);
-            points
+            // Approximate with the outer bounds rectangle (corners inflated
+            // by the border radius)
+            collider_outline(
+                cx,
+                cy,
+                rot,
+                &ColliderDesc::Cuboid {
+                    half_width: half_width + border_radius,
+                    half_height: half_height + border_radius,
+                },
+            )
         }
         ColliderDesc::CapsuleY {
             half_height,
@@ -164,6 +281,34 @@ mod tests {
         assert!(effects.debug_lines[0].points.len() >= 24);
     }
 
+    #[cfg(feature = "physics")]
+    #[test]
+    fn debug_draw_emits_one_line_per_collider() {
+        let mut scene = Scene::new();
+        let mut physics = PhysicsWorld::new(Vec2::ZERO);
+        let mut effects = EffectsState::new(42);
+
+        let shapes = [
+            ColliderDesc::Ball { radius: 10.0 },
+            ColliderDesc::Cuboid { half_width: 20.0, half_height: 5.0 },
+            ColliderDesc::CapsuleY { half_height: 15.0, radius: 8.0 },
+        ];
+        for (i, shape) in shapes.iter().enumerate() {
+            let id = EntityId(i as u32 + 1);
+            let mut entity = Entity::new(id);
+            let body = physics.create_body(
+                id,
+                &BodyDesc::dynamic(shape.clone()).with_position(Vec2::new(i as f32 * 100.0, 0.0)),
+                ColliderMaterial::default(),
+            );
+            entity.body = Some(body);
+            scene.spawn(entity);
+        }
+
+        debug_draw_colliders(&scene, &physics, &mut effects, 2.0, SegmentColor::Green);
+        assert_eq!(effects.debug_lines.len(), shapes.len());
+    }
+
     #[cfg(feature = "physics")]
     #[test]
     fn debug_lines_included_in_effects_buffer() {
@@ -180,6 +325,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn draw_circle_appends_a_closed_polyline() {
+        let mut effects = EffectsState::new(42);
+        draw_circle(&mut effects, Vec2::new(50.0, 50.0), 10.0, 2.0, SegmentColor::Cyan);
+
+        assert_eq!(effects.debug_lines.len(), 1);
+        let points = &effects.debug_lines[0].points;
+        assert_eq!(points.len(), CIRCLE_SEGMENTS + 1);
+        assert_eq!(points.first(), points.last());
+    }
+
+    #[test]
+    fn draw_rect_and_cross_append_without_clearing() {
+        let mut effects = EffectsState::new(42);
+        draw_rect(&mut effects, Vec2::ZERO, Vec2::new(20.0, 10.0), 0.0, 2.0, SegmentColor::Red);
+        draw_cross(&mut effects, Vec2::new(5.0, 5.0), 8.0, 1.0, SegmentColor::White);
+
+        // One closed rectangle plus the two strokes of the cross
+        assert_eq!(effects.debug_lines.len(), 3);
+        assert_eq!(effects.debug_lines[0].points.len(), 5);
+        assert_eq!(effects.debug_lines[0].points.first(), effects.debug_lines[0].points.last());
+        assert_eq!(effects.debug_lines[1].points, vec![[1.0, 5.0], [9.0, 5.0]]);
+        assert_eq!(effects.debug_lines[2].points, vec![[5.0, 1.0], [5.0, 9.0]]);
+    }
+
     #[test]
     fn clear_debug_empties_lines() {
         let mut effects = EffectsState::new(42);
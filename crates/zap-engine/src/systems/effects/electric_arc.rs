@@ -8,6 +8,10 @@ pub struct ElectricArc {
     pub start: [f32; 2],
     pub end: [f32; 2],
     pub points: Vec<[f32; 2]>,
+    /// Fractal child bolts forking off this arc (see `with_branches`).
+    pub branches: Vec<ElectricArc>,
+    /// Length/width scale applied per branch generation (default 0.5).
+    pub branch_taper: f32,
     displacements: Vec<f32>,
     num_segments: usize,
     max_displacement: f32,
@@ -20,6 +24,8 @@ impl ElectricArc {
             start,
             end,
             points: vec![[0.0; 2]; num_segments + 1],
+            branches: Vec::new(),
+            branch_taper: 0.5,
             displacements: vec![0.0; num_segments.saturating_sub(1)],
             num_segments,
             max_displacement: 0.2,
@@ -68,7 +74,60 @@ impl ElectricArc {
         self.generate_points(mid_idx, end_idx);
     }
 
+    /// Grow fractal branches off this bolt (lightning look).
+    ///
+    /// Each interior point forks a child arc with probability
+    /// `branch_prob`; children point roughly onward from the parent with
+    /// a random kink and shrink by `branch_taper` per generation, and
+    /// recurse until `depth` reaches zero. Replaces any existing
+    /// branches, so it can be re-rolled per strike.
+    pub fn with_branches(&mut self, branch_prob: f32, depth: u32, rng: &mut Rng) {
+        self.branches.clear();
+        if depth == 0 || branch_prob <= 0.0 {
+            return;
+        }
+
+        // Children use half the parent's segment resolution (min 2 segments)
+        let child_po2 = (self.num_segments.trailing_zeros().saturating_sub(1)).max(1);
+
+        let dx = self.end[0] - self.start[0];
+        let dy = self.end[1] - self.start[1];
+        let len = (dx * dx + dy * dy).sqrt().max(0.001);
+        let dir = [dx / len, dy / len];
+
+        for i in 1..self.num_segments {
+            if rng.next_int(10000) as f32 / 10000.0 >= branch_prob {
+                continue;
+            }
+            let p = self.points[i];
+
+            // Kink the parent direction by a random ±(0.35..0.85) rad
+            let angle = (0.35 + rng.next_int(5000) as f32 / 10000.0)
+                * if rng.next_int(2) == 0 { 1.0 } else { -1.0 };
+            let (sin_a, cos_a) = angle.sin_cos();
+            let branch_dir = [
+                dir[0] * cos_a - dir[1] * sin_a,
+                dir[0] * sin_a + dir[1] * cos_a,
+            ];
+
+            // Branch length tapers from the remaining parent length
+            let remaining =
+                ((self.end[0] - p[0]).powi(2) + (self.end[1] - p[1]).powi(2)).sqrt();
+            let branch_len = remaining * self.branch_taper;
+            if branch_len <= 0.0 {
+                continue;
+            }
+            let branch_end = [p[0] + branch_dir[0] * branch_len, p[1] + branch_dir[1] * branch_len];
+
+            let mut child = ElectricArc::new(p, branch_end, child_po2, rng);
+            child.branch_taper = self.branch_taper;
+            child.with_branches(branch_prob, depth - 1, rng);
+            self.branches.push(child);
+        }
+    }
+
     /// Jitter the arc displacements for a living-wire effect.
+    /// Branches twitch along with their parent.
     pub fn twitch(&mut self, factor: f32, rng: &mut Rng) {
         for d in self.displacements.iter_mut() {
             let r = (rng.next_int(10000) as f32 / 5000.0) - 1.0;
@@ -78,6 +137,9 @@ impl ElectricArc {
         self.points[0] = self.start;
         self.points[self.num_segments] = self.end;
         self.generate_points(0, self.num_segments);
+        for branch in &mut self.branches {
+            branch.twitch(factor, rng);
+        }
     }
 }
 
@@ -99,6 +161,23 @@ mod tests {
         assert_eq!(arc.points.len(), 17); // 2^4 + 1
     }
 
+    #[test]
+    fn with_branches_spawns_children_at_full_probability() {
+        let mut rng = Rng::new(42);
+        let mut arc = ElectricArc::new([0.0, 0.0], [100.0, 0.0], 3, &mut rng);
+        assert!(arc.branches.is_empty());
+
+        arc.with_branches(1.0, 2, &mut rng);
+        // Every interior point forks at probability 1.0
+        assert_eq!(arc.branches.len(), 7);
+        // Depth 2: first-generation children fork again
+        assert!(arc.branches.iter().any(|b| !b.branches.is_empty()));
+
+        // Depth 0 clears branches back off
+        arc.with_branches(1.0, 0, &mut rng);
+        assert!(arc.branches.is_empty());
+    }
+
     #[test]
     fn electric_arc_twitch_modifies_points() {
         let mut rng = Rng::new(42);
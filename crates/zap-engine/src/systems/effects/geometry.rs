@@ -8,6 +8,29 @@ pub fn build_strip_vertices(
     points: &[[f32; 2]],
     width: f32,
     color: SegmentColor,
+) -> Vec<f32> {
+    build_strip_vertices_z(points, width, color as u8 as f32)
+}
+
+/// Pack an RGBA color into a single float for the effects wire format.
+///
+/// The z channel normally carries a non-negative `SegmentColor` palette
+/// index; packed colors use the negative range instead:
+/// `-(1 + r8 + (g8 << 8) + (b8 << 16))` with channels premultiplied by
+/// alpha. 24 bits stay exactly representable in an f32, so the shader
+/// recovers the channels losslessly.
+pub fn pack_color_z(rgba: [f32; 4]) -> f32 {
+    let quantize = |c: f32| ((c * rgba[3]).clamp(0.0, 1.0) * 255.0).round() as u32;
+    let rgb24 = quantize(rgba[0]) | (quantize(rgba[1]) << 8) | (quantize(rgba[2]) << 16);
+    -(1.0 + rgb24 as f32)
+}
+
+/// `build_strip_vertices` with a raw z value — either a palette index or
+/// a [`pack_color_z`] packed color.
+pub fn build_strip_vertices_z(
+    points: &[[f32; 2]],
+    width: f32,
+    color_z: f32,
 ) -> Vec<f32> {
     if points.len() < 2 {
         return Vec::new();
@@ -25,8 +48,6 @@ pub fn build_strip_vertices(
         (d, p)
     };
 
-    let color_z = color as u8 as f32;
-
     let push_pair = |verts: &mut Vec<f32>, center: [f32; 2], perp: [f32; 2], w: f32, v: f32| {
         // Left vertex (u=0)
         verts.extend_from_slice(&[
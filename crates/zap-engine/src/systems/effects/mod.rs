@@ -9,24 +9,36 @@ mod geometry;
 mod electric_arc;
 mod particle;
 mod debug_line;
+mod trail;
 
 // Re-export public types
 pub use rng::Rng;
 pub use segment_color::{SegmentColor, SegmentUVs};
-pub use geometry::{build_strip_vertices, strip_to_triangles};
+pub use geometry::{build_strip_vertices, build_strip_vertices_z, pack_color_z, strip_to_triangles};
 pub use electric_arc::ElectricArc;
-pub use particle::Particle;
+pub use particle::{ColorGradient, Particle};
 pub use debug_line::DebugLine;
+pub use trail::Trail;
 
 /// Container for all visual effects (arcs + particles + debug lines).
 /// Generic — games add arcs and particles via public methods.
 pub struct EffectsState {
     pub arcs: Vec<(ElectricArc, f32, SegmentColor)>,
+    /// Pooled particles — expired slots stay in place (`alive == false`)
+    /// and are reused by later spawns to avoid allocation churn.
     pub particles: Vec<Particle>,
     pub debug_lines: Vec<DebugLine>,
+    /// Motion trails keyed by caller-chosen id (usually an entity id).
+    pub trails: Vec<(u32, Trail)>,
     pub effects_buffer: Vec<f32>,
     pub rng: Rng,
     pub attractor: [f32; 2],
+    /// Uniform gravity acceleration applied to particles (world units/s²).
+    pub particle_gravity: [f32; 2],
+    /// Hard cap on rebuilt vertices, mirroring the SAB region size.
+    pub max_vertices: usize,
+    /// Free list of dead particle slot indices.
+    free_slots: Vec<usize>,
 }
 
 impl EffectsState {
@@ -36,21 +48,31 @@ impl EffectsState {
             arcs: Vec::new(),
             particles: Vec::new(),
             debug_lines: Vec::new(),
+            trails: Vec::new(),
             effects_buffer: Vec::with_capacity(4096),
             rng: Rng::new(seed.wrapping_add(7919)),
             attractor: [0.0, 0.0],
+            particle_gravity: [0.0, 0.0],
+            // No SAB region to protect — the buffer grows freely.
+            max_vertices: usize::MAX,
+            free_slots: Vec::new(),
         }
     }
 
     /// Create a new EffectsState with a pre-allocated buffer capacity.
+    /// `max_vertices` is also the hard cap enforced during rebuild.
     pub fn with_capacity(seed: u64, max_vertices: usize) -> Self {
         EffectsState {
             arcs: Vec::new(),
             particles: Vec::new(),
             debug_lines: Vec::new(),
+            trails: Vec::new(),
             effects_buffer: Vec::with_capacity(max_vertices * 5), // 5 floats per vertex
             rng: Rng::new(seed.wrapping_add(7919)),
             attractor: [0.0, 0.0],
+            particle_gravity: [0.0, 0.0],
+            max_vertices,
+            free_slots: Vec::new(),
         }
     }
 
@@ -73,7 +95,7 @@ impl EffectsState {
             let sx = (self.rng.next_int(20000) as f32 / 1000.0) - 10.0;
             let sy = (self.rng.next_int(20000) as f32 / 1000.0) - 10.0;
             let color = SegmentColor::random(&mut self.rng);
-            self.particles.push(Particle::new(
+            self.spawn_particle(Particle::new(
                 center,
                 [sx * speed_limit / 10.0, sy * speed_limit / 10.0],
                 width,
@@ -83,23 +105,59 @@ impl EffectsState {
         }
     }
 
+    /// Place a particle into a free pool slot, growing the Vec only when
+    /// no expired slot is available.
+    fn spawn_particle(&mut self, particle: Particle) {
+        match self.free_slots.pop() {
+            Some(slot) => self.particles[slot] = particle,
+            None => self.particles.push(particle),
+        }
+    }
+
     /// Spawn particles with custom physics parameters (used by emitters).
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_particles_with_config(
         &mut self,
         center: [f32; 2],
         count: usize,
+        shape: &crate::components::emitter::EmitterShape,
         speed_range: (f32, f32),
         width: f32,
+        end_width: f32,
         lifetime: f32,
         color_mode: &crate::components::emitter::ParticleColorMode,
         drag: f32,
         attract_strength: f32,
         speed_factor: f32,
     ) {
-        use crate::components::emitter::ParticleColorMode;
+        use crate::components::emitter::{EmitterShape, ParticleColorMode};
+        use std::f32::consts::TAU;
         for _ in 0..count {
-            let angle = (self.rng.next_int(10000) as f32 / 10000.0) * std::f32::consts::TAU;
-            let t = self.rng.next_int(10000) as f32 / 10000.0;
+            let unit = |rng: &mut Rng| rng.next_int(10000) as f32 / 10000.0;
+
+            // Pick a spawn offset and velocity direction from the shape
+            let (offset, angle) = match shape {
+                EmitterShape::Point => ([0.0, 0.0], unit(&mut self.rng) * TAU),
+                EmitterShape::Line { a, b } => {
+                    let t = unit(&mut self.rng);
+                    let offset = [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t];
+                    (offset, unit(&mut self.rng) * TAU)
+                }
+                EmitterShape::Ring { radius } => {
+                    // Velocity points radially outward from the center
+                    let theta = unit(&mut self.rng) * TAU;
+                    ([theta.cos() * radius, theta.sin() * radius], theta)
+                }
+                EmitterShape::Rect { half_extents } => {
+                    let offset = [
+                        (unit(&mut self.rng) * 2.0 - 1.0) * half_extents[0],
+                        (unit(&mut self.rng) * 2.0 - 1.0) * half_extents[1],
+                    ];
+                    (offset, unit(&mut self.rng) * TAU)
+                }
+            };
+
+            let t = unit(&mut self.rng);
             let speed_mag = speed_range.0 + t * (speed_range.1 - speed_range.0);
             let sx = angle.cos() * speed_mag;
             let sy = angle.sin() * speed_mag;
@@ -111,26 +169,69 @@ impl EffectsState {
                     colors[idx]
                 }
             };
-            self.particles.push(Particle {
-                position: center,
+            self.spawn_particle(Particle {
+                position: [center[0] + offset[0], center[1] + offset[1]],
                 speed: [sx, sy],
                 width,
+                end_width,
                 color,
                 lifetime,
+                initial_lifetime: lifetime,
                 drag,
                 attract_strength,
                 speed_factor,
+                gradient: None,
+                alive: true,
             });
         }
     }
 
+    /// Set a uniform gravity acceleration (world units/s²) applied to
+    /// all particles each tick. Default is zero — attractor-only motion.
+    pub fn set_particle_gravity(&mut self, gravity: [f32; 2]) {
+        self.particle_gravity = gravity;
+    }
+
     /// Advance effects: twitch arcs, update particles.
     pub fn tick(&mut self, dt: f32) {
         for (arc, _, _) in &mut self.arcs {
             arc.twitch(0.05, &mut self.rng);
         }
         let attractor = self.attractor;
-        self.particles.retain_mut(|p| p.tick(attractor, dt));
+        let gravity = self.particle_gravity;
+        for (slot, p) in self.particles.iter_mut().enumerate() {
+            if !p.alive {
+                continue;
+            }
+            if !p.tick_with_gravity(attractor, gravity, dt) {
+                p.alive = false;
+                self.free_slots.push(slot);
+            }
+        }
+    }
+
+    /// Register a motion trail under `id`, replacing any existing one.
+    pub fn add_trail(&mut self, id: u32, max_points: usize, width: f32, color: SegmentColor) {
+        self.remove_trail(id);
+        self.trails.push((id, Trail::new(max_points, width, color)));
+    }
+
+    /// Append a position to the trail registered under `id`.
+    /// Unknown ids get a default trail so callers can just push.
+    pub fn push_trail_point(&mut self, id: u32, pos: [f32; 2]) {
+        match self.trails.iter_mut().find(|(tid, _)| *tid == id) {
+            Some((_, trail)) => trail.push(pos),
+            None => {
+                let mut trail = Trail::new(16, 4.0, SegmentColor::White);
+                trail.push(pos);
+                self.trails.push((id, trail));
+            }
+        }
+    }
+
+    /// Remove the trail registered under `id` (no-op if absent).
+    pub fn remove_trail(&mut self, id: u32) {
+        self.trails.retain(|(tid, _)| *tid != id);
     }
 
     /// Add a debug line (for collider visualization, paths, etc.).
@@ -144,25 +245,37 @@ impl EffectsState {
     }
 
     /// Rebuild the effects vertex buffer (triangle list, 5 floats per vertex).
+    ///
+    /// Never exceeds `max_vertices` — runaway effect counts get truncated
+    /// instead of overflowing the SAB region, with a warning per frame.
     pub fn rebuild_effects_buffer(&mut self) {
         self.effects_buffer.clear();
+        let cap_floats = self.max_vertices.saturating_mul(5);
+        let mut warned = false;
 
         for (arc, width, color) in &self.arcs {
-            let strip = build_strip_vertices(&arc.points, *width, *color);
-            let tris = strip_to_triangles(&strip, 5);
-            self.effects_buffer.extend_from_slice(&tris);
+            push_arc_vertices(&mut self.effects_buffer, arc, *width, *color, cap_floats, &mut warned);
         }
 
         for p in &self.particles {
+            if !p.alive {
+                continue;
+            }
             let strip = p.to_vertices();
             let tris = strip_to_triangles(&strip, 5);
-            self.effects_buffer.extend_from_slice(&tris);
+            append_capped(&mut self.effects_buffer, &tris, cap_floats, &mut warned);
+        }
+
+        for (_, trail) in &self.trails {
+            let strip = trail.to_vertices();
+            let tris = strip_to_triangles(&strip, 5);
+            append_capped(&mut self.effects_buffer, &tris, cap_floats, &mut warned);
         }
 
         for line in &self.debug_lines {
             let strip = build_strip_vertices(&line.points, line.width, line.color);
             let tris = strip_to_triangles(&strip, 5);
-            self.effects_buffer.extend_from_slice(&tris);
+            append_capped(&mut self.effects_buffer, &tris, cap_floats, &mut warned);
         }
     }
 
@@ -171,6 +284,8 @@ impl EffectsState {
         self.arcs.clear();
         self.particles.clear();
         self.debug_lines.clear();
+        self.trails.clear();
+        self.free_slots.clear();
         self.effects_buffer.clear();
     }
 
@@ -183,6 +298,43 @@ impl EffectsState {
     }
 }
 
+/// Append triangle-list floats without exceeding `cap_floats`, warning
+/// once per rebuild on the first truncation. `cap_floats` and the buffer
+/// length are both multiples of 5, so truncation stays vertex-aligned.
+fn append_capped(buffer: &mut Vec<f32>, tris: &[f32], cap_floats: usize, warned: &mut bool) {
+    let remaining = cap_floats.saturating_sub(buffer.len());
+    if tris.len() <= remaining {
+        buffer.extend_from_slice(tris);
+        return;
+    }
+    if !*warned {
+        log::warn!(
+            "effects buffer full: truncating at {} vertices",
+            cap_floats / 5
+        );
+        *warned = true;
+    }
+    buffer.extend_from_slice(&tris[..remaining]);
+}
+
+/// Tessellate an arc and its branches into `buffer`, tapering each
+/// branch generation's width by the arc's `branch_taper`.
+fn push_arc_vertices(
+    buffer: &mut Vec<f32>,
+    arc: &ElectricArc,
+    width: f32,
+    color: SegmentColor,
+    cap_floats: usize,
+    warned: &mut bool,
+) {
+    let strip = build_strip_vertices(&arc.points, width, color);
+    let tris = strip_to_triangles(&strip, 5);
+    append_capped(buffer, &tris, cap_floats, warned);
+    for branch in &arc.branches {
+        push_arc_vertices(buffer, branch, width * arc.branch_taper, color, cap_floats, warned);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +348,40 @@ mod tests {
         assert!(effects.effects_vertex_count() > 0);
     }
 
+    #[test]
+    fn branched_arc_emits_more_vertices_than_plain_arc() {
+        let mut plain = EffectsState::new(42);
+        plain.add_arc([0.0, 0.0], [100.0, 0.0], 4.0, SegmentColor::SkyBlue, 4);
+        plain.rebuild_effects_buffer();
+        let plain_count = plain.effects_vertex_count();
+
+        let mut branched = EffectsState::new(42);
+        branched.add_arc([0.0, 0.0], [100.0, 0.0], 4.0, SegmentColor::SkyBlue, 4);
+        {
+            let (arc, _, _) = &mut branched.arcs[0];
+            let mut rng = Rng::new(7);
+            arc.with_branches(1.0, 2, &mut rng);
+            assert!(!arc.branches.is_empty());
+        }
+        branched.rebuild_effects_buffer();
+
+        assert!(branched.effects_vertex_count() > plain_count);
+    }
+
+    #[test]
+    fn particle_gravity_applies_through_state_tick() {
+        let mut effects = EffectsState::new(42);
+        effects.set_particle_gravity([0.0, -50.0]);
+        effects.particles.push({
+            let mut p = Particle::new([0.0, 0.0], [0.0, 0.0], 4.0, SegmentColor::Red, 10.0);
+            p.attract_strength = 0.0;
+            p
+        });
+
+        effects.tick(0.1);
+        assert!(effects.particles[0].speed[1] < 0.0);
+    }
+
     #[test]
     fn effects_state_spawn_particles() {
         let mut effects = EffectsState::new(42);
@@ -207,6 +393,63 @@ mod tests {
     fn effects_state_with_capacity() {
         let effects = EffectsState::with_capacity(42, 1000);
         assert!(effects.effects_buffer.capacity() >= 5000); // 1000 verts * 5 floats
+        assert_eq!(effects.max_vertices, 1000);
+    }
+
+    #[test]
+    fn particle_pool_reuses_expired_slots() {
+        let mut effects = EffectsState::new(42);
+
+        // Prime the pool with one spawn/expire cycle
+        effects.spawn_particles([0.0, 0.0], 8, 10.0, 4.0, 0.05);
+        effects.tick(0.1);
+        let len = effects.particles.len();
+        let capacity = effects.particles.capacity();
+
+        for _ in 0..20 {
+            effects.spawn_particles([0.0, 0.0], 8, 10.0, 4.0, 0.05);
+            effects.tick(0.1);
+        }
+
+        // Slots are recycled: no growth across repeated cycles
+        assert_eq!(effects.particles.len(), len);
+        assert_eq!(effects.particles.capacity(), capacity);
+
+        // All slots are dead, so nothing reaches the vertex buffer
+        effects.rebuild_effects_buffer();
+        assert_eq!(effects.effects_vertex_count(), 0);
+    }
+
+    #[test]
+    fn rebuild_truncates_at_max_effects_vertices() {
+        let mut effects = EffectsState::with_capacity(42, 100);
+        // 50 particles would emit far more than 100 vertices
+        effects.spawn_particles([0.0, 0.0], 50, 10.0, 4.0, 2.0);
+        effects.rebuild_effects_buffer();
+        assert_eq!(effects.effects_vertex_count(), 100);
+
+        // Clearing effects leaves room again
+        effects.particles.truncate(1);
+        effects.rebuild_effects_buffer();
+        assert!(effects.effects_vertex_count() < 100);
+    }
+
+    #[test]
+    fn trail_emits_continuous_strip_and_caps_at_max_length() {
+        let mut effects = EffectsState::new(42);
+        effects.add_trail(1, 5, 4.0, SegmentColor::SkyBlue);
+        for i in 0..8 {
+            effects.push_trail_point(1, [i as f32 * 10.0, 0.0]);
+        }
+
+        let (_, trail) = &effects.trails[0];
+        assert_eq!(trail.points.len(), 5);
+        assert_eq!(trail.points[0], [30.0, 0.0]); // oldest three dropped off
+
+        effects.rebuild_effects_buffer();
+        // 5 points + 2 caps = 7 vertex pairs → 14-vertex strip → 12 triangles
+        let strip_verts = (5 + 2) * 2;
+        assert_eq!(effects.effects_vertex_count(), (strip_verts - 2) * 3);
     }
 
     #[test]
@@ -1,19 +1,42 @@
 //! Particle system for visual effects.
 
-use super::geometry::build_strip_vertices;
+use super::geometry::{build_strip_vertices_z, pack_color_z};
 use super::segment_color::SegmentColor;
 
+/// RGBA color fade over a particle's life (fire, smoke, sparks).
+///
+/// `initial_lifetime` is captured when the gradient is attached so the
+/// interpolation factor can be normalized as the lifetime counts down.
+#[derive(Debug, Clone, Copy)]
+pub struct ColorGradient {
+    /// RGBA at spawn.
+    pub start: [f32; 4],
+    /// RGBA at expiry.
+    pub end: [f32; 4],
+    /// Lifetime at the moment the gradient was attached.
+    pub initial_lifetime: f32,
+}
+
 /// A single particle with physics and rendering state.
 #[derive(Debug, Clone)]
 pub struct Particle {
     pub position: [f32; 2],
     pub speed: [f32; 2],
+    /// Width at spawn.
     pub width: f32,
+    /// Width at expiry — equal to `width` for constant-size particles.
+    pub end_width: f32,
     pub color: SegmentColor,
     pub lifetime: f32,
+    /// Lifetime at spawn, for normalizing width/color curves.
+    pub initial_lifetime: f32,
     pub drag: f32,
     pub attract_strength: f32,
     pub speed_factor: f32,
+    /// Optional RGBA fade; when set it overrides `color` in the output.
+    pub gradient: Option<ColorGradient>,
+    /// Pool slot flag — expired particles stay in place for reuse.
+    pub alive: bool,
 }
 
 impl Particle {
@@ -24,19 +47,69 @@ impl Particle {
     pub fn new(position: [f32; 2], speed: [f32; 2], width: f32, color: SegmentColor, lifetime: f32) -> Self {
         Particle {
             position, speed, width, color, lifetime,
+            end_width: width,
+            initial_lifetime: lifetime,
             drag: Self::DEFAULT_DRAG,
             attract_strength: Self::DEFAULT_ATTRACT_STRENGTH,
             speed_factor: Self::DEFAULT_SPEED_FACTOR,
+            gradient: None,
+            alive: true,
         }
     }
 
+    /// Shrink (or grow) this particle toward `end_width` as it dies.
+    pub fn with_end_width(mut self, end_width: f32) -> Self {
+        self.end_width = end_width;
+        self
+    }
+
+    /// Width interpolated from spawn width to `end_width` by expired
+    /// lifetime fraction.
+    pub fn current_width(&self) -> f32 {
+        let t = (1.0 - self.lifetime / self.initial_lifetime).clamp(0.0, 1.0);
+        self.width + (self.end_width - self.width) * t
+    }
+
+    /// Fade this particle's color from `start` to `end` RGBA over its
+    /// remaining lifetime, overriding the atlas-index `color`.
+    pub fn with_gradient(mut self, start: [f32; 4], end: [f32; 4]) -> Self {
+        self.gradient = Some(ColorGradient {
+            start,
+            end,
+            initial_lifetime: self.lifetime,
+        });
+        self
+    }
+
+    /// Current RGBA of a gradient particle, or `None` for atlas-index ones.
+    pub fn gradient_color(&self) -> Option<[f32; 4]> {
+        self.gradient.map(|g| {
+            let t = (1.0 - self.lifetime / g.initial_lifetime).clamp(0.0, 1.0);
+            let mut rgba = [0.0; 4];
+            for (out, (a, b)) in rgba.iter_mut().zip(g.start.iter().zip(&g.end)) {
+                *out = a + (b - a) * t;
+            }
+            rgba
+        })
+    }
+
     /// Advance particle physics. Returns false when expired.
     pub fn tick(&mut self, attractor: [f32; 2], dt: f32) -> bool {
+        self.tick_with_gravity(attractor, [0.0, 0.0], dt)
+    }
+
+    /// Advance particle physics with a uniform gravity acceleration
+    /// (world units/s²), applied before the attractor term. Returns
+    /// false when expired.
+    pub fn tick_with_gravity(&mut self, attractor: [f32; 2], gravity: [f32; 2], dt: f32) -> bool {
         self.lifetime -= dt;
         if self.lifetime <= 0.0 {
             return false;
         }
 
+        self.speed[0] += gravity[0] * dt;
+        self.speed[1] += gravity[1] * dt;
+
         let dx = attractor[0] - self.position[0];
         let dy = attractor[1] - self.position[1];
         let len = (dx * dx + dy * dy).sqrt().max(0.001);
@@ -58,11 +131,11 @@ impl Particle {
             self.position[0] + self.speed[0],
             self.position[1] + self.speed[1],
         ];
-        build_strip_vertices(
-            &[self.position, end],
-            self.width,
-            self.color,
-        )
+        let color_z = match self.gradient_color() {
+            Some(rgba) => pack_color_z(rgba),
+            None => self.color as u8 as f32,
+        };
+        build_strip_vertices_z(&[self.position, end], self.current_width(), color_z)
     }
 }
 
@@ -92,6 +165,72 @@ mod tests {
         assert!(p.position[0] > 0.0, "particle should move toward attractor");
     }
 
+    #[test]
+    fn gravity_accelerates_particles_downward() {
+        let mut p = Particle::new([0.0, 0.0], [0.0, 0.0], 4.0, SegmentColor::Red, 10.0);
+        p.attract_strength = 0.0; // gravity only
+
+        let mut prev_vy = p.speed[1];
+        for _ in 0..5 {
+            p.tick_with_gravity([0.0, 0.0], [0.0, -100.0], 0.1);
+            assert!(p.speed[1] < prev_vy, "velocity should grow downward");
+            prev_vy = p.speed[1];
+        }
+
+        // Zero gravity leaves the attractor-only path untouched
+        let mut a = Particle::new([0.0, 0.0], [1.0, 0.0], 4.0, SegmentColor::Red, 10.0);
+        let mut b = a.clone();
+        a.tick([50.0, 50.0], 0.1);
+        b.tick_with_gravity([50.0, 50.0], [0.0, 0.0], 0.1);
+        assert_eq!(a.speed, b.speed);
+        assert_eq!(a.position, b.position);
+    }
+
+    #[test]
+    fn width_interpolates_from_start_to_end() {
+        let mut p = Particle::new([0.0, 0.0], [1.0, 0.0], 4.0, SegmentColor::Red, 2.0)
+            .with_end_width(1.0);
+
+        // Full lifetime remaining → spawn width
+        assert_eq!(p.current_width(), 4.0);
+
+        p.lifetime = 1.0;
+        assert_eq!(p.current_width(), 2.5);
+
+        p.lifetime = 0.0;
+        assert_eq!(p.current_width(), 1.0);
+
+        // Single-width constructor keeps particles constant-size
+        let constant = Particle::new([0.0, 0.0], [1.0, 0.0], 4.0, SegmentColor::Red, 2.0);
+        assert_eq!(constant.end_width, 4.0);
+    }
+
+    #[test]
+    fn half_expired_gradient_emits_midpoint_color() {
+        let mut p = Particle::new([0.0, 0.0], [1.0, 0.0], 4.0, SegmentColor::Red, 2.0)
+            .with_gradient([1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]);
+
+        p.lifetime = 1.0; // half expired
+        let rgba = p.gradient_color().unwrap();
+        assert_eq!(rgba, [0.5, 0.0, 0.5, 1.0]);
+
+        // The packed z channel decodes back to the midpoint (8-bit steps)
+        let verts = p.to_vertices();
+        let z = verts[2];
+        assert!(z < 0.0, "gradient colors use the negative z range");
+        let packed = (-z - 1.0) as u32;
+        assert_eq!(packed & 0xFF, 128); // 0.5 quantized
+        assert_eq!((packed >> 8) & 0xFF, 0);
+        assert_eq!((packed >> 16) & 0xFF, 128);
+    }
+
+    #[test]
+    fn atlas_index_particles_still_emit_palette_z() {
+        let p = Particle::new([0.0, 0.0], [1.0, 0.0], 4.0, SegmentColor::Blue, 1.0);
+        let verts = p.to_vertices();
+        assert_eq!(verts[2], SegmentColor::Blue as u8 as f32);
+    }
+
     #[test]
     fn particle_to_vertices_produces_output() {
         let p = Particle::new([0.0, 0.0], [10.0, 0.0], 4.0, SegmentColor::Blue, 1.0);
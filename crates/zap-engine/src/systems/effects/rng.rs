@@ -28,6 +28,11 @@ impl Rng {
     pub fn next_int(&mut self, upper_bound: u32) -> u32 {
         (self.next_u64() % upper_bound as u64) as u32
     }
+
+    /// Generate a random float in [-1.0, 1.0].
+    pub fn next_signed_unit(&mut self) -> f32 {
+        self.next_int(2001) as f32 / 1000.0 - 1.0
+    }
 }
 
 #[cfg(test)]
@@ -6,6 +6,7 @@ use super::rng::Rng;
 /// 13 colors from the arrows texture atlas.
 /// Matches the TypeScript SEGMENT_COLORS array in constants.ts.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum SegmentColor {
     Red = 0,
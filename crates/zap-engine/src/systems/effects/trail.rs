@@ -0,0 +1,101 @@
+//! Motion trail: a fading ribbon that follows a moving point.
+
+use std::collections::VecDeque;
+
+use super::geometry::build_strip_vertices;
+use super::segment_color::SegmentColor;
+
+/// A ribbon of recent positions for comet tails and motion trails.
+///
+/// Positions are appended at the head; once `max_points` is exceeded the
+/// oldest drop off the tail, so the ribbon follows its emitter. The strip
+/// tapers from full `width` at the newest point down to zero at the
+/// oldest, which reads as a fade.
+#[derive(Debug, Clone)]
+pub struct Trail {
+    /// Recent positions, oldest first.
+    pub points: VecDeque<[f32; 2]>,
+    /// Ring buffer capacity — oldest points drop past this.
+    pub max_points: usize,
+    /// Ribbon width at the newest point.
+    pub width: f32,
+    pub color: SegmentColor,
+}
+
+impl Trail {
+    pub fn new(max_points: usize, width: f32, color: SegmentColor) -> Self {
+        Trail {
+            points: VecDeque::with_capacity(max_points),
+            max_points: max_points.max(2),
+            width,
+            color,
+        }
+    }
+
+    /// Append a position, dropping the oldest once capacity is exceeded.
+    pub fn push(&mut self, pos: [f32; 2]) {
+        self.points.push_back(pos);
+        while self.points.len() > self.max_points {
+            self.points.pop_front();
+        }
+    }
+
+    /// Generate tapering strip vertices (5 floats per vertex).
+    ///
+    /// Builds a constant-width strip, then shrinks each vertex pair
+    /// toward its centerline by the point's age fraction so the tail
+    /// narrows to a point at the oldest position.
+    pub fn to_vertices(&self) -> Vec<f32> {
+        let pts: Vec<[f32; 2]> = self.points.iter().copied().collect();
+        let mut verts = build_strip_vertices(&pts, self.width, self.color);
+        let n = pts.len();
+        if n < 2 {
+            return verts;
+        }
+
+        // Strip layout: start cap, one pair per point, end cap.
+        // Caps taper with their neighboring endpoint.
+        for (pair, chunk) in verts.chunks_mut(10).enumerate() {
+            let point_idx = pair.saturating_sub(1).min(n - 1);
+            let t = point_idx as f32 / (n - 1) as f32;
+            let cx = (chunk[0] + chunk[5]) * 0.5;
+            let cy = (chunk[1] + chunk[6]) * 0.5;
+            chunk[0] = cx + (chunk[0] - cx) * t;
+            chunk[1] = cy + (chunk[1] - cy) * t;
+            chunk[5] = cx + (chunk[5] - cx) * t;
+            chunk[6] = cy + (chunk[6] - cy) * t;
+        }
+        verts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trail_caps_at_max_points() {
+        let mut trail = Trail::new(4, 4.0, SegmentColor::White);
+        for i in 0..10 {
+            trail.push([i as f32, 0.0]);
+        }
+        assert_eq!(trail.points.len(), 4);
+        // Oldest points dropped off: front is the 7th push
+        assert_eq!(trail.points[0], [6.0, 0.0]);
+    }
+
+    #[test]
+    fn trail_tapers_toward_oldest_point() {
+        let mut trail = Trail::new(8, 4.0, SegmentColor::White);
+        for i in 0..5 {
+            trail.push([i as f32 * 10.0, 0.0]);
+        }
+        let verts = trail.to_vertices();
+        // Pair for the oldest point (pair 1) collapses to the centerline,
+        // the newest point's pair (pair 5) keeps full width.
+        let oldest_half_width = (verts[10 + 1] - verts[10 + 6]).abs() * 0.5;
+        let newest_half_width = (verts[50 + 1] - verts[50 + 6]).abs() * 0.5;
+        assert!(oldest_half_width < 0.001);
+        assert!((newest_half_width - 4.0).abs() < 0.001);
+    }
+}
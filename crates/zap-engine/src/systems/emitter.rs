@@ -1,3 +1,4 @@
+use crate::components::emitter::EmissionMode;
 use crate::core::scene::Scene;
 use crate::systems::effects::EffectsState;
 
@@ -12,7 +13,10 @@ pub fn tick_emitters(scene: &mut Scene, effects: &mut EffectsState, dt: f32) {
             Some(e) if e.active => e,
             _ => continue,
         };
-        let count = emitter.tick(dt);
+        let count = match emitter.mode {
+            EmissionMode::Distance => emitter.tick_moved([entity.pos.x, entity.pos.y]),
+            _ => emitter.tick(dt),
+        };
         if count == 0 {
             continue;
         }
@@ -20,8 +24,10 @@ pub fn tick_emitters(scene: &mut Scene, effects: &mut EffectsState, dt: f32) {
         effects.spawn_particles_with_config(
             pos,
             count,
+            &emitter.shape,
             emitter.speed_range,
             emitter.width,
+            emitter.end_width.unwrap_or(emitter.width),
             emitter.lifetime,
             &emitter.color_mode,
             emitter.drag,
@@ -36,16 +42,14 @@ mod tests {
     use super::*;
     use crate::api::types::EntityId;
     use crate::components::entity::Entity;
-    use crate::components::emitter::{EmitterComponent, EmissionMode};
+    use crate::components::emitter::{EmitterComponent, EmitterShape, EmissionMode};
     use glam::Vec2;
 
     #[test]
     fn tick_emitters_spawns_at_entity_pos() {
         let mut scene = Scene::new();
         let emitter = EmitterComponent::new()
-            .with_mode(EmissionMode::Burst)
-            .with_burst_count(5)
-            .with_burst_interval(0.0);
+            .with_mode(EmissionMode::Burst { count: 5, delay: 0.0, repeats: 1 });
         scene.spawn(
             Entity::new(EntityId(1))
                 .with_pos(Vec2::new(100.0, 200.0))
@@ -61,13 +65,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn distance_emitter_density_follows_movement() {
+        let mut scene = Scene::new();
+        let emitter = EmitterComponent::new()
+            .with_mode(EmissionMode::Distance)
+            .with_rate_per_unit(0.5);
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::ZERO)
+                .with_emitter(emitter),
+        );
+
+        let mut effects = EffectsState::new(42);
+        // First tick records the starting position
+        tick_emitters(&mut scene, &mut effects, 0.016);
+        assert_eq!(effects.particles.len(), 0);
+
+        // Move 20 units, then 40 units: twice the distance, twice the particles
+        scene.get_mut(EntityId(1)).unwrap().pos = Vec2::new(20.0, 0.0);
+        tick_emitters(&mut scene, &mut effects, 0.016);
+        let after_short = effects.particles.len();
+
+        scene.get_mut(EntityId(1)).unwrap().pos = Vec2::new(60.0, 0.0);
+        tick_emitters(&mut scene, &mut effects, 0.016);
+        let after_long = effects.particles.len();
+
+        assert_eq!(after_short, 10);
+        assert_eq!(after_long - after_short, 20);
+
+        // Stationary: no further particles
+        tick_emitters(&mut scene, &mut effects, 0.016);
+        assert_eq!(effects.particles.len(), after_long);
+    }
+
+    #[test]
+    fn line_shape_spawns_on_segment() {
+        let mut scene = Scene::new();
+        let emitter = EmitterComponent::new()
+            .with_mode(EmissionMode::Burst { count: 20, delay: 0.0, repeats: 1 })
+            .with_shape(EmitterShape::Line { a: [-50.0, 0.0], b: [50.0, 0.0] });
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(100.0, 200.0))
+                .with_emitter(emitter),
+        );
+
+        let mut effects = EffectsState::new(42);
+        tick_emitters(&mut scene, &mut effects, 0.016);
+
+        assert_eq!(effects.particles.len(), 20);
+        for p in &effects.particles {
+            // On the segment: y fixed, x within the endpoints
+            assert_eq!(p.position[1], 200.0);
+            assert!(p.position[0] >= 50.0 && p.position[0] <= 150.0);
+        }
+    }
+
+    #[test]
+    fn ring_shape_spawns_on_radius_with_outward_velocity() {
+        let mut scene = Scene::new();
+        let emitter = EmitterComponent::new()
+            .with_mode(EmissionMode::Burst { count: 20, delay: 0.0, repeats: 1 })
+            .with_shape(EmitterShape::Ring { radius: 30.0 })
+            .with_speed_range(5.0, 10.0);
+        scene.spawn(
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(100.0, 100.0))
+                .with_emitter(emitter),
+        );
+
+        let mut effects = EffectsState::new(42);
+        tick_emitters(&mut scene, &mut effects, 0.016);
+
+        assert_eq!(effects.particles.len(), 20);
+        for p in &effects.particles {
+            let dx = p.position[0] - 100.0;
+            let dy = p.position[1] - 100.0;
+            let dist = (dx * dx + dy * dy).sqrt();
+            assert!((dist - 30.0).abs() < 0.001, "spawn should sit on the ring");
+            // Velocity points radially outward
+            let dot = p.speed[0] * dx + p.speed[1] * dy;
+            assert!(dot > 0.0, "velocity should point away from the center");
+        }
+    }
+
     #[test]
     fn tick_emitters_skips_inactive_entity() {
         let mut scene = Scene::new();
         let emitter = EmitterComponent::new()
-            .with_mode(EmissionMode::Burst)
-            .with_burst_count(5)
-            .with_burst_interval(0.0);
+            .with_mode(EmissionMode::Burst { count: 5, delay: 0.0, repeats: 1 });
         let mut entity = Entity::new(EntityId(1))
             .with_pos(Vec2::new(100.0, 200.0))
             .with_emitter(emitter);
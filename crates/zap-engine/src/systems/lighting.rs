@@ -6,6 +6,63 @@
 
 use glam::Vec2;
 
+use crate::components::layer::RenderLayer;
+use crate::systems::effects::Rng;
+
+/// Bitflags-style helper for light layer masks — one bit per
+/// [`RenderLayer`], so games compose `LayerMask::OBJECTS |
+/// LayerMask::TERRAIN` instead of error-prone hex literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayerMask(pub u8);
+
+impl LayerMask {
+    pub const BACKGROUND: Self = Self(1 << RenderLayer::Background as u8);
+    pub const TERRAIN: Self = Self(1 << RenderLayer::Terrain as u8);
+    pub const OBJECTS: Self = Self(1 << RenderLayer::Objects as u8);
+    pub const FOREGROUND: Self = Self(1 << RenderLayer::Foreground as u8);
+    pub const VFX: Self = Self(1 << RenderLayer::VFX as u8);
+    pub const UI: Self = Self(1 << RenderLayer::UI as u8);
+    pub const OVERLAY: Self = Self(1 << RenderLayer::Overlay as u8);
+    /// All seven layers — the default for new lights.
+    pub const ALL: Self = Self(0x7F);
+    pub const NONE: Self = Self(0);
+
+    /// Raw mask bits (bits 0-6).
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Whether every bit of `other` is set in this mask.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// This mask with the bits of `other` removed
+    /// (e.g. `LayerMask::ALL.without(LayerMask::TERRAIN)`).
+    pub fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl std::ops::BitOr for LayerMask {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<u8> for LayerMask {
+    fn from(bits: u8) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<RenderLayer> for LayerMask {
+    fn from(layer: RenderLayer) -> Self {
+        Self(1 << layer.as_u8())
+    }
+}
+
 /// A 2D point light with position, color, intensity, radius, and layer mask.
 ///
 /// Wire format (8 floats / 32 bytes):
@@ -20,11 +77,50 @@ pub struct PointLight {
     pub b: f32,
     pub intensity: f32,
     pub radius: f32,
-    /// Bitmask of which layers this light affects (bits 0-5).
-    /// Default: 0x3F (all layers).
+    /// Bitmask of which layers this light affects (bits 0-6, default
+    /// 0x7F = all layers). Bits 7-8 carry the packed [`Falloff`]
+    /// discriminant — same bit-packing scheme as the header's bake state.
     pub layer_mask: f32,
 }
 
+/// Attenuation model for a point light, packed into `layer_mask` bits 7-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Falloff {
+    /// Smooth quadratic window `(1 - d/r)²` — the engine's original curve.
+    #[default]
+    Quadratic,
+    /// Straight-line fade `1 - d/r`; softer edge, dimmer core.
+    Linear,
+    /// Normalized inverse-square — bright core with a long dim tail.
+    InverseSquare,
+}
+
+impl Falloff {
+    /// Wire discriminant as a float (0, 1 or 2).
+    pub fn as_f32(self) -> f32 {
+        self as u32 as f32
+    }
+}
+
+/// Sentinel radius marking the directional-light slot in the wire format.
+/// Point lights always have a positive radius, so the shader can branch
+/// on the sign.
+pub const DIRECTIONAL_RADIUS: f32 = -1.0;
+
+/// An infinite directional light (sun, moon). At most one per scene,
+/// stored apart from the point-light array and emitted in slot 0 of the
+/// light section with [`DIRECTIONAL_RADIUS`] so the shader applies it
+/// uniformly instead of with distance falloff.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalLight {
+    /// Direction the light travels (normalized on set).
+    pub dir: Vec2,
+    /// RGB color (can exceed 1.0 for HDR).
+    pub color: [f32; 3],
+    /// Light strength multiplier.
+    pub intensity: f32,
+}
+
 impl PointLight {
     /// Create a new point light at the given position.
     ///
@@ -41,16 +137,34 @@ impl PointLight {
             b: color[2],
             intensity,
             radius,
-            layer_mask: 0x3F as f32, // All 6 layers by default
+            layer_mask: 0x7F as f32, // All 7 layers by default
         }
     }
 
-    /// Set which layers this light affects.
-    pub fn with_layer_mask(mut self, mask: u8) -> Self {
-        self.layer_mask = mask as f32;
+    /// Set which layers this light affects. Accepts a [`LayerMask`] or a
+    /// raw `u8`. Preserves the falloff bits.
+    pub fn with_layer_mask(mut self, mask: impl Into<LayerMask>) -> Self {
+        let falloff_bits = (self.layer_mask as u32) & !0x7F;
+        self.layer_mask = (falloff_bits | (mask.into().bits() as u32 & 0x7F)) as f32;
         self
     }
 
+    /// Choose the attenuation model (default: [`Falloff::Quadratic`]).
+    pub fn with_falloff(mut self, falloff: Falloff) -> Self {
+        let mask_bits = (self.layer_mask as u32) & 0x7F;
+        self.layer_mask = (mask_bits | ((falloff as u32) << 7)) as f32;
+        self
+    }
+
+    /// The attenuation model packed into the layer mask.
+    pub fn falloff(&self) -> Falloff {
+        match ((self.layer_mask as u32) >> 7) & 0x3 {
+            1 => Falloff::Linear,
+            2 => Falloff::InverseSquare,
+            _ => Falloff::Quadratic,
+        }
+    }
+
     /// Set the position.
     pub fn with_pos(mut self, pos: Vec2) -> Self {
         self.x = pos.x;
@@ -59,21 +173,75 @@ impl PointLight {
     }
 }
 
+/// Intensity modulation for a pulsing or flickering light.
+///
+/// The effective intensity oscillates around the light's base intensity:
+/// `base * (1 + depth * sin(2π * frequency * t + phase))`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PulseParams {
+    /// Oscillation frequency in Hz.
+    pub frequency: f32,
+    /// Modulation depth as a fraction of the base intensity (0.2 = ±20%).
+    pub depth: f32,
+    /// Phase offset in radians — desynchronizes lights sharing a frequency.
+    pub phase: f32,
+}
+
+impl PulseParams {
+    /// A smooth pulse at the given frequency and depth.
+    pub fn new(frequency: f32, depth: f32) -> Self {
+        Self {
+            frequency,
+            depth,
+            phase: 0.0,
+        }
+    }
+
+    /// A fast, seed-randomized flicker (torches, arcs, broken neon).
+    /// The seed picks a frequency in the 8–14 Hz band and a random phase,
+    /// so lights with different seeds never flicker in lockstep.
+    pub fn flicker(seed: u64, amount: f32) -> Self {
+        let mut rng = Rng::new(seed);
+        Self {
+            frequency: 8.0 + rng.next_int(601) as f32 / 100.0,
+            depth: amount,
+            phase: rng.next_int(6284) as f32 / 1000.0,
+        }
+    }
+}
+
+/// A pulse bound to a light, remembering the unmodulated intensity.
+#[derive(Debug, Clone, Copy)]
+struct Pulse {
+    params: PulseParams,
+    base_intensity: f32,
+}
+
 /// Manages active lights and ambient color for the scene.
 ///
 /// Lights are persistent — add them once and they stay until removed.
 /// The ambient color defaults to (1.0, 1.0, 1.0) which produces unlit output
 /// when no lights are present.
 pub struct LightState {
+    /// Wire buffer. When a directional light is set it occupies slot 0,
+    /// encoded as a PointLight with [`DIRECTIONAL_RADIUS`].
     lights: Vec<PointLight>,
+    /// Per-light modulation, kept in lockstep with `lights`.
+    pulses: Vec<Option<Pulse>>,
+    has_directional: bool,
     ambient: [f32; 3],
+    /// Accumulated time driving pulse phases (seconds).
+    time: f32,
 }
 
 impl LightState {
     pub fn new() -> Self {
         Self {
             lights: Vec::new(),
+            pulses: Vec::new(),
+            has_directional: false,
             ambient: [1.0, 1.0, 1.0],
+            time: 0.0,
         }
     }
 
@@ -81,33 +249,128 @@ impl LightState {
     pub fn with_capacity(max_lights: usize) -> Self {
         Self {
             lights: Vec::with_capacity(max_lights),
+            pulses: Vec::with_capacity(max_lights),
+            has_directional: false,
             ambient: [1.0, 1.0, 1.0],
+            time: 0.0,
         }
     }
 
     /// Add a point light to the scene.
     pub fn add(&mut self, light: PointLight) {
         self.lights.push(light);
+        self.pulses.push(None);
     }
 
-    /// Remove all lights.
+    /// Add a point light whose intensity pulses or flickers over time.
+    /// Requires `tick(dt)` to be called each frame (the runner does this).
+    pub fn add_pulsing(&mut self, light: PointLight, params: PulseParams) {
+        let base_intensity = light.intensity;
+        self.lights.push(light);
+        self.pulses.push(Some(Pulse {
+            params,
+            base_intensity,
+        }));
+    }
+
+    /// Advance pulse/flicker modulation by `dt` seconds, updating the
+    /// effective intensity of every pulsing light in place.
+    pub fn tick(&mut self, dt: f32) {
+        self.time += dt;
+        for (light, pulse) in self.lights.iter_mut().zip(&self.pulses) {
+            if let Some(pulse) = pulse {
+                let angle =
+                    std::f32::consts::TAU * pulse.params.frequency * self.time + pulse.params.phase;
+                light.intensity =
+                    pulse.base_intensity * (1.0 + pulse.params.depth * angle.sin());
+            }
+        }
+    }
+
+    /// Remove all lights, including any directional light.
     pub fn clear(&mut self) {
         self.lights.clear();
+        self.pulses.clear();
+        self.has_directional = false;
     }
 
-    /// Get an iterator over active lights.
+    /// Set or disable the scene's directional light. `None` disables it.
+    pub fn set_directional(&mut self, directional: Option<DirectionalLight>) {
+        match directional {
+            Some(d) => {
+                let dir = d.dir.normalize_or_zero();
+                let encoded = PointLight {
+                    x: dir.x,
+                    y: dir.y,
+                    r: d.color[0],
+                    g: d.color[1],
+                    b: d.color[2],
+                    intensity: d.intensity,
+                    radius: DIRECTIONAL_RADIUS,
+                    layer_mask: 0x7F as f32,
+                };
+                if self.has_directional {
+                    self.lights[0] = encoded;
+                } else {
+                    self.lights.insert(0, encoded);
+                    self.pulses.insert(0, None);
+                    self.has_directional = true;
+                }
+            }
+            None => {
+                if self.has_directional {
+                    self.lights.remove(0);
+                    self.pulses.remove(0);
+                    self.has_directional = false;
+                }
+            }
+        }
+    }
+
+    /// Get the current directional light, decoded from its wire slot.
+    pub fn directional(&self) -> Option<DirectionalLight> {
+        if !self.has_directional {
+            return None;
+        }
+        let l = &self.lights[0];
+        Some(DirectionalLight {
+            dir: Vec2::new(l.x, l.y),
+            color: [l.r, l.g, l.b],
+            intensity: l.intensity,
+        })
+    }
+
+    /// Get an iterator over active point lights (excludes the
+    /// directional slot).
     pub fn iter(&self) -> impl Iterator<Item = &PointLight> {
-        self.lights.iter()
+        self.lights.iter().skip(self.point_light_start())
     }
 
-    /// Get a mutable iterator over active lights.
+    /// Get a mutable iterator over active point lights.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut PointLight> {
-        self.lights.iter_mut()
+        let start = self.point_light_start();
+        self.lights.iter_mut().skip(start)
+    }
+
+    /// Remove point lights that don't match a predicate. The directional
+    /// light is unaffected — disable it via `set_directional(None)`.
+    pub fn retain<F: FnMut(&PointLight) -> bool>(&mut self, mut f: F) {
+        let start = self.point_light_start();
+        let keep: Vec<bool> = self
+            .lights
+            .iter()
+            .enumerate()
+            .map(|(i, light)| i < start || f(light))
+            .collect();
+        let mut kept = keep.iter();
+        self.lights.retain(|_| *kept.next().unwrap());
+        let mut kept = keep.iter();
+        self.pulses.retain(|_| *kept.next().unwrap());
     }
 
-    /// Remove lights that don't match a predicate.
-    pub fn retain<F: FnMut(&PointLight) -> bool>(&mut self, f: F) {
-        self.lights.retain(f);
+    /// Index where point lights begin in the wire buffer.
+    fn point_light_start(&self) -> usize {
+        self.has_directional as usize
     }
 
     /// Number of active lights.
@@ -153,7 +416,7 @@ mod tests {
         assert_eq!(light.b, 0.0);
         assert_eq!(light.intensity, 2.0);
         assert_eq!(light.radius, 150.0);
-        assert_eq!(light.layer_mask, 63.0); // 0x3F
+        assert_eq!(light.layer_mask, 127.0); // 0x7F
     }
 
     #[test]
@@ -163,6 +426,50 @@ mod tests {
         assert_eq!(light.layer_mask, 6.0);
     }
 
+    #[test]
+    fn layer_mask_composes_the_pool_game_bits() {
+        // The masks pool-game used to hardcode as raw hex (now with Overlay's bit)
+        assert_eq!(LayerMask::ALL.without(LayerMask::TERRAIN).bits(), 0x7D);
+        assert_eq!(LayerMask::TERRAIN.bits(), 0x02);
+
+        assert_eq!((LayerMask::OBJECTS | LayerMask::TERRAIN).bits(), 0b00_0110);
+        assert_eq!(LayerMask::from(crate::components::layer::RenderLayer::UI).bits(), 0b10_0000);
+        assert!(LayerMask::ALL.contains(LayerMask::VFX));
+        assert!(!LayerMask::NONE.contains(LayerMask::VFX));
+
+        // with_layer_mask accepts the helper and raw u8 alike
+        let a = PointLight::new(Vec2::ZERO, [1.0; 3], 1.0, 50.0)
+            .with_layer_mask(LayerMask::ALL.without(LayerMask::TERRAIN));
+        let b = PointLight::new(Vec2::ZERO, [1.0; 3], 1.0, 50.0).with_layer_mask(0x7D);
+        assert_eq!(a.layer_mask, b.layer_mask);
+    }
+
+    #[test]
+    fn falloff_packs_into_layer_mask_bits() {
+        assert_eq!(Falloff::Quadratic.as_f32(), 0.0);
+        assert_eq!(Falloff::Linear.as_f32(), 1.0);
+        assert_eq!(Falloff::InverseSquare.as_f32(), 2.0);
+
+        // Default keeps the original quadratic curve
+        let light = PointLight::new(Vec2::ZERO, [1.0; 3], 1.0, 50.0);
+        assert_eq!(light.falloff(), Falloff::Quadratic);
+        assert_eq!(light.layer_mask, 127.0);
+
+        // Discriminant lands in bits 7-8 above the 7 layer bits
+        let light = light.with_falloff(Falloff::InverseSquare);
+        assert_eq!(light.layer_mask, (0x7F | (2 << 7)) as f32);
+        assert_eq!(light.falloff(), Falloff::InverseSquare);
+
+        // Layer mask and falloff survive setting each other
+        let light = light.with_layer_mask(0b00_0110);
+        assert_eq!(light.falloff(), Falloff::InverseSquare);
+        assert_eq!((light.layer_mask as u32) & 0x7F, 6);
+
+        let light = light.with_falloff(Falloff::Linear);
+        assert_eq!((light.layer_mask as u32) & 0x7F, 6);
+        assert_eq!(light.falloff(), Falloff::Linear);
+    }
+
     #[test]
     fn light_state_add_and_count() {
         let mut state = LightState::new();
@@ -205,6 +512,111 @@ mod tests {
         assert_eq!(state.count(), 2);
     }
 
+    #[test]
+    fn directional_light_round_trips_through_slot_zero() {
+        let mut state = LightState::new();
+        state.add(PointLight::new(Vec2::ZERO, [1.0; 3], 1.0, 50.0));
+        state.set_directional(Some(DirectionalLight {
+            dir: Vec2::new(0.0, -2.0), // normalized on set
+            color: [1.0, 0.9, 0.7],
+            intensity: 1.5,
+        }));
+
+        // Directional occupies wire slot 0 with the sentinel radius
+        assert_eq!(state.count(), 2);
+        assert_eq!(state.lights[0].radius, DIRECTIONAL_RADIUS);
+        assert_eq!(state.lights[0].y, -1.0);
+
+        let d = state.directional().unwrap();
+        assert_eq!(d.dir, Vec2::new(0.0, -1.0));
+        assert_eq!(d.color, [1.0, 0.9, 0.7]);
+        assert_eq!(d.intensity, 1.5);
+
+        // Point-light iteration skips the directional slot
+        assert_eq!(state.iter().count(), 1);
+        assert_eq!(state.iter().next().unwrap().radius, 50.0);
+
+        // Setting again replaces instead of stacking
+        state.set_directional(Some(DirectionalLight {
+            dir: Vec2::new(1.0, 0.0),
+            color: [0.2; 3],
+            intensity: 0.5,
+        }));
+        assert_eq!(state.count(), 2);
+
+        state.set_directional(None);
+        assert!(state.directional().is_none());
+        assert_eq!(state.count(), 1);
+    }
+
+    #[test]
+    fn clearing_lights_also_clears_directional() {
+        let mut state = LightState::new();
+        state.set_directional(Some(DirectionalLight {
+            dir: Vec2::new(1.0, 0.0),
+            color: [1.0; 3],
+            intensity: 1.0,
+        }));
+        state.clear();
+        assert!(state.directional().is_none());
+        assert_eq!(state.count(), 0);
+    }
+
+    #[test]
+    fn retain_spares_the_directional_slot() {
+        let mut state = LightState::new();
+        state.set_directional(Some(DirectionalLight {
+            dir: Vec2::new(1.0, 0.0),
+            color: [1.0; 3],
+            intensity: 1.0,
+        }));
+        state.add(PointLight::new(Vec2::ZERO, [1.0; 3], 0.1, 50.0));
+
+        state.retain(|l| l.intensity > 0.5);
+        assert!(state.directional().is_some());
+        assert_eq!(state.iter().count(), 0);
+    }
+
+    #[test]
+    fn pulsing_light_oscillates_within_depth() {
+        let mut state = LightState::new();
+        state.add_pulsing(
+            PointLight::new(Vec2::ZERO, [1.0; 3], 2.0, 50.0),
+            PulseParams::new(1.0, 0.25), // 1 Hz, ±25%
+        );
+
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+        for _ in 0..120 {
+            state.tick(1.0 / 60.0); // two full periods
+            let intensity = state.iter().next().unwrap().intensity;
+            min = min.min(intensity);
+            max = max.max(intensity);
+        }
+
+        // Stays inside the depth envelope and actually swings
+        assert!(min >= 2.0 * 0.75 - 1e-3);
+        assert!(max <= 2.0 * 1.25 + 1e-3);
+        assert!(max - min > 2.0 * 0.25);
+    }
+
+    #[test]
+    fn flicker_seeds_desynchronize_lights() {
+        let a = PulseParams::flicker(1, 0.3);
+        let b = PulseParams::flicker(2, 0.3);
+        assert_eq!(a, PulseParams::flicker(1, 0.3)); // deterministic
+        assert!(a.frequency != b.frequency || a.phase != b.phase);
+        assert!(a.frequency >= 8.0 && a.frequency <= 14.0);
+    }
+
+    #[test]
+    fn non_pulsing_lights_keep_their_intensity() {
+        let mut state = LightState::new();
+        state.add(PointLight::new(Vec2::ZERO, [1.0; 3], 3.0, 50.0));
+        state.tick(0.5);
+        assert_eq!(state.iter().next().unwrap().intensity, 3.0);
+    }
+
     #[test]
     fn point_light_is_8_floats() {
         assert_eq!(std::mem::size_of::<PointLight>(), LIGHT_FLOATS * 4);
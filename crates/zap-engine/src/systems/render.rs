@@ -1,9 +1,13 @@
+use glam::Vec2;
+
 use crate::components::entity::Entity;
 use crate::components::layer::RenderLayer;
+use crate::components::sprite::SpriteComponent;
 use crate::renderer::instance::{RenderBuffer, RenderInstance};
 
-/// Describes a contiguous batch of instances sharing the same layer AND atlas.
-/// One batch per (layer, atlas) pair enables N-atlas rendering.
+/// Describes a contiguous batch of instances sharing the same layer, atlas
+/// AND blend mode. One batch per (layer, atlas, blend) triple enables
+/// N-atlas rendering and per-batch blend pipeline selection.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LayerBatch {
     /// Which render layer this batch belongs to.
@@ -14,21 +18,36 @@ pub struct LayerBatch {
     pub end: u32,
     /// Which atlas this batch uses (index into manifest's atlas list).
     pub atlas_id: u32,
+    /// Parallax factor for this batch's layer (1.0 = moves with the world).
+    pub parallax: f32,
 }
 
 impl LayerBatch {
     /// Floats per LayerBatch in the protocol wire format.
-    pub const FLOATS: usize = 4;
+    pub const FLOATS: usize = 5;
 }
 
 /// Build the render buffer from a set of entities.
-/// Sorts entities by (layer, atlas) for layered rendering with N-atlas support.
-/// Returns one LayerBatch per (layer, atlas) pair.
+/// Sorts entities by (layer, atlas, blend) for layered rendering with
+/// N-atlas support. Returns one LayerBatch per (layer, atlas, blend) triple.
 ///
 /// Draw order: layers back-to-front, within each layer atlases in ascending order.
 pub fn build_render_buffer<'a>(
     entities: impl Iterator<Item = &'a Entity>,
     buffer: &mut RenderBuffer,
+) -> Vec<LayerBatch> {
+    build_render_buffer_with_parallax(entities, buffer, Vec2::ZERO, &[1.0; RenderLayer::COUNT])
+}
+
+/// Like [`build_render_buffer`], but offsets each instance by
+/// `camera_offset * (1 - parallax)` for its layer, so background layers
+/// scroll slower than the camera. `parallax = 1.0` leaves a layer untouched;
+/// `parallax = 0.0` pins it to the camera.
+pub fn build_render_buffer_with_parallax<'a>(
+    entities: impl Iterator<Item = &'a Entity>,
+    buffer: &mut RenderBuffer,
+    camera_offset: Vec2,
+    parallax: &[f32; RenderLayer::COUNT],
 ) -> Vec<LayerBatch> {
     buffer.clear();
 
@@ -36,6 +55,8 @@ pub fn build_render_buffer<'a>(
     struct SortEntry {
         layer: RenderLayer,
         atlas: u32,
+        blend: u32,     // Blend mode discriminant — batches must be blend-homogeneous
+        z_order: f32,   // Draw order within a batch (higher on top)
         entity_id: u32, // Tiebreaker for deterministic ordering within batches
         instance: RenderInstance,
     }
@@ -43,7 +64,7 @@ pub fn build_render_buffer<'a>(
     let mut entries: Vec<SortEntry> = Vec::new();
 
     for entity in entities {
-        if !entity.active {
+        if !entity.active || !entity.visible {
             continue;
         }
 
@@ -52,50 +73,77 @@ pub fn build_render_buffer<'a>(
             None => continue,
         };
 
-        let instance = RenderInstance {
-            x: entity.pos.x,
-            y: entity.pos.y,
+        let layer_parallax = parallax[entity.layer.as_u8() as usize];
+        let px = entity.pos.x + camera_offset.x * (1.0 - layer_parallax);
+        let py = entity.pos.y + camera_offset.y * (1.0 - layer_parallax);
+
+        let mut push_entry = |instance: RenderInstance| {
+            entries.push(SortEntry {
+                layer: entity.layer,
+                atlas: sprite.atlas.0,
+                blend: sprite.blend as u32,
+                z_order: entity.z_order,
+                entity_id: entity.id.0,
+                instance,
+            });
+        };
+
+        if let Some(border) = sprite.nine_slice {
+            for instance in nine_slice_instances(entity, sprite, px, py, border) {
+                push_entry(instance);
+            }
+            continue;
+        }
+
+        push_entry(RenderInstance {
+            x: px,
+            y: py,
             rotation: entity.rotation,
             scale: entity.scale.x,
             sprite_col: sprite.col,
             alpha: sprite.alpha,
             cell_span: sprite.cell_span,
             atlas_row: sprite.row,
-        };
-
-        entries.push(SortEntry {
-            layer: entity.layer,
-            atlas: sprite.atlas.0,
-            entity_id: entity.id.0,
-            instance,
+            alpha_cutoff: sprite.alpha_cutoff,
+            tint_r: sprite.tint[0],
+            tint_g: sprite.tint[1],
+            tint_b: sprite.tint[2],
+            scale_y: entity.scale.y,
+            cell_span_y: sprite.cell_span,
+            blend: sprite.blend.as_f32(),
         });
     }
 
-    // Sort by (layer, atlas, entity_id) — deterministic ordering prevents flicker
+    // Sort by (layer, atlas, blend, z_order, entity_id) — deterministic ordering prevents flicker
     // Using unstable sort for ~2x speed; entity_id tiebreaker ensures consistent results
     entries.sort_unstable_by(|a, b| {
         a.layer.cmp(&b.layer)
             .then_with(|| a.atlas.cmp(&b.atlas))
+            .then_with(|| a.blend.cmp(&b.blend))
+            .then_with(|| a.z_order.total_cmp(&b.z_order))
             .then_with(|| a.entity_id.cmp(&b.entity_id))
     });
 
-    // Build buffer and extract batch boundaries — one batch per (layer, atlas) pair
+    // Build buffer and extract batch boundaries — one batch per
+    // (layer, atlas, blend) triple so the JS renderer can switch blend
+    // pipelines per batch.
     let mut batches: Vec<LayerBatch> = Vec::new();
-    let mut current_key: Option<(RenderLayer, u32)> = None;
+    let mut current_key: Option<(RenderLayer, u32, u32)> = None;
     let mut batch_start: u32 = 0;
 
     for entry in &entries {
         let idx = buffer.instance_count();
-        let key = (entry.layer, entry.atlas);
+        let key = (entry.layer, entry.atlas, entry.blend);
 
         if current_key != Some(key) {
             // Close previous batch
-            if let Some((layer, atlas)) = current_key {
+            if let Some((layer, atlas, _)) = current_key {
                 batches.push(LayerBatch {
                     layer,
                     start: batch_start,
                     end: idx,
                     atlas_id: atlas,
+                    parallax: parallax[layer.as_u8() as usize],
                 });
             }
             // Start new batch
@@ -107,12 +155,13 @@ pub fn build_render_buffer<'a>(
     }
 
     // Close final batch
-    if let Some((layer, atlas)) = current_key {
+    if let Some((layer, atlas, _)) = current_key {
         batches.push(LayerBatch {
             layer,
             start: batch_start,
             end: buffer.instance_count(),
             atlas_id: atlas,
+            parallax: parallax[layer.as_u8() as usize],
         });
     }
 
@@ -127,11 +176,76 @@ pub fn build_render_buffer<'a>(
     batches
 }
 
+/// Expand a 9-slice sprite into nine sub-quad instances.
+///
+/// Corner slices keep a fixed size (border fraction × the smaller scale
+/// axis) so they never distort; edge and center slices stretch to fill the
+/// entity's scale. Border fractions address sub-cell UV regions via
+/// fractional `sprite_col`/`atlas_row`/`cell_span` values.
+fn nine_slice_instances(
+    entity: &Entity,
+    sprite: &SpriteComponent,
+    px: f32,
+    py: f32,
+    border: [f32; 4],
+) -> Vec<RenderInstance> {
+    let [left, right, top, bottom] = border;
+    let w = entity.scale.x;
+    let h = entity.scale.y;
+    // Corners scale with the smaller axis so they stay square
+    let corner_ref = w.min(h);
+
+    // Columns left→right: world widths and cell-space U offsets/spans
+    let col_widths = [left * corner_ref, w - (left + right) * corner_ref, right * corner_ref];
+    let u_offsets = [0.0, left, 1.0 - right];
+    let u_spans = [left, 1.0 - left - right, right];
+
+    // Rows bottom→top. The quad maps texture top to world bottom (matching
+    // single-quad rendering), so the `top` border fraction comes first.
+    let row_heights = [top * corner_ref, h - (top + bottom) * corner_ref, bottom * corner_ref];
+    let v_offsets = [0.0, top, 1.0 - bottom];
+    let v_spans = [top, 1.0 - top - bottom, bottom];
+
+    let (sin_r, cos_r) = entity.rotation.sin_cos();
+    let mut instances = Vec::with_capacity(9);
+
+    let mut y_cursor = -h / 2.0;
+    for row in 0..3 {
+        let mut x_cursor = -w / 2.0;
+        for col in 0..3 {
+            // Slice center offset from the entity center, rotated with it
+            let dx = x_cursor + col_widths[col] / 2.0;
+            let dy = y_cursor + row_heights[row] / 2.0;
+            instances.push(RenderInstance {
+                x: px + dx * cos_r - dy * sin_r,
+                y: py + dx * sin_r + dy * cos_r,
+                rotation: entity.rotation,
+                scale: col_widths[col],
+                sprite_col: sprite.col + u_offsets[col] * sprite.cell_span,
+                alpha: sprite.alpha,
+                cell_span: u_spans[col] * sprite.cell_span,
+                atlas_row: sprite.row + v_offsets[row] * sprite.cell_span,
+                alpha_cutoff: sprite.alpha_cutoff,
+                tint_r: sprite.tint[0],
+                tint_g: sprite.tint[1],
+                tint_b: sprite.tint[2],
+                scale_y: row_heights[row],
+                cell_span_y: v_spans[row] * sprite.cell_span,
+                blend: sprite.blend.as_f32(),
+            });
+            x_cursor += col_widths[col];
+        }
+        y_cursor += row_heights[row];
+    }
+
+    instances
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::types::EntityId;
-    use crate::components::sprite::{AtlasId, SpriteComponent};
+    use crate::components::sprite::AtlasId;
     use glam::Vec2;
 
     #[test]
@@ -348,6 +462,236 @@ mod tests {
         assert_eq!(buffer.atlas_split, 1);
     }
 
+    #[test]
+    fn alpha_cutoff_packs_into_instance() {
+        let entities = vec![
+            // Default sprite — no discard
+            Entity::new(EntityId(1)).with_sprite(SpriteComponent::default()),
+            // Cutout sprite
+            Entity::new(EntityId(2)).with_sprite(SpriteComponent {
+                alpha_cutoff: 0.5,
+                ..Default::default()
+            }),
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(entities.iter(), &mut buffer);
+
+        assert_eq!(buffer.instances[0].alpha_cutoff, 0.0);
+        assert_eq!(buffer.instances[1].alpha_cutoff, 0.5);
+    }
+
+    #[test]
+    fn blend_modes_split_batches() {
+        use crate::components::sprite::BlendMode;
+        let entities = vec![
+            // Same layer and atlas, different blend modes
+            Entity::new(EntityId(1)).with_sprite(SpriteComponent::default()),
+            Entity::new(EntityId(2)).with_sprite(SpriteComponent {
+                blend: BlendMode::Additive,
+                ..Default::default()
+            }),
+            Entity::new(EntityId(3)).with_sprite(SpriteComponent::default()),
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        let batches = build_render_buffer(entities.iter(), &mut buffer);
+
+        // Alpha instances batch before additive ones
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].end - batches[0].start, 2);
+        assert_eq!(batches[1].end - batches[1].start, 1);
+
+        // Each instance carries its blend discriminant on the wire
+        assert_eq!(buffer.instances[0].blend, BlendMode::Alpha.as_f32());
+        assert_eq!(buffer.instances[1].blend, BlendMode::Alpha.as_f32());
+        assert_eq!(buffer.instances[2].blend, BlendMode::Additive.as_f32());
+    }
+
+    #[test]
+    fn invisible_entities_are_skipped_but_stay_in_the_scene() {
+        use crate::core::scene::Scene;
+
+        let mut scene = Scene::new();
+        scene.spawn(Entity::new(EntityId(1)).with_sprite(SpriteComponent::default()));
+        scene.spawn(
+            Entity::new(EntityId(2))
+                .with_visible(false)
+                .with_sprite(SpriteComponent::default()),
+        );
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(scene.iter(), &mut buffer);
+
+        // Only the visible entity is packed, but both remain in the scene
+        assert_eq!(buffer.instance_count(), 1);
+        assert_eq!(scene.len(), 2);
+        assert!(scene.contains(EntityId(2)));
+
+        // Toggling back on restores rendering without a respawn
+        scene.get_mut(EntityId(2)).unwrap().set_visible(true);
+        build_render_buffer(scene.iter(), &mut buffer);
+        assert_eq!(buffer.instance_count(), 2);
+    }
+
+    #[test]
+    fn z_order_sorts_instances_within_a_layer() {
+        let entities = vec![
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(1.0, 0.0))
+                .with_z_order(5.0)
+                .with_sprite(SpriteComponent::default()),
+            Entity::new(EntityId(2))
+                .with_pos(Vec2::new(2.0, 0.0))
+                .with_z_order(-1.0)
+                .with_sprite(SpriteComponent::default()),
+            Entity::new(EntityId(3))
+                .with_pos(Vec2::new(3.0, 0.0))
+                .with_sprite(SpriteComponent::default()), // default z_order 0.0
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(entities.iter(), &mut buffer);
+
+        // Packed back-to-front: z=-1, z=0, z=5
+        assert_eq!(buffer.instances[0].x, 2.0);
+        assert_eq!(buffer.instances[1].x, 3.0);
+        assert_eq!(buffer.instances[2].x, 1.0);
+    }
+
+    #[test]
+    fn z_order_ties_keep_id_order() {
+        // Same z_order — the entity_id tiebreaker keeps spawn order
+        let entities = vec![
+            Entity::new(EntityId(2))
+                .with_pos(Vec2::new(2.0, 0.0))
+                .with_sprite(SpriteComponent::default()),
+            Entity::new(EntityId(1))
+                .with_pos(Vec2::new(1.0, 0.0))
+                .with_sprite(SpriteComponent::default()),
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(entities.iter(), &mut buffer);
+
+        assert_eq!(buffer.instances[0].x, 1.0);
+        assert_eq!(buffer.instances[1].x, 2.0);
+    }
+
+    #[test]
+    fn tint_packs_into_trailing_instance_floats() {
+        let entities = vec![
+            // Default sprite — identity white tint
+            Entity::new(EntityId(1)).with_sprite(SpriteComponent::default()),
+            // Red team tint
+            Entity::new(EntityId(2))
+                .with_sprite(SpriteComponent::default())
+                .with_tint(1.0, 0.2, 0.2),
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(entities.iter(), &mut buffer);
+
+        // Wire layout: tint occupies floats 9..12 of each instance
+        let floats: &[f32] = bytemuck::cast_slice(&buffer.instances);
+        assert_eq!(&floats[9..12], &[1.0, 1.0, 1.0]);
+        let base = RenderInstance::FLOATS;
+        assert_eq!(&floats[base + 9..base + 12], &[1.0, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn parallax_offsets_layers_against_the_camera() {
+        let entities = vec![
+            Entity::new(EntityId(1))
+                .with_layer(RenderLayer::Background)
+                .with_pos(Vec2::new(100.0, 100.0))
+                .with_sprite(SpriteComponent::default()),
+            Entity::new(EntityId(2))
+                .with_layer(RenderLayer::Objects)
+                .with_pos(Vec2::new(100.0, 100.0))
+                .with_sprite(SpriteComponent::default()),
+        ];
+
+        let mut parallax = [1.0; RenderLayer::COUNT];
+        parallax[RenderLayer::Background.as_u8() as usize] = 0.5;
+
+        let mut buffer = RenderBuffer::new();
+        let camera_offset = Vec2::new(200.0, -40.0);
+        let batches =
+            build_render_buffer_with_parallax(entities.iter(), &mut buffer, camera_offset, &parallax);
+
+        // Background scrolls at half camera speed: offset by camera * (1 - 0.5)
+        assert_eq!(buffer.instances[0].x, 100.0 + 200.0 * 0.5);
+        assert_eq!(buffer.instances[0].y, 100.0 - 40.0 * 0.5);
+        // Objects layer (parallax 1.0) stays at its world position
+        assert_eq!(buffer.instances[1].x, 100.0);
+        assert_eq!(buffer.instances[1].y, 100.0);
+
+        // Batches carry the effective parallax for the JS renderer
+        assert_eq!(batches[0].parallax, 0.5);
+        assert_eq!(batches[1].parallax, 1.0);
+    }
+
+    #[test]
+    fn nine_slice_sprite_emits_nine_quads() {
+        let entities = vec![
+            Entity::new(EntityId(1))
+                .with_scale(Vec2::new(300.0, 100.0))
+                .with_sprite(SpriteComponent {
+                    nine_slice: Some([0.25, 0.25, 0.25, 0.25]),
+                    ..Default::default()
+                }),
+            Entity::new(EntityId(2)).with_sprite(SpriteComponent::default()),
+        ];
+
+        let mut buffer = RenderBuffer::new();
+        let batches = build_render_buffer(entities.iter(), &mut buffer);
+
+        // Nine quads for the panel plus one for the plain sprite
+        assert_eq!(buffer.instance_count(), 10);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].end, 10);
+    }
+
+    #[test]
+    fn nine_slice_corners_keep_size_while_edges_stretch() {
+        let entities = vec![Entity::new(EntityId(1))
+            .with_scale(Vec2::new(300.0, 100.0))
+            .with_sprite(SpriteComponent {
+                nine_slice: Some([0.2, 0.2, 0.2, 0.2]),
+                ..Default::default()
+            })];
+
+        let mut buffer = RenderBuffer::new();
+        build_render_buffer(entities.iter(), &mut buffer);
+
+        // Corner size = border fraction × min(scale.x, scale.y)
+        let corner = &buffer.instances[0]; // bottom-left
+        assert_eq!(corner.scale, 0.2 * 100.0);
+        assert_eq!(corner.scale_y, 0.2 * 100.0);
+        assert_eq!(corner.cell_span, 0.2);
+        assert_eq!(corner.cell_span_y, 0.2);
+
+        // Bottom edge stretches horizontally but keeps the border height
+        let edge = &buffer.instances[1];
+        assert_eq!(edge.scale, 300.0 - 2.0 * 0.2 * 100.0);
+        assert_eq!(edge.scale_y, 0.2 * 100.0);
+
+        // Center fills the remaining area
+        let center = &buffer.instances[4];
+        assert_eq!(center.scale, 300.0 - 2.0 * 0.2 * 100.0);
+        assert_eq!(center.scale_y, 100.0 - 2.0 * 0.2 * 100.0);
+        // Center UV starts past the left/top borders
+        assert_eq!(center.sprite_col, 0.2);
+        assert_eq!(center.atlas_row, 0.2);
+
+        // Slice positions span the entity's extent symmetrically
+        assert_eq!(buffer.instances[0].x, -150.0 + 10.0);
+        assert_eq!(buffer.instances[8].x, 150.0 - 10.0);
+        assert_eq!(center.x, 0.0);
+        assert_eq!(center.y, 0.0);
+    }
+
     #[test]
     fn empty_entities_produces_no_batches() {
         let entities: Vec<Entity> = vec![];
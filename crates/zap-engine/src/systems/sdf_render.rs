@@ -1,6 +1,7 @@
 use crate::components::entity::Entity;
 use crate::components::mesh::SDFShape;
 use crate::renderer::sdf_instance::{SDFBuffer, SDFInstance};
+use glam::Vec2;
 
 /// Build the SDF instance buffer from entities with mesh components.
 pub fn build_sdf_buffer<'a>(
@@ -9,17 +10,79 @@ pub fn build_sdf_buffer<'a>(
 ) {
     buffer.clear();
     for entity in entities {
-        if !entity.active {
+        if !entity.active || !entity.visible {
             continue;
         }
         let mesh = match &entity.mesh {
             Some(m) => m,
             None => continue,
         };
-        let (radius, shape_type, half_height, extra) = match mesh.shape {
-            SDFShape::Sphere { radius } => (radius, 0.0, 0.0, mesh.extra),
-            SDFShape::Capsule { radius, half_height } => (radius, 1.0, half_height, 0.0),
-            SDFShape::RoundedBox { radius, half_height, corner_radius } => (radius, 2.0, half_height, corner_radius),
+        let (radius, shape_type, half_height, extra) = match &mesh.shape {
+            SDFShape::Sphere { radius } => (*radius, 0.0, 0.0, mesh.extra),
+            SDFShape::Capsule { radius, half_height } => (*radius, 1.0, *half_height, 0.0),
+            SDFShape::RoundedBox { radius, half_height, corner_radius } => {
+                (*radius, 2.0, *half_height, *corner_radius)
+            }
+            SDFShape::Box { half_extents, corner_radius } => {
+                (half_extents.x, 3.0, half_extents.y, *corner_radius)
+            }
+            SDFShape::Segment { a, b, thickness } => {
+                // A segment is a capsule whose axis runs a → b: the instance
+                // centers on the midpoint and rotates the capsule's local +Y
+                // axis onto the segment direction.
+                let mid = (*a + *b) * 0.5;
+                let delta = *b - *a;
+                let (sin_r, cos_r) = entity.rotation.sin_cos();
+                let world_mid = entity.pos
+                    + Vec2::new(mid.x * cos_r - mid.y * sin_r, mid.x * sin_r + mid.y * cos_r);
+                buffer.push(SDFInstance {
+                    x: world_mid.x,
+                    y: world_mid.y,
+                    radius: *thickness,
+                    rotation: entity.rotation + (-delta.x).atan2(delta.y),
+                    r: mesh.color.r,
+                    g: mesh.color.g,
+                    b: mesh.color.b,
+                    shininess: mesh.shininess,
+                    emissive: mesh.emissive,
+                    shape_type: 4.0,
+                    half_height: delta.length() * 0.5,
+                    outline_width: mesh.outline_width,
+                    outline_r: mesh.outline_color.r,
+                    outline_g: mesh.outline_color.g,
+                    outline_b: mesh.outline_color.b,
+                    ..Default::default()
+                });
+                continue;
+            }
+            SDFShape::Combine { op, a, b, smoothing } => {
+                let (ra, ta, ha, ea) = primitive_params(a);
+                let (rb, tb, hb, eb) = primitive_params(b);
+                buffer.push(SDFInstance {
+                    x: entity.pos.x,
+                    y: entity.pos.y,
+                    radius: ra,
+                    rotation: entity.rotation,
+                    r: mesh.color.r,
+                    g: mesh.color.g,
+                    b: mesh.color.b,
+                    shininess: mesh.shininess,
+                    emissive: mesh.emissive,
+                    // Pack shape_a (bits 0-2), shape_b (bits 3-5), op (bits 6-7)
+                    shape_type: ta + tb * 8.0 + (*op as u8 as f32) * 64.0,
+                    half_height: ha,
+                    extra: ea,
+                    radius_b: rb,
+                    half_height_b: hb,
+                    extra_b: eb,
+                    smoothing: *smoothing,
+                    outline_width: mesh.outline_width,
+                    outline_r: mesh.outline_color.r,
+                    outline_g: mesh.outline_color.g,
+                    outline_b: mesh.outline_color.b,
+                });
+                continue;
+            }
         };
         buffer.push(SDFInstance {
             x: entity.pos.x,
@@ -34,15 +97,42 @@ pub fn build_sdf_buffer<'a>(
             shape_type,
             half_height,
             extra,
+            outline_width: mesh.outline_width,
+            outline_r: mesh.outline_color.r,
+            outline_g: mesh.outline_color.g,
+            outline_b: mesh.outline_color.b,
+            ..Default::default()
         });
     }
 }
 
+/// Packing parameters (radius, shape code, half_height, extra) of a
+/// primitive shape. Nested combines cannot be represented in the
+/// instance format, so they collapse to their left child.
+fn primitive_params(shape: &SDFShape) -> (f32, f32, f32, f32) {
+    match shape {
+        SDFShape::Sphere { radius } => (*radius, 0.0, 0.0, 0.0),
+        SDFShape::Capsule { radius, half_height } => (*radius, 1.0, *half_height, 0.0),
+        SDFShape::RoundedBox { radius, half_height, corner_radius } => {
+            (*radius, 2.0, *half_height, *corner_radius)
+        }
+        SDFShape::Box { half_extents, corner_radius } => {
+            (half_extents.x, 3.0, half_extents.y, *corner_radius)
+        }
+        // Segments lose their endpoints inside a combine — treat them as a
+        // capsule centered on the instance.
+        SDFShape::Segment { a, b, thickness } => {
+            (*thickness, 1.0, (*b - *a).length() * 0.5, 0.0)
+        }
+        SDFShape::Combine { a, .. } => primitive_params(a),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::types::EntityId;
-    use crate::components::mesh::{MeshComponent, SDFColor, SDFShape};
+    use crate::components::mesh::{MeshComponent, SDFColor, SDFShape, SdfOp};
     use glam::Vec2;
 
     #[test]
@@ -128,6 +218,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_sdf_buffer_subtract_combination() {
+        let entity = Entity::new(EntityId(1))
+            .with_pos(Vec2::ZERO)
+            .with_mesh(MeshComponent::combine(
+                SdfOp::Subtract,
+                SDFShape::Sphere { radius: 20.0 },
+                SDFShape::Capsule { radius: 6.0, half_height: 25.0 },
+                4.0,
+                SDFColor::new(1.0, 0.5, 0.0),
+            ));
+
+        let entities = vec![entity];
+        let mut buffer = SDFBuffer::new();
+        build_sdf_buffer(entities.iter(), &mut buffer);
+        assert_eq!(buffer.instance_count(), 1);
+
+        let ptr = buffer.instances_ptr();
+        unsafe {
+            // shape_type packs sphere (0) + capsule (1 << 3) + subtract (2 << 6)
+            assert_eq!(*ptr.add(9), 8.0 + 128.0);
+            // Shape a params
+            assert_eq!(*ptr.add(2), 20.0);  // radius
+            assert_eq!(*ptr.add(10), 0.0);  // half_height
+            assert_eq!(*ptr.add(11), 0.0);  // extra
+            // Shape b params
+            assert_eq!(*ptr.add(12), 6.0);  // radius_b
+            assert_eq!(*ptr.add(13), 25.0); // half_height_b
+            assert_eq!(*ptr.add(14), 0.0);  // extra_b
+            assert_eq!(*ptr.add(15), 4.0);  // smoothing
+        }
+    }
+
+    #[test]
+    fn build_sdf_buffer_box_and_segment() {
+        let boxy = Entity::new(EntityId(1)).with_mesh(MeshComponent::r#box(
+            Vec2::new(20.0, 8.0),
+            3.0,
+            SDFColor::default(),
+        ));
+        let seg = Entity::new(EntityId(2))
+            .with_pos(Vec2::new(100.0, 50.0))
+            .with_mesh(MeshComponent::segment(
+                Vec2::new(-10.0, 0.0),
+                Vec2::new(10.0, 0.0),
+                2.0,
+                SDFColor::default(),
+            ));
+
+        let entities = vec![boxy, seg];
+        let mut buffer = SDFBuffer::new();
+        build_sdf_buffer(entities.iter(), &mut buffer);
+        assert_eq!(buffer.instance_count(), 2);
+
+        let ptr = buffer.instances_ptr();
+        unsafe {
+            // Box: half_extents.x → radius, half_extents.y → half_height
+            assert_eq!(*ptr.add(2), 20.0);
+            assert_eq!(*ptr.add(9), 3.0); // shape_type = Box
+            assert_eq!(*ptr.add(10), 8.0);
+            assert_eq!(*ptr.add(11), 3.0); // corner_radius
+
+            // Segment: centered on midpoint, rotated so local +Y runs a → b
+            let base = SDFInstance::FLOATS;
+            assert_eq!(*ptr.add(base), 100.0); // midpoint x (a+b centered on entity)
+            assert_eq!(*ptr.add(base + 1), 50.0);
+            assert_eq!(*ptr.add(base + 2), 2.0); // thickness → radius
+            assert!((*ptr.add(base + 3) + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+            assert_eq!(*ptr.add(base + 9), 4.0); // shape_type = Segment
+            assert_eq!(*ptr.add(base + 10), 10.0); // half-length
+        }
+    }
+
+    #[test]
+    fn build_sdf_buffer_outline_packs_and_defaults_disabled() {
+        let plain = Entity::new(EntityId(1))
+            .with_mesh(MeshComponent::sphere(10.0, SDFColor::default()));
+        let outlined = Entity::new(EntityId(2)).with_mesh(
+            MeshComponent::sphere(10.0, SDFColor::default())
+                .with_outline(2.5, SDFColor::new(1.0, 0.8, 0.2)),
+        );
+
+        let entities = vec![plain, outlined];
+        let mut buffer = SDFBuffer::new();
+        build_sdf_buffer(entities.iter(), &mut buffer);
+        assert_eq!(buffer.instance_count(), 2);
+
+        let ptr = buffer.instances_ptr();
+        unsafe {
+            // Default mesh: outline disabled (width 0)
+            assert_eq!(*ptr.add(16), 0.0);
+            // Second instance: width + RGB at slots 16-19
+            let base = SDFInstance::FLOATS;
+            assert_eq!(*ptr.add(base + 16), 2.5);
+            assert_eq!(*ptr.add(base + 17), 1.0);
+            assert_eq!(*ptr.add(base + 18), 0.8);
+            assert_eq!(*ptr.add(base + 19), 0.2);
+        }
+    }
+
     #[test]
     fn build_sdf_buffer_skips_inactive_and_no_mesh() {
         let e1 = Entity::new(EntityId(1)); // no mesh
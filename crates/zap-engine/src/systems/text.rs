@@ -11,6 +11,7 @@ use crate::components::entity::Entity;
 use crate::components::sprite::{AtlasId, BlendMode, SpriteComponent};
 use crate::core::scene::Scene;
 use glam::Vec2;
+use std::collections::HashMap;
 
 /// Configuration for a bitmap font atlas.
 ///
@@ -28,6 +29,10 @@ pub struct FontConfig {
     pub start_char: u8,
     /// Horizontal advance as fraction of character size (e.g., 0.55 for tight, 1.0 for monospace).
     pub spacing: f32,
+    /// Optional per-glyph advances as fractions of character size
+    /// (e.g., 0.3 for 'i', 0.7 for 'W'). Characters without an entry
+    /// fall back to `spacing`.
+    pub advances: Option<HashMap<char, f32>>,
 }
 
 impl Default for FontConfig {
@@ -38,6 +43,7 @@ impl Default for FontConfig {
             rows: 6,
             start_char: 32, // space
             spacing: 0.55,
+            advances: None,
         }
     }
 }
@@ -69,6 +75,28 @@ impl FontConfig {
         self.spacing = spacing;
         self
     }
+
+    /// Set per-glyph advances (fractions of character size).
+    pub fn with_advances(mut self, advances: HashMap<char, f32>) -> Self {
+        self.advances = Some(advances);
+        self
+    }
+}
+
+/// Horizontal advance of one character in world units: the per-glyph
+/// fraction when the font defines one, otherwise the fixed `spacing`.
+fn char_advance(c: char, size: f32, font: &FontConfig) -> f32 {
+    let fraction = font
+        .advances
+        .as_ref()
+        .and_then(|m| m.get(&c).copied())
+        .unwrap_or(font.spacing);
+    size * fraction
+}
+
+/// Total advance of a line of text in world units.
+fn line_width(line: &str, size: f32, font: &FontConfig) -> f32 {
+    line.chars().map(|c| char_advance(c, size, font)).sum()
 }
 
 /// Convert an ASCII character to grid coordinates (col, row) in the font atlas.
@@ -134,12 +162,117 @@ where
                     row,
                     cell_span: 1.0,
                     alpha: 1.0,
+                    alpha_cutoff: 0.0,
+                    tint: [1.0, 1.0, 1.0],
+                    nine_slice: None,
                     blend: BlendMode::Alpha,
                 });
             entities.push(entity);
         }
         // Always advance cursor (even for skipped chars, to preserve spacing)
-        cursor_x += size * font.spacing;
+        cursor_x += char_advance(c, size, font);
+    }
+
+    entities
+}
+
+/// Horizontal alignment for multi-line text layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Greedy word wrap: breaks on spaces so no line's summed advances
+/// exceed `max_width`. A single word longer than the limit gets its own
+/// line rather than being split mid-word.
+fn wrap_lines(text: &str, size: f32, font: &FontConfig, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for source_line in text.split('\n') {
+        let mut current = String::new();
+        for word in source_line.split(' ') {
+            if !current.is_empty() {
+                let candidate = line_width(&current, size, font)
+                    + char_advance(' ', size, font)
+                    + line_width(word, size, font);
+                if candidate > max_width {
+                    lines.push(std::mem::take(&mut current));
+                } else {
+                    current.push(' ');
+                }
+            }
+            current.push_str(word);
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// Build character entities with alignment and optional word wrapping.
+///
+/// Like [`build_text_entities`], but lays out multiple lines: explicit
+/// `\n` always breaks, and when `max_width` is given lines also wrap on
+/// spaces. Per-line widths come from the font's advance
+/// (`size * font.spacing`); alignment offsets each line within
+/// `max_width`. Without `max_width`, `pos.x` is the anchor instead:
+/// centered lines straddle it and right-aligned lines end at it.
+/// Lines advance downward by `size`.
+#[allow(clippy::too_many_arguments)]
+pub fn build_text_entities_ex<F>(
+    text: &str,
+    pos: Vec2,
+    size: f32,
+    font: &FontConfig,
+    tag: &str,
+    align: TextAlign,
+    max_width: Option<f32>,
+    id_gen: &mut F,
+) -> Vec<Entity>
+where
+    F: FnMut() -> EntityId,
+{
+    let lines = match max_width {
+        Some(width) => wrap_lines(text, size, font, width),
+        None => text.split('\n').map(str::to_owned).collect(),
+    };
+
+    let mut entities = Vec::new();
+    for (line_index, line) in lines.iter().enumerate() {
+        let width = line_width(line, size, font);
+        let box_width = max_width.unwrap_or(0.0);
+        let offset_x = match align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => (box_width - width) / 2.0,
+            TextAlign::Right => box_width - width,
+        };
+
+        let line_y = pos.y + line_index as f32 * size;
+        let mut cursor_x = pos.x + offset_x;
+        for c in line.chars() {
+            if let Some((col, row)) = char_to_grid(c, font) {
+                let id = id_gen();
+                let entity = Entity::new(id)
+                    .with_tag(tag)
+                    .with_pos(Vec2::new(cursor_x + size / 2.0, line_y + size / 2.0))
+                    .with_scale(Vec2::splat(size))
+                    .with_sprite(SpriteComponent {
+                        atlas: font.atlas,
+                        col,
+                        row,
+                        cell_span: 1.0,
+                        alpha: 1.0,
+                        alpha_cutoff: 0.0,
+                        tint: [1.0, 1.0, 1.0],
+                        nine_slice: None,
+                        blend: BlendMode::Alpha,
+                    });
+                entities.push(entity);
+            }
+            // Always advance cursor (even for skipped chars, to preserve spacing)
+            cursor_x += char_advance(c, size, font);
+        }
     }
 
     entities
@@ -172,6 +305,7 @@ mod tests {
             rows: 6,
             start_char: 32,
             spacing: 0.55,
+            advances: None,
         }
     }
 
@@ -252,6 +386,107 @@ mod tests {
         assert_eq!(i_sprite.row, 4.0);
     }
 
+    fn make_monospaced_font() -> FontConfig {
+        // spacing 0.5 keeps the advance math round: size 20 → advance 10
+        make_default_font().with_spacing(0.5)
+    }
+
+    fn sequential_ids() -> impl FnMut() -> EntityId {
+        let mut next = 1u32;
+        move || {
+            let id = EntityId(next);
+            next += 1;
+            id
+        }
+    }
+
+    #[test]
+    fn per_glyph_advances_place_characters_non_uniformly() {
+        let font = make_monospaced_font()
+            .with_advances(HashMap::from([('i', 0.25), ('W', 1.0)]));
+        // size 20: 'i' advances 5, 'W' advances 20, others 10
+        let entities = build_text_entities("iWA", Vec2::ZERO, 20.0, &font, "t", &mut sequential_ids());
+        assert_eq!(entities.len(), 3);
+        assert_eq!(entities[0].pos.x, 10.0, "'i' centered in first cell");
+        assert_eq!(entities[1].pos.x, 15.0, "'W' starts after the narrow 'i'");
+        assert_eq!(entities[2].pos.x, 35.0, "'A' starts after the wide 'W'");
+    }
+
+    #[test]
+    fn centered_text_offsets_by_half_leftover_width() {
+        let font = make_monospaced_font();
+        // "AB" is 2 chars × advance 10 = 20 wide; leftover = 100 - 20 = 80
+        let entities = build_text_entities_ex(
+            "AB",
+            Vec2::ZERO,
+            20.0,
+            &font,
+            "t",
+            TextAlign::Center,
+            Some(100.0),
+            &mut sequential_ids(),
+        );
+        assert_eq!(entities.len(), 2);
+        // First char: half the leftover (40) + half a cell (10)
+        assert_eq!(entities[0].pos.x, 50.0);
+    }
+
+    #[test]
+    fn right_aligned_text_ends_at_the_box_edge() {
+        let font = make_monospaced_font();
+        let entities = build_text_entities_ex(
+            "AB",
+            Vec2::ZERO,
+            20.0,
+            &font,
+            "t",
+            TextAlign::Right,
+            Some(100.0),
+            &mut sequential_ids(),
+        );
+        // Offset = full leftover (80), first char center at 80 + 10
+        assert_eq!(entities[0].pos.x, 90.0);
+    }
+
+    #[test]
+    fn wrapping_breaks_on_the_expected_word() {
+        let font = make_monospaced_font();
+        // advance 10; "AA BB" = 50 fits in 60, "AA BB CC" = 80 does not
+        let entities = build_text_entities_ex(
+            "AA BB CC",
+            Vec2::ZERO,
+            20.0,
+            &font,
+            "t",
+            TextAlign::Left,
+            Some(60.0),
+            &mut sequential_ids(),
+        );
+        // Line 1: "AA BB" (5 glyphs incl. space), line 2: "CC"
+        assert_eq!(entities.len(), 7);
+        let first_c = &entities[5];
+        assert_eq!(first_c.pos.x, 10.0, "second line restarts at the left edge");
+        assert_eq!(first_c.pos.y, 30.0, "second line sits one size below");
+    }
+
+    #[test]
+    fn explicit_newline_always_breaks() {
+        let font = make_monospaced_font();
+        let entities = build_text_entities_ex(
+            "A\nB",
+            Vec2::ZERO,
+            20.0,
+            &font,
+            "t",
+            TextAlign::Left,
+            None,
+            &mut sequential_ids(),
+        );
+        assert_eq!(entities.len(), 2);
+        assert_eq!(entities[0].pos.y, 10.0);
+        assert_eq!(entities[1].pos.y, 30.0);
+    }
+
     #[test]
     fn spawn_text_skips_unprintable() {
         let font = make_default_font();
@@ -108,12 +108,33 @@ impl VectorColor {
     pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
 }
 
+impl VectorColor {
+    /// Linearly interpolate toward `other` by `t` (0.0 = self, 1.0 = other).
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+            a: self.a + (other.a - self.a) * t,
+        }
+    }
+}
+
 impl Default for VectorColor {
     fn default() -> Self {
         Self::WHITE
     }
 }
 
+/// Direction of a linear gradient fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientAxis {
+    /// `color_a` on the left edge, `color_b` on the right.
+    Horizontal,
+    /// `color_a` on the bottom edge, `color_b` on the top.
+    Vertical,
+}
+
 /// Vertex constructor for lyon fill tessellation.
 struct FillVertexCtor {
     color: VectorColor,
@@ -237,6 +258,74 @@ impl VectorState {
         self.fill_polygon(&points, color);
     }
 
+    /// Fill a rectangle with a linear gradient.
+    ///
+    /// The GPU interpolates per-vertex colors, so a single quad replaces
+    /// the stacked-translucent-shape approximation games resorted to.
+    pub fn fill_rect_gradient(
+        &mut self,
+        origin: Vec2,
+        width: f32,
+        height: f32,
+        color_a: VectorColor,
+        color_b: VectorColor,
+        axis: GradientAxis,
+    ) {
+        let bl = origin;
+        let br = Vec2::new(origin.x + width, origin.y);
+        let tr = Vec2::new(origin.x + width, origin.y + height);
+        let tl = Vec2::new(origin.x, origin.y + height);
+
+        let (c_bl, c_br, c_tr, c_tl) = match axis {
+            GradientAxis::Horizontal => (color_a, color_b, color_b, color_a),
+            GradientAxis::Vertical => (color_a, color_a, color_b, color_b),
+        };
+
+        // Two triangles, colors assigned per corner
+        self.push_vertex(bl, c_bl);
+        self.push_vertex(br, c_br);
+        self.push_vertex(tr, c_tr);
+        self.push_vertex(bl, c_bl);
+        self.push_vertex(tr, c_tr);
+        self.push_vertex(tl, c_tl);
+    }
+
+    /// Fill a circle with a radial gradient from `inner_color` at the
+    /// center to `outer_color` on the rim (coronas, glows, vignettes).
+    pub fn fill_circle_radial(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        inner_color: VectorColor,
+        outer_color: VectorColor,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+
+        // Segment count for chord error below the fill tolerance (0.5),
+        // matching the fidelity of lyon's circle tessellation.
+        let tolerance = 0.5_f32;
+        let step = 2.0 * (1.0 - tolerance / radius.max(tolerance)).acos();
+        let segments = (std::f32::consts::TAU / step).ceil().max(8.0) as usize;
+
+        for i in 0..segments {
+            let a0 = i as f32 / segments as f32 * std::f32::consts::TAU;
+            let a1 = (i + 1) as f32 / segments as f32 * std::f32::consts::TAU;
+            let p0 = center + Vec2::new(a0.cos(), a0.sin()) * radius;
+            let p1 = center + Vec2::new(a1.cos(), a1.sin()) * radius;
+            self.push_vertex(center, inner_color);
+            self.push_vertex(p0, outer_color);
+            self.push_vertex(p1, outer_color);
+        }
+    }
+
+    /// Append a single vertex with an explicit color to the flat buffer.
+    fn push_vertex(&mut self, pos: Vec2, color: VectorColor) {
+        self.buffer
+            .extend_from_slice(&[pos.x, pos.y, color.r, color.g, color.b, color.a]);
+    }
+
     /// Tessellate and fill a circle.
     ///
     /// The circle is approximated using lyon's default tolerance.
@@ -300,6 +389,124 @@ impl VectorState {
         self.stroke_path(&path, width, color);
     }
 
+    /// Stroke a polyline with a dash pattern (orbit previews, UI guides).
+    ///
+    /// Walks the polyline by arc length, stroking `dash_len` units then
+    /// skipping `gap_len` units. The pattern phase carries across vertices,
+    /// so dashes bend smoothly around corners instead of restarting at
+    /// every segment. Non-positive dash or gap lengths fall back to a
+    /// solid stroke.
+    pub fn stroke_polyline_dashed(
+        &mut self,
+        points: &[Vec2],
+        width: f32,
+        color: VectorColor,
+        dash_len: f32,
+        gap_len: f32,
+    ) {
+        if points.len() < 2 {
+            return;
+        }
+        if dash_len <= 0.0 || gap_len <= 0.0 {
+            self.stroke_polyline(points, width, color);
+            return;
+        }
+
+        let dashes = dash_polylines(points, dash_len, gap_len);
+        if dashes.is_empty() {
+            return;
+        }
+
+        // One open sub-path per dash, tessellated in a single pass
+        let mut builder = Path::builder();
+        for dash in &dashes {
+            builder.begin(point(dash[0].x, dash[0].y));
+            for p in &dash[1..] {
+                builder.line_to(point(p.x, p.y));
+            }
+            builder.end(false);
+        }
+        let path = builder.build();
+        self.stroke_path(&path, width, color);
+    }
+
+    /// Stroke a circular arc from `start_angle` to `end_angle` (radians,
+    /// counter-clockwise). An end angle behind the start wraps around the
+    /// circle, so progress rings can sweep past the zero angle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stroke_arc(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        width: f32,
+        color: VectorColor,
+        segments: usize,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+        let points = arc_points(center, radius, start_angle, end_angle, segments);
+        self.stroke_polyline(&points, width, color);
+    }
+
+    /// Fill a pie slice (pac-man shapes, clock faces, cooldown wedges)
+    /// by fanning triangles from the center — see `stroke_arc` for the
+    /// angle convention.
+    pub fn fill_pie(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        color: VectorColor,
+        segments: usize,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+        let points = arc_points(center, radius, start_angle, end_angle, segments);
+        for pair in points.windows(2) {
+            self.push_vertex(center, color);
+            self.push_vertex(pair[0], color);
+            self.push_vertex(pair[1], color);
+        }
+    }
+
+    /// Stroke a cubic Bézier curve from `p0` to `p1` with control points
+    /// `c0`/`c1`, flattened into `segments` straight pieces and fed through
+    /// the regular stroke path. More segments = smoother curve.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stroke_bezier(
+        &mut self,
+        p0: Vec2,
+        c0: Vec2,
+        c1: Vec2,
+        p1: Vec2,
+        width: f32,
+        color: VectorColor,
+        segments: usize,
+    ) {
+        let points = cubic_bezier_points(p0, c0, c1, p1, segments);
+        self.stroke_polyline(&points, width, color);
+    }
+
+    /// Stroke a quadratic Bézier curve from `p0` to `p1` with control
+    /// point `c` — see `stroke_bezier`.
+    pub fn stroke_quadratic(
+        &mut self,
+        p0: Vec2,
+        c: Vec2,
+        p1: Vec2,
+        width: f32,
+        color: VectorColor,
+        segments: usize,
+    ) {
+        let points = quadratic_bezier_points(p0, c, p1, segments);
+        self.stroke_polyline(&points, width, color);
+    }
+
     /// Tessellate a stroked closed polygon.
     pub fn stroke_polygon(&mut self, points: &[Vec2], width: f32, color: VectorColor) {
         if points.len() < 3 {
@@ -317,6 +524,60 @@ impl VectorState {
         self.stroke_path(&path, width, color);
     }
 
+    /// Tessellate many stroked closed polygons in one batch.
+    ///
+    /// All polygons become sub-paths of a single lyon path, so the
+    /// tessellator runs once and the geometry flushes once. For many-shape
+    /// frames (orbit lines, rings) this cuts per-call overhead versus
+    /// repeated `stroke_polygon` calls. Polygons with fewer than 3 points
+    /// are skipped.
+    pub fn stroke_polygons(&mut self, polygons: &[&[Vec2]], width: f32, color: VectorColor) {
+        let mut builder = Path::builder();
+        let mut any = false;
+        for points in polygons {
+            if points.len() < 3 {
+                continue;
+            }
+            builder.begin(point(points[0].x, points[0].y));
+            for p in &points[1..] {
+                builder.line_to(point(p.x, p.y));
+            }
+            builder.close();
+            any = true;
+        }
+        if !any {
+            return;
+        }
+        let path = builder.build();
+        self.stroke_path(&path, width, color);
+    }
+
+    /// Tessellate and fill many polygons in one batch.
+    ///
+    /// Batched counterpart of `fill_polygon` — see `stroke_polygons`.
+    /// Note that overlapping sub-paths interact through lyon's fill rule,
+    /// so batch disjoint shapes (stars, flags, confetti), not unions.
+    pub fn fill_polygons(&mut self, polygons: &[&[Vec2]], color: VectorColor) {
+        let mut builder = Path::builder();
+        let mut any = false;
+        for points in polygons {
+            if points.len() < 3 {
+                continue;
+            }
+            builder.begin(point(points[0].x, points[0].y));
+            for p in &points[1..] {
+                builder.line_to(point(p.x, p.y));
+            }
+            builder.close();
+            any = true;
+        }
+        if !any {
+            return;
+        }
+        let path = builder.build();
+        self.fill_path(&path, color);
+    }
+
     /// Tessellate a stroked circle.
     pub fn stroke_circle(&mut self, center: Vec2, radius: f32, width: f32, color: VectorColor) {
         if radius <= 0.0 {
@@ -341,6 +602,68 @@ impl VectorState {
         self.stroke_polygon(&points, line_width, color);
     }
 
+    /// Draw a numeric value as 7-segment-style digits — on-screen debug
+    /// readouts (FPS, counts) without the font atlas the text module needs.
+    ///
+    /// `pos` is the top-left corner of the first digit cell; `size` is the
+    /// digit height. Renders the integer and up to two decimal places
+    /// (trailing zeros trimmed), with `-` as a lone middle segment and `.`
+    /// as a short tick on the baseline.
+    pub fn draw_number(&mut self, value: f32, pos: Vec2, size: f32, color: VectorColor) {
+        let width = size * DIGIT_WIDTH_RATIO;
+        let advance = width + size * DIGIT_SPACING_RATIO;
+        let stroke = size * DIGIT_STROKE_RATIO;
+
+        let mut text = format!("{:.2}", value);
+        if text.contains('.') {
+            while text.ends_with('0') {
+                text.pop();
+            }
+            if text.ends_with('.') {
+                text.pop();
+            }
+        }
+
+        // One open sub-path per lit segment, tessellated in a single pass
+        let mut builder = Path::builder();
+        let mut cursor = pos;
+        for ch in text.chars() {
+            match ch {
+                '-' => {
+                    // Middle segment only
+                    builder.begin(point(cursor.x, cursor.y + size / 2.0));
+                    builder.line_to(point(cursor.x + width, cursor.y + size / 2.0));
+                    builder.end(false);
+                    cursor.x += advance;
+                }
+                '.' => {
+                    // Baseline tick, half a cell wide
+                    builder.begin(point(cursor.x, cursor.y + size));
+                    builder.line_to(point(cursor.x + width / 2.0, cursor.y + size));
+                    builder.end(false);
+                    cursor.x += advance / 2.0;
+                }
+                _ => {
+                    let digit = match ch.to_digit(10) {
+                        Some(d) => d as usize,
+                        None => continue,
+                    };
+                    for (seg, &[[x0, y0], [x1, y1]]) in SEGMENT_ENDPOINTS.iter().enumerate() {
+                        if DIGIT_SEGMENTS[digit] & (1 << seg) == 0 {
+                            continue;
+                        }
+                        builder.begin(point(cursor.x + x0 * width, cursor.y + y0 * size));
+                        builder.line_to(point(cursor.x + x1 * width, cursor.y + y1 * size));
+                        builder.end(false);
+                    }
+                    cursor.x += advance;
+                }
+            }
+        }
+        let path = builder.build();
+        self.stroke_path(&path, stroke, color);
+    }
+
     /// Tessellate an arbitrary stroked lyon Path.
     pub fn stroke_path(&mut self, path: &Path, width: f32, color: VectorColor) {
         let result = self.stroke_tess.tessellate_path(
@@ -361,6 +684,140 @@ impl Default for VectorState {
     }
 }
 
+/// Digit cell width as a fraction of the digit height.
+const DIGIT_WIDTH_RATIO: f32 = 0.6;
+/// Gap between digit cells as a fraction of the digit height.
+const DIGIT_SPACING_RATIO: f32 = 0.25;
+/// Stroke width as a fraction of the digit height.
+const DIGIT_STROKE_RATIO: f32 = 0.1;
+
+/// The seven segment endpoints in a unit digit cell (x scaled by the cell
+/// width, y by the height). Order: top, top-right, bottom-right, bottom,
+/// bottom-left, top-left, middle — matching the bit order of
+/// [`DIGIT_SEGMENTS`].
+const SEGMENT_ENDPOINTS: [[[f32; 2]; 2]; 7] = [
+    [[0.0, 0.0], [1.0, 0.0]], // top
+    [[1.0, 0.0], [1.0, 0.5]], // top-right
+    [[1.0, 0.5], [1.0, 1.0]], // bottom-right
+    [[0.0, 1.0], [1.0, 1.0]], // bottom
+    [[0.0, 0.5], [0.0, 1.0]], // bottom-left
+    [[0.0, 0.0], [0.0, 0.5]], // top-left
+    [[0.0, 0.5], [1.0, 0.5]], // middle
+];
+
+/// Lit-segment bitmask for each digit 0-9 (bit i = `SEGMENT_ENDPOINTS[i]`).
+const DIGIT_SEGMENTS: [u8; 10] = [
+    0b0111111, // 0
+    0b0000110, // 1
+    0b1011011, // 2
+    0b1001111, // 3
+    0b1100110, // 4
+    0b1101101, // 5
+    0b1111101, // 6
+    0b0000111, // 7
+    0b1111111, // 8
+    0b1101111, // 9
+];
+
+/// Split a polyline into dash sub-polylines by arc length.
+///
+/// The dash/gap phase is continuous across vertices — a dash that spans a
+/// corner keeps the corner as an interior point of its sub-polyline.
+fn dash_polylines(points: &[Vec2], dash_len: f32, gap_len: f32) -> Vec<Vec<Vec2>> {
+    let period = dash_len + gap_len;
+    let mut dashes = Vec::new();
+    let mut current: Vec<Vec2> = Vec::new();
+    // Distance into the dash/gap pattern; starts at the beginning of a dash
+    let mut phase = 0.0;
+
+    for pair in points.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let seg_len = a.distance(b);
+        if seg_len <= 0.0 {
+            continue;
+        }
+        let dir = (b - a) / seg_len;
+
+        let mut t = 0.0;
+        while t < seg_len {
+            let in_dash = phase < dash_len;
+            let boundary = if in_dash { dash_len } else { period };
+            let step = (boundary - phase).min(seg_len - t);
+
+            if in_dash {
+                if current.is_empty() {
+                    current.push(a + dir * t);
+                }
+                current.push(a + dir * (t + step));
+            }
+
+            t += step;
+            phase += step;
+            if in_dash && phase >= dash_len {
+                dashes.push(std::mem::take(&mut current));
+            }
+            if phase >= period {
+                phase = 0.0;
+            }
+        }
+    }
+
+    if current.len() >= 2 {
+        dashes.push(current);
+    }
+    dashes
+}
+
+/// Sample `segments + 1` points along a circular arc. An end angle at or
+/// behind the start is wrapped forward by a full turn.
+fn arc_points(
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+    segments: usize,
+) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    let mut sweep = end_angle - start_angle;
+    if sweep <= 0.0 {
+        sweep += std::f32::consts::TAU;
+    }
+    (0..=segments)
+        .map(|i| {
+            let angle = start_angle + sweep * i as f32 / segments as f32;
+            center + Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Flatten a cubic Bézier into `segments` straight pieces (`segments + 1`
+/// points). At least one segment is always emitted.
+fn cubic_bezier_points(p0: Vec2, c0: Vec2, c1: Vec2, p1: Vec2, segments: usize) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    (0..=segments)
+        .map(|i| {
+            let t = i as f32 / segments as f32;
+            let u = 1.0 - t;
+            p0 * (u * u * u)
+                + c0 * (3.0 * u * u * t)
+                + c1 * (3.0 * u * t * t)
+                + p1 * (t * t * t)
+        })
+        .collect()
+}
+
+/// Flatten a quadratic Bézier into `segments` straight pieces.
+fn quadratic_bezier_points(p0: Vec2, c: Vec2, p1: Vec2, segments: usize) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    (0..=segments)
+        .map(|i| {
+            let t = i as f32 / segments as f32;
+            let u = 1.0 - t;
+            p0 * (u * u) + c * (2.0 * u * t) + p1 * (t * t)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,6 +874,33 @@ mod tests {
         assert_eq!(state.vertex_count(), 6);
     }
 
+    #[test]
+    fn draw_number_emits_segments() {
+        let mut state = VectorState::new();
+        state.draw_number(42.0, Vec2::ZERO, 20.0, VectorColor::WHITE);
+        // '4' lights 4 segments, '2' lights 5 — each stroked segment
+        // tessellates into at least one quad (4 vertices)
+        assert!(state.vertex_count() >= (4 + 5) * 4);
+
+        // Every digit mask lights the expected segment count
+        assert_eq!(DIGIT_SEGMENTS[0].count_ones(), 6);
+        assert_eq!(DIGIT_SEGMENTS[1].count_ones(), 2);
+        assert_eq!(DIGIT_SEGMENTS[8].count_ones(), 7);
+    }
+
+    #[test]
+    fn draw_number_handles_sign_and_decimals() {
+        let mut state = VectorState::new();
+        state.draw_number(-1.5, Vec2::ZERO, 20.0, VectorColor::WHITE);
+        let with_sign = state.vertex_count();
+        assert!(with_sign > 0);
+
+        // "-1.5" draws more geometry than plain "1" (sign, point, extra digit)
+        let mut plain = VectorState::new();
+        plain.draw_number(1.0, Vec2::ZERO, 20.0, VectorColor::WHITE);
+        assert!(with_sign > plain.vertex_count());
+    }
+
     #[test]
     fn fill_circle_produces_vertices() {
         let mut state = VectorState::new();
@@ -436,6 +920,221 @@ mod tests {
         assert!(state.vertex_count() > 0);
     }
 
+    #[test]
+    fn bezier_flattening_hits_endpoints_with_expected_count() {
+        let p0 = Vec2::new(0.0, 0.0);
+        let c0 = Vec2::new(50.0, 100.0);
+        let c1 = Vec2::new(150.0, -100.0);
+        let p1 = Vec2::new(200.0, 0.0);
+
+        let points = cubic_bezier_points(p0, c0, c1, p1, 16);
+        assert_eq!(points.len(), 17); // N segments = N + 1 points
+        assert_eq!(points[0], p0);
+        assert_eq!(points[16], p1);
+
+        let quad = quadratic_bezier_points(p0, c0, p1, 8);
+        assert_eq!(quad.len(), 9);
+        assert_eq!(quad[0], p0);
+        assert_eq!(quad[8], p1);
+        // Midpoint of a quadratic: (p0 + 2c + p1) / 4
+        assert_eq!(quad[4], (p0 + c0 * 2.0 + p1) / 4.0);
+    }
+
+    #[test]
+    fn horizontal_gradient_rect_colors_left_and_right_edges() {
+        let mut state = VectorState::new();
+        state.fill_rect_gradient(
+            Vec2::ZERO,
+            100.0,
+            50.0,
+            VectorColor::RED,
+            VectorColor::BLUE,
+            GradientAxis::Horizontal,
+        );
+
+        assert_eq!(state.vertex_count(), 6);
+        let f = VectorVertex::FLOATS;
+        for i in 0..6 {
+            let v = &state.buffer[i * f..(i + 1) * f];
+            let expected = if v[0] == 0.0 {
+                VectorColor::RED // left edge
+            } else {
+                VectorColor::BLUE // right edge (x == 100)
+            };
+            assert_eq!(&v[2..6], &[expected.r, expected.g, expected.b, expected.a]);
+        }
+    }
+
+    #[test]
+    fn radial_gradient_circle_colors_center_and_rim() {
+        let center = Vec2::new(50.0, 50.0);
+        let mut state = VectorState::new();
+        state.fill_circle_radial(center, 25.0, VectorColor::WHITE, VectorColor::TRANSPARENT);
+
+        assert!(state.vertex_count() >= 3 * 8);
+        assert_eq!(state.vertex_count() % 3, 0);
+        let f = VectorVertex::FLOATS;
+        for i in 0..state.vertex_count() {
+            let v = &state.buffer[i * f..(i + 1) * f];
+            if v[0] == center.x && v[1] == center.y {
+                assert_eq!(v[5], 1.0); // center: opaque white
+            } else {
+                assert_eq!(v[5], 0.0); // rim: transparent
+            }
+        }
+    }
+
+    #[test]
+    fn half_circle_arc_spans_expected_angular_range() {
+        let center = Vec2::new(10.0, 20.0);
+        let points = arc_points(center, 5.0, 0.0, std::f32::consts::PI, 16);
+
+        assert_eq!(points.len(), 17); // N segments = N + 1 points
+        // Endpoints: angle 0 is (r, 0) from center, angle π is (-r, 0)
+        assert!((points[0] - (center + Vec2::new(5.0, 0.0))).length() < 1e-4);
+        assert!((points[16] - (center + Vec2::new(-5.0, 0.0))).length() < 1e-4);
+        // Midpoint sits at the top of the circle
+        assert!((points[8] - (center + Vec2::new(0.0, 5.0))).length() < 1e-4);
+    }
+
+    #[test]
+    fn arc_wraps_when_end_angle_is_behind_start() {
+        // 3π/2 to π/2 must sweep forward through the zero angle
+        let points = arc_points(Vec2::ZERO, 1.0, 3.0 * std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2, 2);
+        assert!((points[1] - Vec2::new(1.0, 0.0)).length() < 1e-4);
+    }
+
+    #[test]
+    fn fill_pie_fans_from_center() {
+        let mut state = VectorState::new();
+        state.fill_pie(Vec2::ZERO, 10.0, 0.0, std::f32::consts::PI, VectorColor::RED, 8);
+
+        // One triangle per segment, three vertices each
+        assert_eq!(state.vertex_count(), 8 * 3);
+        // Every triangle starts at the pie center
+        let f = VectorVertex::FLOATS;
+        for tri in 0..8 {
+            let v = &state.buffer[tri * 3 * f..];
+            assert_eq!((v[0], v[1]), (0.0, 0.0));
+        }
+
+        let mut stroked = VectorState::new();
+        stroked.stroke_arc(Vec2::ZERO, 10.0, 0.0, std::f32::consts::PI, 2.0, VectorColor::RED, 8);
+        assert!(stroked.vertex_count() > 0);
+    }
+
+    #[test]
+    fn dashed_line_emits_expected_dash_count() {
+        // 100-unit line, dash 10 / gap 10 → dashes at 0, 20, 40, 60, 80
+        let points = [Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0)];
+        let dashes = dash_polylines(&points, 10.0, 10.0);
+
+        assert_eq!(dashes.len(), 5);
+        assert_eq!(dashes[0], vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)]);
+        assert_eq!(dashes[4], vec![Vec2::new(80.0, 0.0), Vec2::new(90.0, 0.0)]);
+
+        let mut state = VectorState::new();
+        state.stroke_polyline_dashed(&points, 2.0, VectorColor::WHITE, 10.0, 10.0);
+        assert!(state.vertex_count() > 0);
+    }
+
+    #[test]
+    fn dash_phase_continues_across_vertices() {
+        // An L-shape: 15 units right, then 15 units up. With dash 10 / gap
+        // 10 the second dash (20..30 by arc length) spans the corner at 15.
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(15.0, 0.0),
+            Vec2::new(15.0, 15.0),
+        ];
+        let dashes = dash_polylines(&points, 10.0, 10.0);
+
+        assert_eq!(dashes.len(), 2);
+        // Second dash starts 5 units up the vertical leg, not at the corner
+        assert_eq!(dashes[1][0], Vec2::new(15.0, 5.0));
+        assert_eq!(*dashes[1].last().unwrap(), Vec2::new(15.0, 15.0));
+    }
+
+    #[test]
+    fn stroke_bezier_produces_vertices() {
+        let mut state = VectorState::new();
+        state.stroke_bezier(
+            Vec2::ZERO,
+            Vec2::new(50.0, 100.0),
+            Vec2::new(150.0, 100.0),
+            Vec2::new(200.0, 0.0),
+            3.0,
+            VectorColor::WHITE,
+            16,
+        );
+        assert!(state.vertex_count() > 0);
+    }
+
+    #[test]
+    fn batched_stroke_matches_individual_calls() {
+        let tri_a = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(50.0, 100.0),
+        ];
+        let tri_b = [
+            Vec2::new(300.0, 300.0),
+            Vec2::new(400.0, 300.0),
+            Vec2::new(350.0, 400.0),
+        ];
+
+        let mut individual = VectorState::new();
+        individual.stroke_polygon(&tri_a, 4.0, VectorColor::WHITE);
+        individual.stroke_polygon(&tri_b, 4.0, VectorColor::WHITE);
+
+        let mut batched = VectorState::new();
+        batched.stroke_polygons(&[&tri_a, &tri_b], 4.0, VectorColor::WHITE);
+
+        assert_eq!(batched.vertex_count(), individual.vertex_count());
+        assert_eq!(batched.buffer, individual.buffer);
+    }
+
+    #[test]
+    fn batched_fill_matches_individual_calls() {
+        let tri_a = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(50.0, 100.0),
+        ];
+        let tri_b = [
+            Vec2::new(300.0, 300.0),
+            Vec2::new(400.0, 300.0),
+            Vec2::new(350.0, 400.0),
+        ];
+
+        let mut individual = VectorState::new();
+        individual.fill_polygon(&tri_a, VectorColor::RED);
+        individual.fill_polygon(&tri_b, VectorColor::RED);
+
+        let mut batched = VectorState::new();
+        batched.fill_polygons(&[&tri_a, &tri_b], VectorColor::RED);
+
+        assert_eq!(batched.vertex_count(), individual.vertex_count());
+    }
+
+    #[test]
+    fn batched_calls_skip_degenerate_polygons() {
+        let tri = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(50.0, 100.0),
+        ];
+        let degenerate = [Vec2::ZERO, Vec2::ONE];
+
+        let mut state = VectorState::new();
+        state.stroke_polygons(&[&degenerate, &tri], 4.0, VectorColor::WHITE);
+        assert!(state.vertex_count() > 0);
+
+        let mut empty = VectorState::new();
+        empty.fill_polygons(&[&degenerate], VectorColor::WHITE);
+        assert_eq!(empty.vertex_count(), 0);
+    }
+
     #[test]
     fn clear_resets_buffer() {
         let mut state = VectorState::new();
@@ -1,6 +1,6 @@
 pub mod runner;
 
-pub use runner::GameRunner;
+pub use runner::{FrameStats, GameRunner};
 
 /// Generate all `#[wasm_bindgen]` exports for a game.
 ///
@@ -73,18 +73,38 @@ macro_rules! export_game {
         }
 
         #[wasm_bindgen]
-        pub fn game_pointer_down(x: f32, y: f32) {
-            with_runner(|r| r.push_input(InputEvent::PointerDown { x, y }));
+        pub fn game_set_paused(paused: bool) {
+            with_runner(|r| r.set_paused(paused));
         }
 
         #[wasm_bindgen]
-        pub fn game_pointer_up(x: f32, y: f32) {
-            with_runner(|r| r.push_input(InputEvent::PointerUp { x, y }));
+        pub fn game_set_time_scale(scale: f32) {
+            with_runner(|r| r.set_time_scale(scale));
         }
 
         #[wasm_bindgen]
-        pub fn game_pointer_move(x: f32, y: f32) {
-            with_runner(|r| r.push_input(InputEvent::PointerMove { x, y }));
+        pub fn game_pointer_down(pointer_id: u32, x: f32, y: f32) {
+            with_runner(|r| r.push_input(InputEvent::PointerDown { pointer_id, x, y }));
+        }
+
+        #[wasm_bindgen]
+        pub fn game_pointer_up(pointer_id: u32, x: f32, y: f32) {
+            with_runner(|r| r.push_input(InputEvent::PointerUp { pointer_id, x, y }));
+        }
+
+        #[wasm_bindgen]
+        pub fn game_pointer_move(pointer_id: u32, x: f32, y: f32) {
+            with_runner(|r| r.push_input(InputEvent::PointerMove { pointer_id, x, y }));
+        }
+
+        #[wasm_bindgen]
+        pub fn game_gamepad_axis(index: u32, axis: u32, value: f32) {
+            with_runner(|r| r.push_input(InputEvent::GamepadAxis { index, axis, value }));
+        }
+
+        #[wasm_bindgen]
+        pub fn game_gamepad_button(index: u32, button: u32, pressed: bool) {
+            with_runner(|r| r.push_input(InputEvent::GamepadButton { index, button, pressed }));
         }
 
         #[wasm_bindgen]
@@ -166,6 +186,11 @@ macro_rules! export_game {
 
         // ---- Capacity accessors ----
 
+        #[wasm_bindgen]
+        pub fn get_protocol_version() -> f32 {
+            with_runner(|r| r.protocol_version())
+        }
+
         #[wasm_bindgen]
         pub fn get_max_instances() -> u32 {
             with_runner(|r| r.max_instances())
@@ -237,6 +262,60 @@ macro_rules! export_game {
             with_runner(|r| r.bake_state())
         }
 
+        // ---- Dirty region accessors (header[28..33]) ----
+
+        #[wasm_bindgen]
+        pub fn get_dirty_layer() -> f32 {
+            with_runner(|r| r.dirty_region()[0])
+        }
+
+        #[wasm_bindgen]
+        pub fn get_dirty_min_x() -> f32 {
+            with_runner(|r| r.dirty_region()[1])
+        }
+
+        #[wasm_bindgen]
+        pub fn get_dirty_min_y() -> f32 {
+            with_runner(|r| r.dirty_region()[2])
+        }
+
+        #[wasm_bindgen]
+        pub fn get_dirty_max_x() -> f32 {
+            with_runner(|r| r.dirty_region()[3])
+        }
+
+        #[wasm_bindgen]
+        pub fn get_dirty_max_y() -> f32 {
+            with_runner(|r| r.dirty_region()[4])
+        }
+
+        // ---- Frame stats accessors ----
+
+        #[wasm_bindgen]
+        pub fn get_frame_update_ms() -> f32 {
+            with_runner(|r| r.stats().update_ms)
+        }
+
+        #[wasm_bindgen]
+        pub fn get_frame_physics_ms() -> f32 {
+            with_runner(|r| r.stats().physics_ms)
+        }
+
+        #[wasm_bindgen]
+        pub fn get_frame_effects_vertices() -> u32 {
+            with_runner(|r| r.stats().effects_vertices)
+        }
+
+        #[wasm_bindgen]
+        pub fn get_frame_instances() -> u32 {
+            with_runner(|r| r.stats().instances)
+        }
+
+        #[wasm_bindgen]
+        pub fn get_frame_sdf_instances() -> u32 {
+            with_runner(|r| r.stats().sdf_instances)
+        }
+
         // ---- Lighting accessors ----
 
         #[wasm_bindgen]
@@ -1,14 +1,61 @@
 use zap_engine::{
-    Game, GameConfig, EngineContext, RenderContext,
+    Game, GameConfig, EngineContext, FrameData, RenderContext,
     InputEvent, InputQueue, RenderBuffer,
     FixedTimestep, ProtocolLayout, LayerBatch,
 };
-use zap_engine::systems::render::build_render_buffer;
+use zap_engine::systems::render::build_render_buffer_with_parallax;
 use zap_engine::systems::emitter::tick_emitters;
 use zap_engine::renderer::sdf_instance::SDFBuffer;
-use zap_engine::bridge::protocol::LAYER_BATCH_FLOATS;
+use zap_engine::bridge::protocol::{LAYER_BATCH_FLOATS, SOUND_EVENT_BYTES, SOUND_NO_VOICE_GROUP};
+
+/// Per-tick performance counters for debug overlays.
+///
+/// Counts are always recorded (they are free — the buffers already know their
+/// sizes). Timings require the `profiling` feature; without it the `_ms`
+/// fields stay at zero and release builds pay nothing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameStats {
+    /// Time spent in `Game::update` calls last tick, in milliseconds.
+    pub update_ms: f32,
+    /// Time spent stepping physics last tick, in milliseconds.
+    pub physics_ms: f32,
+    /// Effects vertices emitted last tick.
+    pub effects_vertices: u32,
+    /// Render instances emitted last tick.
+    pub instances: u32,
+    /// SDF instances emitted last tick.
+    pub sdf_instances: u32,
+}
+
+/// Monotonic timestamp in milliseconds for frame timing.
+#[cfg(all(feature = "profiling", target_arch = "wasm32"))]
+fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or(0.0)
+}
+
+/// Monotonic timestamp in milliseconds for frame timing.
+#[cfg(all(feature = "profiling", not(target_arch = "wasm32")))]
+fn now_ms() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
 /// Generic game runner that wires up the engine loop.
 ///
+/// One tick of a recorded session: the frame dt and the raw input events
+/// pushed since the previous tick. Gesture events are not recorded — the
+/// recognizers re-synthesize them on replay.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RecordedTick {
+    dt: f32,
+    events: Vec<InputEvent>,
+}
+
 /// Each concrete game (e.g., `basic-demo`) creates a `thread_local!` GameRunner
 /// and exports free functions via `#[wasm_bindgen]`, because wasm-bindgen
 /// cannot export generic structs directly.
@@ -22,6 +69,10 @@ pub struct GameRunner<G: Game> {
     config: GameConfig,
     layout: ProtocolLayout,
     initialized: bool,
+    /// When true, `tick` skips game updates and physics but keeps rendering.
+    paused: bool,
+    /// Multiplier applied to frame dt before fixed-step accumulation.
+    time_scale: f32,
     /// Flat buffer of sound event IDs for SharedArrayBuffer reads.
     sound_buffer: Vec<u8>,
     /// Layer batch descriptors from the most recent frame.
@@ -29,17 +80,23 @@ pub struct GameRunner<G: Game> {
     /// Flat f32 buffer of layer batch data for SharedArrayBuffer reads.
     /// Each batch: [layer_id, start, end, atlas_id] = 4 floats.
     layer_batch_buffer: Vec<f32>,
+    /// Performance counters from the most recent tick.
+    stats: FrameStats,
+    /// Recorded ticks while a recording session is active.
+    recording: Option<Vec<RecordedTick>>,
+    /// Raw events pushed since the last recorded tick.
+    recorded_events: Vec<InputEvent>,
 }
 
 impl<G: Game> GameRunner<G> {
     pub fn new(game: G) -> Self {
         let config = game.config();
-        let timestep = FixedTimestep::new(config.fixed_dt);
+        let timestep = FixedTimestep::new(config.fixed_dt).with_max_steps(config.max_catch_up_steps);
         let layout = ProtocolLayout::from_config(&config);
 
         let render_buffer = RenderBuffer::with_capacity(config.max_instances);
         let sdf_buffer = SDFBuffer::with_capacity(config.max_sdf_instances);
-        let sound_buffer = Vec::with_capacity(config.max_sounds);
+        let sound_buffer = Vec::with_capacity(config.max_sounds * SOUND_EVENT_BYTES);
         let layer_batch_buffer = Vec::with_capacity(config.max_layer_batches * LAYER_BATCH_FLOATS);
 
         // Use with_config to wire capacity settings through all subsystems
@@ -50,6 +107,7 @@ impl<G: Game> GameRunner<G> {
             // Physics dt = game dt / substeps (e.g., 1/60 / 4 = 1/240 for 240Hz physics)
             let physics_dt = config.fixed_dt / config.physics_substeps.max(1) as f32;
             ctx.physics.set_dt(physics_dt);
+            ctx.physics_substeps = config.physics_substeps.max(1);
         }
 
         Self {
@@ -62,9 +120,14 @@ impl<G: Game> GameRunner<G> {
             layout,
             config,
             initialized: false,
+            paused: false,
+            time_scale: 1.0,
             sound_buffer,
             layer_batches: Vec::new(),
             layer_batch_buffer,
+            stats: FrameStats::default(),
+            recording: None,
+            recorded_events: Vec::new(),
         }
     }
 
@@ -72,6 +135,9 @@ impl<G: Game> GameRunner<G> {
     pub fn init(&mut self) {
         self.config = self.game.config();
         self.layout = ProtocolLayout::from_config(&self.config);
+        // Catch a miscomputed section offset before it corrupts neighbors
+        #[cfg(debug_assertions)]
+        self.layout.assert_non_overlapping();
         self.game.init(&mut self.ctx);
         self.initialized = true;
     }
@@ -94,38 +160,193 @@ impl<G: Game> GameRunner<G> {
 
     /// Push an input event into the queue.
     pub fn push_input(&mut self, event: InputEvent) {
+        if self.recording.is_some() {
+            self.recorded_events.push(event);
+        }
         self.input.push(event);
     }
 
+    // -- Recording & replay (reproducing nondeterminism bugs) --
+
+    /// Begin recording each tick's dt and input events. Discards any
+    /// recording in progress. Sessions replay deterministically because the
+    /// effects RNG is seeded solely from `GameConfig::effects_seed`.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Vec::new());
+        self.recorded_events.clear();
+    }
+
+    /// Serialize the recording in progress to JSON for storage or bug
+    /// reports. Returns an empty session if recording was never started.
+    pub fn export_recording(&self) -> String {
+        serde_json::to_string(self.recording.as_deref().unwrap_or(&[]))
+            .expect("Recording serialization is infallible")
+    }
+
+    /// Re-run a recorded session against a fresh game. The context is
+    /// rebuilt from config (resetting scene, effects RNG, and physics), the
+    /// game re-initialized, and every recorded tick replayed with its
+    /// original dt and events.
+    ///
+    /// The game instance itself is reused — the runner owns it and `Game`
+    /// requires neither `Clone` nor `Default`, so it cannot be rebuilt here.
+    /// Determinism therefore depends on [`Game::init`] resetting all state
+    /// the game keeps in `self`; a game that only initializes such state in
+    /// its constructor will diverge on replay.
+    pub fn replay(&mut self, recording: &str) -> Result<(), String> {
+        let ticks: Vec<RecordedTick> =
+            serde_json::from_str(recording).map_err(|e| e.to_string())?;
+
+        // Fresh deterministic state, mirroring construction in `new`
+        self.recording = None;
+        self.recorded_events.clear();
+        #[allow(unused_mut)]
+        let mut ctx = EngineContext::with_config(&self.config);
+        #[cfg(feature = "physics")]
+        {
+            let physics_dt = self.config.fixed_dt / self.config.physics_substeps.max(1) as f32;
+            ctx.physics.set_dt(physics_dt);
+            ctx.physics_substeps = self.config.physics_substeps.max(1);
+        }
+        self.ctx = ctx;
+        self.input = InputQueue::new();
+        self.timestep =
+            FixedTimestep::new(self.config.fixed_dt).with_max_steps(self.config.max_catch_up_steps);
+        self.init();
+
+        for tick in ticks {
+            for event in tick.events {
+                self.push_input(event);
+            }
+            self.tick(tick.dt);
+        }
+        Ok(())
+    }
+
+    /// Pause or resume game updates. A paused runner still drains input and
+    /// rebuilds render buffers, so the last frame stays visible and interactive.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Scale the passage of game time (1.0 = realtime, 0.5 = slow motion).
+    /// Negative values are clamped to zero.
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
+
     /// Run one frame tick: update game, build render buffer, run effects.
     pub fn tick(&mut self, dt: f32) {
         if !self.initialized {
             return;
         }
 
+        if let Some(recording) = &mut self.recording {
+            recording.push(RecordedTick {
+                dt,
+                events: std::mem::take(&mut self.recorded_events),
+            });
+        }
+
         // Clear per-frame transient data
         self.ctx.clear_frame_data();
 
-        // Fixed timestep accumulation
-        let steps = self.timestep.accumulate(dt);
+        // Advance the gesture clock so opt-in recognizers can timestamp events
+        self.input.advance_clock(dt);
+
+        // Capped runners feed events per fixed step below; uncapped ones
+        // fold the whole queue into the polling-style input state up front
+        let input_cap = self.config.max_input_events_per_step as usize;
+        if input_cap == 0 {
+            self.ctx.input_state.fold(&self.input);
+        }
+
+        // Fixed timestep accumulation (paused runners accumulate no time)
+        let steps = if self.paused {
+            0
+        } else {
+            self.timestep.accumulate(dt * self.time_scale)
+        };
+        #[cfg(feature = "profiling")]
+        let mut update_ms = 0.0f64;
+        #[cfg(feature = "profiling")]
+        let mut physics_ms = 0.0f64;
+
         for _ in 0..steps {
-            self.game.update(&mut self.ctx, &self.input);
+            #[cfg(feature = "profiling")]
+            let update_start = now_ms();
+            if input_cap > 0 {
+                // Feed at most `input_cap` events this step; the remainder
+                // stays queued for subsequent steps (and ticks)
+                let step_input = self.input.split_front(input_cap);
+                self.ctx.input_state.fold(&step_input);
+                self.game.update(&mut self.ctx, &step_input);
+            } else {
+                self.game.update(&mut self.ctx, &self.input);
+            }
+            #[cfg(feature = "profiling")]
+            {
+                update_ms += now_ms() - update_start;
+            }
 
-            // Run physics substeps (e.g., 4 substeps = 240Hz physics with 60Hz game updates)
+            // Physics substepping (e.g., 4 substeps = 240Hz physics with
+            // 60Hz game updates) lives inside step_physics so collision
+            // events from every substep land in one frame's collisions()
             #[cfg(feature = "physics")]
-            for _ in 0..self.config.physics_substeps.max(1) {
+            {
+                #[cfg(feature = "profiling")]
+                let physics_start = now_ms();
                 self.ctx.step_physics();
+                #[cfg(feature = "profiling")]
+                {
+                    physics_ms += now_ms() - physics_start;
+                }
             }
 
             tick_emitters(&mut self.ctx.scene, &mut self.ctx.effects, self.timestep.dt());
             self.ctx.effects.tick(self.timestep.dt());
         }
 
-        // Drain input after update
-        self.input.drain();
+        #[cfg(feature = "profiling")]
+        {
+            self.stats.update_ms = update_ms as f32;
+            self.stats.physics_ms = physics_ms as f32;
+        }
+
+        // Advance camera shake in real time (not fixed steps) so it decays
+        // smoothly even on frames with zero fixed updates
+        self.ctx.camera.tick_shake(dt);
+
+        // Advance light pulse/flicker modulation
+        self.ctx.lights.tick(dt);
+
+        // Drain input after update. Capped runners consumed their share per
+        // step and keep the deferred remainder queued — except while paused,
+        // where events would otherwise pile up unboundedly. A paused capped
+        // runner ran zero steps, so fold the discarded events into the
+        // polling-style input state first — dropping a KeyUp here would leave
+        // `is_key_down` stuck true across the pause.
+        if input_cap == 0 || self.paused {
+            if input_cap > 0 {
+                self.ctx.input_state.fold(&self.input);
+            }
+            self.input.drain();
+        }
 
-        // Build render buffer from entities (returns layer batch descriptors)
-        self.layer_batches = build_render_buffer(self.ctx.scene.iter(), &mut self.render_buffer);
+        // Build render buffer from entities (returns layer batch descriptors).
+        // Parallax layers are offset against the camera center here so the
+        // instance data is already parallax-corrected.
+        let camera_offset = glam::Vec2::new(self.ctx.camera.center[0], self.ctx.camera.center[1]);
+        self.layer_batches = build_render_buffer_with_parallax(
+            self.ctx.scene.iter(),
+            &mut self.render_buffer,
+            camera_offset,
+            self.ctx.layer_parallax_factors(),
+        );
 
         // Serialize layer batches to flat f32 buffer for SAB
         self.layer_batch_buffer.clear();
@@ -134,6 +355,7 @@ impl<G: Game> GameRunner<G> {
             self.layer_batch_buffer.push(batch.start as f32);
             self.layer_batch_buffer.push(batch.end as f32);
             self.layer_batch_buffer.push(batch.atlas_id as f32);
+            self.layer_batch_buffer.push(batch.parallax);
         }
 
         // Build SDF buffer from entities with mesh components
@@ -147,24 +369,76 @@ impl<G: Game> GameRunner<G> {
             self.game.render(&mut render_ctx);
         }
 
+        // Collider visualization: one config flag instead of a manual call per frame
+        #[cfg(feature = "physics")]
+        if self.config.debug_draw_physics {
+            zap_engine::debug_draw_colliders(
+                &self.ctx.scene,
+                &self.ctx.physics,
+                &mut self.ctx.effects,
+                2.0,
+                zap_engine::SegmentColor::Green,
+            );
+        }
+
         // Rebuild effects buffer
         self.ctx.effects.rebuild_effects_buffer();
 
-        // Pack sound events into flat buffer
+        // Pack sound events into flat buffer (SOUND_EVENT_BYTES per record,
+        // layout documented in bridge::protocol)
         self.sound_buffer.clear();
         for sound in &self.ctx.sounds {
-            self.sound_buffer.push(sound.0 as u8);
+            self.sound_buffer.push(sound.id as u8);
+            self.sound_buffer.push(sound.priority);
+            self.sound_buffer
+                .push(sound.voice_group.unwrap_or(SOUND_NO_VOICE_GROUP));
+            let mut flags = 0u8;
+            if sound.looping {
+                flags |= 1;
+            }
+            if sound.position.is_some() {
+                flags |= 2;
+            }
+            self.sound_buffer.push(flags);
+            self.sound_buffer.extend_from_slice(&sound.volume.to_le_bytes());
+            let pos = sound.position.unwrap_or(glam::Vec2::ZERO);
+            self.sound_buffer.extend_from_slice(&pos.x.to_le_bytes());
+            self.sound_buffer.extend_from_slice(&pos.y.to_le_bytes());
         }
+
+        // Publish the completed frame: the pointers exposed to JS flip to the
+        // buffers just built, so a racing read never sees a partial rebuild
+        self.render_buffer.swap();
+        self.sdf_buffer.swap();
+
+        // Record buffer sizes for the debug overlay
+        self.stats.effects_vertices = self.ctx.effects.effects_vertex_count() as u32;
+        self.stats.instances = self.render_buffer.front_count();
+        self.stats.sdf_instances = self.sdf_buffer.front_count() as u32;
+
+        // Snapshot the frame summary so games can self-throttle next update
+        self.ctx.set_frame_data(FrameData {
+            instances: self.render_buffer.front_count(),
+            sdf_instances: self.sdf_buffer.front_count() as u32,
+            effects_vertices: self.ctx.effects.effects_vertex_count() as u32,
+            lights: self.ctx.lights.count() as u32,
+            dt: self.timestep.dt(),
+        });
+    }
+
+    /// Performance counters from the most recent tick.
+    pub fn stats(&self) -> &FrameStats {
+        &self.stats
     }
 
     // ---- Pointer accessors for SharedArrayBuffer reads ----
 
     pub fn instances_ptr(&self) -> *const f32 {
-        self.render_buffer.instances_ptr()
+        self.render_buffer.front_ptr()
     }
 
     pub fn instance_count(&self) -> u32 {
-        self.render_buffer.instance_count()
+        self.render_buffer.front_count()
     }
 
     pub fn effects_ptr(&self) -> *const f32 {
@@ -179,6 +453,8 @@ impl<G: Game> GameRunner<G> {
         self.sound_buffer.as_ptr()
     }
 
+    /// Length of the packed sound buffer in bytes
+    /// (`SOUND_EVENT_BYTES` per queued sound).
     pub fn sound_events_len(&self) -> u32 {
         self.sound_buffer.len() as u32
     }
@@ -200,7 +476,7 @@ impl<G: Game> GameRunner<G> {
     }
 
     pub fn atlas_split(&self) -> u32 {
-        self.render_buffer.atlas_split
+        self.render_buffer.front_atlas_split()
     }
 
     // ---- Capacity accessors (read by TypeScript via wasm_bindgen exports) ----
@@ -221,6 +497,10 @@ impl<G: Game> GameRunner<G> {
         self.layout.max_events as u32
     }
 
+    pub fn protocol_version(&self) -> f32 {
+        self.layout.version
+    }
+
     pub fn buffer_total_floats(&self) -> u32 {
         self.layout.buffer_total_floats as u32
     }
@@ -228,11 +508,11 @@ impl<G: Game> GameRunner<G> {
     // ---- SDF accessors ----
 
     pub fn sdf_instances_ptr(&self) -> *const f32 {
-        self.sdf_buffer.instances_ptr()
+        self.sdf_buffer.front_ptr()
     }
 
     pub fn sdf_instance_count(&self) -> u32 {
-        self.sdf_buffer.instance_count() as u32
+        self.sdf_buffer.front_count() as u32
     }
 
     pub fn max_sdf_instances(&self) -> u32 {
@@ -259,11 +539,17 @@ impl<G: Game> GameRunner<G> {
     // ---- Bake state accessor ----
 
     /// Get the encoded bake state for SAB header[21].
-    /// Format: baked_layers_mask | (bake_generation << 6).
+    /// Format: baked_layers_mask | (bake_generation << 7).
     pub fn bake_state(&self) -> f32 {
         self.ctx.bake_state_encoded()
     }
 
+    /// Get the encoded dirty region for SAB header[28..33]:
+    /// [layer, min_x, min_y, max_x, max_y], layer is -1.0 when none is pending.
+    pub fn dirty_region(&self) -> [f32; 5] {
+        self.ctx.dirty_region_encoded()
+    }
+
     // ---- Lighting accessors ----
 
     pub fn lights_ptr(&self) -> *const f32 {
@@ -308,3 +594,356 @@ impl<G: Game> GameRunner<G> {
         self.layout.layer_batch_data_offset as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal game that counts update calls.
+    struct CounterGame {
+        updates: u32,
+    }
+
+    impl Game for CounterGame {
+        fn init(&mut self, _ctx: &mut EngineContext) {}
+
+        fn update(&mut self, _ctx: &mut EngineContext, _input: &InputQueue) {
+            self.updates += 1;
+        }
+    }
+
+    fn make_runner() -> GameRunner<CounterGame> {
+        let mut runner = GameRunner::new(CounterGame { updates: 0 });
+        runner.init();
+        runner
+    }
+
+    #[test]
+    fn paused_runner_skips_updates() {
+        let mut runner = make_runner();
+        let dt = runner.config.fixed_dt;
+
+        runner.tick(dt);
+        assert_eq!(runner.game.updates, 1);
+
+        runner.set_paused(true);
+        assert!(runner.is_paused());
+        for _ in 0..10 {
+            runner.tick(dt);
+        }
+        assert_eq!(runner.game.updates, 1, "paused runner must not update the game");
+
+        runner.set_paused(false);
+        runner.tick(dt);
+        assert_eq!(runner.game.updates, 2);
+    }
+
+    #[test]
+    fn replayed_recording_reproduces_final_positions() {
+        use zap_engine::{Entity, EntityId};
+        use glam::Vec2;
+
+        /// Physics-free game: key presses nudge the entity by the key code.
+        struct NudgeGame;
+
+        impl Game for NudgeGame {
+            fn init(&mut self, ctx: &mut EngineContext) {
+                let id = ctx.next_id();
+                ctx.scene.spawn(Entity::new(id).with_tag("mover"));
+            }
+
+            fn update(&mut self, ctx: &mut EngineContext, input: &InputQueue) {
+                let mut nudge = Vec2::ZERO;
+                for event in input.iter() {
+                    if let InputEvent::KeyDown { key_code } = event {
+                        nudge.x += *key_code as f32;
+                        nudge.y += 0.5;
+                    }
+                }
+                if let Some(mover) = ctx.scene.first_by_tag_mut("mover") {
+                    mover.pos += nudge;
+                }
+            }
+        }
+
+        let mut runner = GameRunner::new(NudgeGame);
+        runner.init();
+        runner.start_recording();
+
+        let dt = runner.config.fixed_dt;
+        for key_code in 0..20 {
+            runner.push_input(InputEvent::KeyDown { key_code });
+            runner.tick(dt);
+        }
+        let recording = runner.export_recording();
+        let recorded_pos = runner.ctx.scene.first_by_tag("mover").unwrap().pos;
+        assert_ne!(recorded_pos, Vec2::ZERO);
+
+        runner.replay(&recording).unwrap();
+        let replayed_pos = runner.ctx.scene.first_by_tag("mover").unwrap().pos;
+        assert_eq!(replayed_pos, recorded_pos);
+
+        assert!(runner.replay("not json").is_err());
+    }
+
+    #[test]
+    fn input_cap_defers_excess_events_to_later_steps() {
+        use zap_engine::GameConfig;
+
+        /// Game that records how many events each update call saw.
+        struct EventCountingGame {
+            per_step: Vec<usize>,
+        }
+
+        impl Game for EventCountingGame {
+            fn config(&self) -> GameConfig {
+                GameConfig {
+                    max_input_events_per_step: 100,
+                    ..Default::default()
+                }
+            }
+
+            fn init(&mut self, _ctx: &mut EngineContext) {}
+
+            fn update(&mut self, _ctx: &mut EngineContext, input: &InputQueue) {
+                self.per_step.push(input.len());
+            }
+        }
+
+        let mut runner = GameRunner::new(EventCountingGame { per_step: Vec::new() });
+        runner.init();
+        for key_code in 0..1000 {
+            runner.push_input(InputEvent::KeyDown { key_code });
+        }
+
+        // One fixed step per tick: 1000 events at 100 per step drain over
+        // exactly 10 steps, then updates see an empty queue
+        let dt = runner.config.fixed_dt;
+        for _ in 0..12 {
+            runner.tick(dt);
+        }
+        let expected: Vec<usize> = std::iter::repeat(100).take(10).chain([0, 0]).collect();
+        assert_eq!(runner.game.per_step, expected);
+    }
+
+    #[test]
+    fn input_cap_folds_events_discarded_while_paused() {
+        use zap_engine::GameConfig;
+
+        /// Game that caps input so the paused drain path takes the capped branch.
+        struct CappedGame;
+
+        impl Game for CappedGame {
+            fn config(&self) -> GameConfig {
+                GameConfig {
+                    max_input_events_per_step: 4,
+                    ..Default::default()
+                }
+            }
+
+            fn init(&mut self, _ctx: &mut EngineContext) {}
+
+            fn update(&mut self, _ctx: &mut EngineContext, _input: &InputQueue) {}
+        }
+
+        let mut runner = GameRunner::new(CappedGame);
+        runner.init();
+        let dt = runner.config.fixed_dt;
+
+        // Key goes down before the pause and is released during it. The
+        // paused runner runs no fixed steps, so the release only reaches
+        // the polling-style state via the fold-before-drain path.
+        runner.push_input(InputEvent::KeyDown { key_code: 32 });
+        runner.tick(dt);
+        assert!(runner.ctx.input_state.is_key_down(32));
+
+        runner.set_paused(true);
+        runner.push_input(InputEvent::KeyUp { key_code: 32 });
+        runner.tick(dt);
+        assert!(
+            !runner.ctx.input_state.is_key_down(32),
+            "a KeyUp discarded during pause must still release the key"
+        );
+    }
+
+    #[test]
+    fn frame_stats_populate_after_a_tick() {
+        use zap_engine::{Entity, EntityId, SpriteComponent};
+
+        /// Game that spawns a visible entity so the counters have something to count.
+        struct SpriteGame;
+
+        impl Game for SpriteGame {
+            fn init(&mut self, ctx: &mut EngineContext) {
+                ctx.scene.spawn(
+                    Entity::new(EntityId(1)).with_sprite(SpriteComponent::default()),
+                );
+            }
+
+            fn update(&mut self, _ctx: &mut EngineContext, _input: &InputQueue) {}
+        }
+
+        let mut runner = GameRunner::new(SpriteGame);
+        runner.init();
+        assert_eq!(runner.stats().instances, 0);
+
+        runner.tick(runner.config.fixed_dt);
+        assert_eq!(runner.stats().instances, 1);
+        assert_eq!(runner.stats().sdf_instances, 0);
+        // Timings are zero unless built with the `profiling` feature
+        #[cfg(not(feature = "profiling"))]
+        assert_eq!(runner.stats().update_ms, 0.0);
+        #[cfg(feature = "profiling")]
+        assert!(runner.stats().update_ms >= 0.0);
+    }
+
+    #[test]
+    fn frame_data_reflects_counts_after_a_tick() {
+        use zap_engine::{Entity, EntityId, SpriteComponent};
+        use zap_engine::components::mesh::{MeshComponent, SDFColor};
+        use zap_engine::systems::lighting::PointLight;
+        use glam::Vec2;
+
+        /// Game with a known render load: two sprites, one mesh, one light.
+        struct BusyGame;
+
+        impl Game for BusyGame {
+            fn init(&mut self, ctx: &mut EngineContext) {
+                for _ in 0..2 {
+                    let id = ctx.next_id();
+                    ctx.scene.spawn(Entity::new(id).with_sprite(SpriteComponent::default()));
+                }
+                let id = ctx.next_id();
+                ctx.scene.spawn(
+                    Entity::new(id)
+                        .with_mesh(MeshComponent::sphere(8.0, SDFColor::new(1.0, 0.0, 0.0))),
+                );
+                ctx.lights.add(PointLight::new(Vec2::ZERO, [1.0, 1.0, 1.0], 1.0, 100.0));
+            }
+
+            fn update(&mut self, _ctx: &mut EngineContext, _input: &InputQueue) {}
+        }
+
+        let mut runner = GameRunner::new(BusyGame);
+        runner.init();
+        assert_eq!(runner.ctx.frame_data().instances, 0, "zeroed before the first tick");
+
+        runner.tick(runner.config.fixed_dt);
+        let frame = runner.ctx.frame_data();
+        assert_eq!(frame.instances, 2);
+        assert_eq!(frame.sdf_instances, 1);
+        assert_eq!(frame.lights, 1);
+        assert_eq!(frame.dt, runner.config.fixed_dt);
+    }
+
+    #[test]
+    fn positional_looping_sound_packs_expected_bytes() {
+        use zap_engine::SoundEvent;
+
+        /// Game that emits one rich sound every update.
+        struct HummingGame;
+
+        impl Game for HummingGame {
+            fn init(&mut self, _ctx: &mut EngineContext) {}
+
+            fn update(&mut self, ctx: &mut EngineContext, _input: &InputQueue) {
+                ctx.emit_sound(
+                    SoundEvent::new(3)
+                        .with_priority(7)
+                        .with_voice_group(2)
+                        .with_looping(true)
+                        .with_position(glam::Vec2::new(10.0, 20.0))
+                        .with_volume(0.5),
+                );
+            }
+        }
+
+        let mut runner = GameRunner::new(HummingGame);
+        runner.init();
+        runner.tick(runner.config.fixed_dt);
+
+        assert_eq!(runner.sound_events_len() as usize, SOUND_EVENT_BYTES);
+        let record = &runner.sound_buffer[..SOUND_EVENT_BYTES];
+        assert_eq!(record[0], 3, "id");
+        assert_eq!(record[1], 7, "priority");
+        assert_eq!(record[2], 2, "voice_group");
+        assert_eq!(record[3], 0b11, "looping + positional flags");
+        assert_eq!(f32::from_le_bytes(record[4..8].try_into().unwrap()), 0.5);
+        assert_eq!(f32::from_le_bytes(record[8..12].try_into().unwrap()), 10.0);
+        assert_eq!(f32::from_le_bytes(record[12..16].try_into().unwrap()), 20.0);
+    }
+
+    #[test]
+    fn plain_sound_packs_sentinel_group_and_zero_flags() {
+        use zap_engine::SoundEvent;
+
+        struct BeepGame;
+
+        impl Game for BeepGame {
+            fn init(&mut self, _ctx: &mut EngineContext) {}
+
+            fn update(&mut self, ctx: &mut EngineContext, _input: &InputQueue) {
+                ctx.emit_sound(SoundEvent::new(1));
+            }
+        }
+
+        let mut runner = GameRunner::new(BeepGame);
+        runner.init();
+        runner.tick(runner.config.fixed_dt);
+
+        let record = &runner.sound_buffer[..SOUND_EVENT_BYTES];
+        assert_eq!(record[0], 1);
+        assert_eq!(record[2], SOUND_NO_VOICE_GROUP);
+        assert_eq!(record[3], 0);
+        assert_eq!(f32::from_le_bytes(record[4..8].try_into().unwrap()), 1.0);
+    }
+
+    #[test]
+    fn input_state_exposes_held_keys_to_update() {
+        /// Game that samples the polling input state during update.
+        struct KeyProbe {
+            key_down: bool,
+        }
+
+        impl Game for KeyProbe {
+            fn init(&mut self, _ctx: &mut EngineContext) {}
+
+            fn update(&mut self, ctx: &mut EngineContext, _input: &InputQueue) {
+                self.key_down = ctx.input_state.is_key_down(32);
+            }
+        }
+
+        let mut runner = GameRunner::new(KeyProbe { key_down: false });
+        runner.init();
+        let dt = runner.config.fixed_dt;
+
+        runner.push_input(InputEvent::KeyDown { key_code: 32 });
+        runner.tick(dt);
+        assert!(runner.game.key_down, "key should read down during update");
+
+        // Still held on the next frame with no new events
+        runner.tick(dt);
+        assert!(runner.game.key_down);
+
+        runner.push_input(InputEvent::KeyUp { key_code: 32 });
+        runner.tick(dt);
+        assert!(!runner.game.key_down, "key should read up after KeyUp");
+    }
+
+    #[test]
+    fn time_scale_stretches_the_accumulator() {
+        let mut runner = make_runner();
+        let dt = runner.config.fixed_dt;
+
+        // At half speed, two real frames produce one fixed update.
+        runner.set_time_scale(0.5);
+        runner.tick(dt);
+        runner.tick(dt);
+        assert_eq!(runner.game.updates, 1);
+
+        // At double speed, one real frame produces two fixed updates.
+        runner.set_time_scale(2.0);
+        runner.tick(dt);
+        assert_eq!(runner.game.updates, 3);
+    }
+}
@@ -41,7 +41,10 @@ impl BasicDemo {
                 row: 0.0,
                 cell_span: 1.0,
                 alpha: 1.0,
+                alpha_cutoff: 0.0,
                 blend: BlendMode::Alpha,
+                tint: [1.0, 1.0, 1.0],
+                nine_slice: None,
             });
 
         // Random velocity derived from entity ID
@@ -134,7 +137,7 @@ impl Game for BasicDemo {
     fn update(&mut self, ctx: &mut EngineContext, input: &InputQueue) {
         // Handle input: spawn sprite on click
         for event in input.iter() {
-            if let InputEvent::PointerDown { x, y } = event {
+            if let InputEvent::PointerDown { x, y, .. } = event {
                 self.spawn_physics_sprite(ctx, Vec2::new(*x, *y));
                 // Spawn a particle burst at click position
                 ctx.effects.spawn_particles(
@@ -144,7 +147,7 @@ impl Game for BasicDemo {
                     3.0,
                     1.5,
                 );
-                ctx.emit_sound(SoundEvent(0));
+                ctx.emit_sound(SoundEvent::new(0));
             }
         }
 
@@ -198,7 +198,7 @@ impl Game for ChemistryLab {
                 InputEvent::Custom { kind, a, b, c } => {
                     self.handle_custom_event(*kind, *a, *b, *c);
                 }
-                InputEvent::PointerDown { x, y } => {
+                InputEvent::PointerDown { x, y, .. } => {
                     let pos = Vec2::new(*x, *y);
                     let result = self.interaction.on_pointer_down(
                         pos,
@@ -208,12 +208,12 @@ impl Game for ChemistryLab {
                     );
                     self.handle_interaction(result);
                 }
-                InputEvent::PointerMove { x, y } => {
+                InputEvent::PointerMove { x, y, .. } => {
                     let pos = Vec2::new(*x, *y);
                     let result = self.interaction.on_pointer_move(pos, &mut self.camera);
                     self.handle_interaction(result);
                 }
-                InputEvent::PointerUp { x, y } => {
+                InputEvent::PointerUp { x, y, .. } => {
                     let pos = Vec2::new(*x, *y);
                     let result = self.interaction.on_pointer_up(
                         pos,
@@ -60,6 +60,7 @@ pub fn build_3d_sdf_buffer(
                 shape_type: 0.0, // Sphere
                 half_height: 0.0,
                 extra: 0.0,
+                ..Default::default()
             },
         });
     }
@@ -156,6 +157,7 @@ pub fn build_3d_sdf_buffer(
                 shape_type: 1.0, // Capsule
                 half_height,
                 extra: 0.0,
+                ..Default::default()
             },
         });
     }
@@ -761,7 +761,7 @@ impl Glypher {
     fn handle_input(&mut self, input: &InputQueue, ctx: &mut EngineContext) {
         for event in input.iter() {
             match event {
-                InputEvent::PointerDown { x, y } => {
+                InputEvent::PointerDown { x, y, .. } => {
                     if self.phase == GamePhase::Tracing {
                         let pos = Vec2::new(*x, *y);
                         match self.tracer.on_pointer_down(pos) {
@@ -773,7 +773,7 @@ impl Glypher {
                         }
                     }
                 }
-                InputEvent::PointerMove { x, y } => {
+                InputEvent::PointerMove { x, y, .. } => {
                     if self.phase == GamePhase::Tracing {
                         let pos = Vec2::new(*x, *y);
                         match self.tracer.on_pointer_move(pos) {
@@ -127,7 +127,10 @@ impl PhysicsPlayground {
                         row: 0.0,
                         cell_span: 1.0,
                         alpha: 1.0,
+                        alpha_cutoff: 0.0,
                         blend: BlendMode::Alpha,
+                        tint: [1.0, 1.0, 1.0],
+                        nine_slice: None,
                     });
 
                 let desc = BodyDesc::dynamic(ColliderDesc::Cuboid {
@@ -191,7 +194,10 @@ impl PhysicsPlayground {
                 row: 0.0,
                 cell_span: 1.0,
                 alpha: 1.0,
+                alpha_cutoff: 0.0,
                 blend: BlendMode::Alpha,
+                tint: [1.0, 1.0, 1.0],
+                nine_slice: None,
             });
 
         let desc = BodyDesc::dynamic(ColliderDesc::Ball { radius: BALL_RADIUS })
@@ -280,7 +286,10 @@ impl Game for PhysicsPlayground {
                     row: 0.0,
                     cell_span: 1.0,
                     alpha: 0.4,
+                    alpha_cutoff: 0.0,
                     blend: BlendMode::Alpha,
+                    tint: [1.0, 1.0, 1.0],
+                    nine_slice: None,
                 }),
         );
     }
@@ -293,7 +302,7 @@ impl Game for PhysicsPlayground {
                     self.reset_level(ctx);
                     return;
                 }
-                InputEvent::PointerDown { x, y } => {
+                InputEvent::PointerDown { x, y, .. } => {
                     if self.state == GameState::Aiming {
                         let pos = Vec2::new(*x, *y);
                         let dist = pos.distance(Vec2::new(SLING_X, SLING_Y));
@@ -304,12 +313,12 @@ impl Game for PhysicsPlayground {
                         }
                     }
                 }
-                InputEvent::PointerMove { x, y } => {
+                InputEvent::PointerMove { x, y, .. } => {
                     if self.dragging {
                         self.drag_current = Vec2::new(*x, *y);
                     }
                 }
-                InputEvent::PointerUp { x, y } => {
+                InputEvent::PointerUp { x, y, .. } => {
                     if self.dragging {
                         self.drag_current = Vec2::new(*x, *y);
                         self.dragging = false;
@@ -565,9 +565,9 @@ impl PoolGame {
     }
 
     /// Setup dynamic lighting - main table lights + dim pocket lights
-    /// Main lights affect all layers except Terrain (0x3D), pocket lights affect only Terrain (0x02)
+    /// Main lights affect all layers except Terrain, pocket lights affect only Terrain
     fn setup_lights(&self, ctx: &mut EngineContext) {
-        use zap_engine::PointLight;
+        use zap_engine::{LayerMask, PointLight};
 
         ctx.lights.clear();
         // Moderate ambient for main table
@@ -577,9 +577,9 @@ impl PoolGame {
         let intensity = 0.8;
         let radius = 450.0;
 
-        // Layer masks: Terrain = 0x02, everything else = 0x3D (0x3F minus 0x02)
-        let main_layer_mask = 0x3D;  // Affects all except Terrain (pocketed balls)
-        let pocket_layer_mask = 0x02;  // Affects only Terrain (pocketed balls)
+        // Pocketed balls live on Terrain and get their own dim lights
+        let main_layer_mask = LayerMask::ALL.without(LayerMask::TERRAIN);
+        let pocket_layer_mask = LayerMask::TERRAIN;
 
         // Three evenly-spaced overhead lights (over the table) - main lights
         let spacing = TABLE_W / 4.0;
@@ -935,7 +935,7 @@ impl Game for PoolGame {
                     self.reset(ctx);
                     return;
                 }
-                InputEvent::PointerDown { x, y } => {
+                InputEvent::PointerDown { x, y, .. } => {
                     if self.state == GameState::Aiming {
                         // Start aiming from anywhere on the table (easier to use)
                         self.aiming = true;
@@ -943,7 +943,7 @@ impl Game for PoolGame {
                         self.aim_current = Vec2::new(*x, *y);
                     }
                 }
-                InputEvent::PointerMove { x, y } => {
+                InputEvent::PointerMove { x, y, .. } => {
                     if self.aiming {
                         self.aim_current = Vec2::new(*x, *y);
                     }
@@ -549,13 +549,13 @@ impl Game for SolarSystem {
                     }
                     _ => {}
                 },
-                InputEvent::PointerDown { x, y } => {
+                InputEvent::PointerDown { x, y, .. } => {
                     self.dragging = true;
                     self.drag_moved = false;
                     self.drag_start = (*x, *y);
                     self.drag_cam_start = (self.cam_x, self.cam_y);
                 }
-                InputEvent::PointerMove { x, y } => {
+                InputEvent::PointerMove { x, y, .. } => {
                     if self.dragging {
                         let dx = *x - self.drag_start.0;
                         let dy = *y - self.drag_start.1;
@@ -569,7 +569,7 @@ impl Game for SolarSystem {
                         }
                     }
                 }
-                InputEvent::PointerUp { x, y } => {
+                InputEvent::PointerUp { x, y, .. } => {
                     if self.dragging && !self.drag_moved {
                         // Click (not a drag) → select planet
                         self.selected = self.hit_test(Vec2::new(*x, *y));
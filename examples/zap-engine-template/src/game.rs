@@ -41,7 +41,10 @@ impl Game for HelloGame {
                     row: 0.0,
                     cell_span: 1.0,
                     alpha: 1.0,
+                    alpha_cutoff: 0.0,
                     blend: BlendMode::Alpha,
+                    tint: [1.0, 1.0, 1.0],
+                    nine_slice: None,
                 }),
         );
         self.sprite_id = Some(id);
@@ -250,7 +250,10 @@ impl ZapZapMini {
                     row: 0.0, // row 0 — background tile
                     cell_span: 1.0,
                     alpha: 1.0,
+                    alpha_cutoff: 0.0,
                     blend: BlendMode::Alpha,
+                    tint: [1.0, 1.0, 1.0],
+                    nine_slice: None,
                 }),
         );
 
@@ -270,7 +273,10 @@ impl ZapZapMini {
                         row: ATLAS_ROW_PINS,
                         cell_span: 1.0,
                         alpha: 1.0,
+                        alpha_cutoff: 0.0,
                         blend: BlendMode::Alpha,
+                        tint: [1.0, 1.0, 1.0],
+                        nine_slice: None,
                     }),
             );
         }
@@ -291,7 +297,10 @@ impl ZapZapMini {
                         row: ATLAS_ROW_PINS,
                         cell_span: 1.0,
                         alpha: 1.0,
+                        alpha_cutoff: 0.0,
                         blend: BlendMode::Alpha,
+                        tint: [1.0, 1.0, 1.0],
+                        nine_slice: None,
                     }),
             );
         }
@@ -331,7 +340,10 @@ impl ZapZapMini {
                                 row: ATLAS_ROW_NORMAL,
                                 cell_span: 1.0,
                                 alpha: 1.0,
+                                alpha_cutoff: 0.0,
                                 blend: BlendMode::Alpha,
+                                tint: [1.0, 1.0, 1.0],
+                                nine_slice: None,
                             }),
                     );
                 }
@@ -388,7 +400,7 @@ impl Game for ZapZapMini {
             GamePhase::WaitingForInput => {
                 // Handle pointer down → rotate tile
                 for event in input.iter() {
-                    if let InputEvent::PointerDown { x, y } = event {
+                    if let InputEvent::PointerDown { x, y, .. } = event {
                         if let Some((gx, gy)) = Self::world_to_grid(*x, *y) {
                             self.tap_tile(gx, gy);
                             break;
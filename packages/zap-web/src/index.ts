@@ -45,6 +45,11 @@ export {
   INSTANCE_FLOATS,
   EFFECTS_VERTEX_FLOATS,
   EVENT_FLOATS,
+  EVENT_KIND,
+  EVENT_A,
+  EVENT_B,
+  EVENT_C,
+  readGameEvent,
   SDF_INSTANCE_FLOATS,
   VECTOR_VERTEX_FLOATS,
   LAYER_BATCH_FLOATS,
@@ -63,6 +68,7 @@ export { SEGMENT_COLORS, SEGMENT_COLORS_RGB8, packColorsForGPU } from './rendere
 export { computeProjection, buildProjectionMatrix } from './renderer/camera';
 export { readFrameState } from './worker/frame-reader';
 export type { FrameState } from './worker/frame-reader';
+export type { GameEvent } from './worker/protocol';
 
 /** Create an engine worker instance. Works with any Vite-based bundler. */
 export function createEngineWorker(): Worker {
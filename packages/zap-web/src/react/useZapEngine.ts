@@ -16,15 +16,10 @@ import {
   createEngineWorker,
   readFrameState,
 } from '../index';
-import type { Renderer, SoundConfig } from '../index';
-
-/** Game event forwarded from the worker. */
-export interface GameEvent {
-  kind: number;
-  a: number;
-  b: number;
-  c: number;
-}
+import type { Renderer, SoundConfig, GameEvent } from '../index';
+
+// Re-exported so existing imports of the event shape keep working
+export type { GameEvent };
 
 /** Performance timing data for profiling. */
 export interface PerformanceTiming {
@@ -226,8 +221,8 @@ export function useZapEngine(config: ZapEngineConfig): ZapEngineState {
         } else if (type === 'sound') {
           const sm = soundManagerRef.current;
           if (sm) {
-            for (const id of e.data.events) {
-              sm.play(id);
+            for (const ev of e.data.events) {
+              sm.play(ev.id);
             }
           }
         } else if (type === 'event') {
@@ -247,6 +242,7 @@ export function useZapEngine(config: ZapEngineConfig): ZapEngineState {
         soundManagerRef.current?.resume();
         workerRef.current?.postMessage({
           type: 'pointer_down',
+          pointerId: e.pointerId,
           x: e.offsetX,
           y: e.offsetY,
         });
@@ -254,6 +250,7 @@ export function useZapEngine(config: ZapEngineConfig): ZapEngineState {
       function onPointerUp(e: PointerEvent) {
         workerRef.current?.postMessage({
           type: 'pointer_up',
+          pointerId: e.pointerId,
           x: e.offsetX,
           y: e.offsetY,
         });
@@ -261,6 +258,7 @@ export function useZapEngine(config: ZapEngineConfig): ZapEngineState {
       function onPointerMove(e: PointerEvent) {
         workerRef.current?.postMessage({
           type: 'pointer_move',
+          pointerId: e.pointerId,
           x: e.offsetX,
           y: e.offsetY,
         });
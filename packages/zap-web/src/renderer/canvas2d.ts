@@ -104,8 +104,20 @@ export async function initCanvas2DRenderer(config: Canvas2DRendererConfig): Prom
     const x1 = data[off1], y1 = data[off1 + 1];
     const x2 = data[off2], y2 = data[off2 + 1];
 
-    const colorIdx = Math.round(data[off0 + 2]);
-    const [r, g, b] = SEGMENT_COLORS_RGB8[Math.min(Math.max(colorIdx, 0), SEGMENT_COLORS_RGB8.length - 1)];
+    // Negative z carries a packed RGB color instead of a palette index:
+    // -(1 + r8 + (g8 << 8) + (b8 << 16)), emitted by gradient particles.
+    // Mirrors segment_color() in shaders.wgsl.
+    let r: number, g: number, b: number;
+    const colorIdx = data[off0 + 2];
+    if (colorIdx < 0) {
+      const packed = Math.round(-colorIdx - 1);
+      r = packed & 0xFF;
+      g = (packed >> 8) & 0xFF;
+      b = (packed >> 16) & 0xFF;
+    } else {
+      const idx = Math.round(colorIdx);
+      [r, g, b] = SEGMENT_COLORS_RGB8[Math.min(idx, SEGMENT_COLORS_RGB8.length - 1)];
+    }
 
     const u0 = data[off0 + 3], u1 = data[off1 + 3], u2 = data[off2 + 3];
     const v0v = data[off0 + 4], v1v = data[off1 + 4], v2v = data[off2 + 4];
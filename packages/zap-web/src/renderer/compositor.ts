@@ -86,8 +86,8 @@ export class LayerCompositor {
   static decodeBakeState(encoded: number): { bakedMask: number; bakeGen: number } {
     const raw = Math.floor(encoded);
     return {
-      bakedMask: raw & 0x3F,
-      bakeGen: raw >>> 6,
+      bakedMask: raw & 0x7F,
+      bakeGen: raw >>> 7,
     };
   }
 
@@ -140,6 +140,20 @@ export class LayerCompositor {
     return { texture, view };
   }
 
+  /**
+   * Whether a layer already has cached texture content that a partial
+   * re-bake can load from (i.e., it was fully baked at least once at the
+   * current size). False means the dirty-region fast path must fall back
+   * to a full re-bake.
+   */
+  hasBakedContent(layerId: number): boolean {
+    const cache = this.caches.get(layerId);
+    return !!cache
+      && cache.lastBakeGen >= 0
+      && cache.texture.width === this.width
+      && cache.texture.height === this.height;
+  }
+
   /**
    * Mark a layer's cache as up-to-date with the given bake generation.
    * Call this after rendering the layer to its intermediate texture.
@@ -68,13 +68,37 @@ fn fs_lighting(in: VertexOutput) -> @location(0) vec4<f32> {
 
     for (var i = 0u; i < light_count; i = i + 1u) {
         let light = lights[i];
+
+        // Negative radius marks the directional-light slot: x/y hold the
+        // travel direction, illumination is uniform (no distance falloff).
+        if (light.radius < 0.0) {
+            let L = normalize(vec3<f32>(-light.x, -light.y, 1.0));
+            let NdotL_dir = max(dot(N, L), 0.0);
+            total_light = total_light + vec3<f32>(light.r, light.g, light.b) * light.intensity * NdotL_dir;
+            continue;
+        }
+
         let light_pos = vec2<f32>(light.x, light.y);
         let delta = light_pos - world_pos;
         let d = length(delta);
 
-        // Smooth quadratic falloff: (1 - d/r)^2
+        // Falloff model lives in layer_mask bits 7-8 (bits 0-6 = layers)
+        let falloff = (u32(light.layer_mask) >> 7u) & 0x3u;
         let norm_dist = saturate(1.0 - d / light.radius);
-        let attenuation = norm_dist * norm_dist;
+        var attenuation: f32;
+        if (falloff == 1u) {
+            // Linear: straight fade to the radius
+            attenuation = norm_dist;
+        } else if (falloff == 2u) {
+            // Inverse-square 1/(1+nd)^2, normalized so the curve spans
+            // exactly [1, 0] over [center, radius]
+            let nd = 1.0 - norm_dist;
+            let inv = 1.0 / ((1.0 + nd) * (1.0 + nd));
+            attenuation = (inv - 0.25) * (4.0 / 3.0);
+        } else {
+            // Quadratic (default): (1 - d/r)^2
+            attenuation = norm_dist * norm_dist;
+        }
 
         // Light direction in tangent space: (dx, dy, height_above_surface)
         // Use a fixed Z height for 2D point lights (simulates light above the surface)
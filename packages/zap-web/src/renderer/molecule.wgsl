@@ -1,6 +1,7 @@
 // ZapEngine — SDF Molecule Shader (Raymarched Shapes)
 // Renders instanced quads with per-fragment SDF raymarching.
-// Supports Sphere (atoms), Capsule (bonds), and RoundedBox (labels).
+// Supports Sphere (atoms), Capsule (bonds), RoundedBox/Box (labels, UI
+// chips), and Segment (rotated capsules between two points).
 // Phong shading + Fresnel rim glow + HDR emissive.
 
 // ---- Camera Uniform (shared with sprite pipeline) ----
@@ -11,8 +12,10 @@ struct Camera {
 @group(0) @binding(0) var<uniform> camera: Camera;
 
 // ---- SDF Instance Storage Buffer ----
-// Matches SDFInstance layout: 12 floats = 48 bytes per instance.
-// [x, y, radius, rotation, r, g, b, shininess, emissive, shape_type, half_height, extra]
+// Matches SDFInstance layout: 20 floats = 80 bytes per instance.
+// [x, y, radius, rotation, r, g, b, shininess, emissive, shape_type,
+//  half_height, extra, radius_b, half_height_b, extra_b, smoothing,
+//  outline_width, outline_r, outline_g, outline_b]
 
 struct SDFInstance {
     position: vec2<f32>,
@@ -21,11 +24,36 @@ struct SDFInstance {
     color: vec3<f32>,
     shininess: f32,
     emissive: f32,
+    // Packs shape_a (bits 0-2), shape_b (bits 3-5), op (bits 6-7).
+    // Plain primitives have op = 0 and read as 0/1/2/3/4 like before.
     shape_type: f32,
     half_height: f32,
     extra: f32,
+    radius_b: f32,
+    half_height_b: f32,
+    extra_b: f32,
+    smoothing: f32,
+    outline_width: f32,
+    outline_r: f32,
+    outline_g: f32,
+    outline_b: f32,
 };
 
+// ---- shape_type decoding ----
+
+fn decode_shape_a(packed: f32) -> f32 {
+    return f32(u32(packed + 0.5) & 7u);
+}
+
+fn decode_shape_b(packed: f32) -> f32 {
+    return f32((u32(packed + 0.5) >> 3u) & 7u);
+}
+
+// Combine op: 0 = none, 1 = union, 2 = subtract, 3 = intersect
+fn decode_op(packed: f32) -> u32 {
+    return (u32(packed + 0.5) >> 6u) & 3u;
+}
+
 @group(1) @binding(0) var<storage, read> sdf_instances: array<SDFInstance>;
 
 // ---- Dynamic Light Data (shared with lighting pass) ----
@@ -74,6 +102,11 @@ struct VertexOutput {
     @location(7) world_center: vec2<f32>,  // Instance center in world space
     @location(8) world_radius: f32,        // Instance radius in world units
     @location(9) rotation: f32,            // Entity rotation for local→world normal transform
+    // Combine-only: (radius_b / radius, half_height_b / radius_b, extra_b / radius_b)
+    @location(10) b_params: vec3<f32>,
+    @location(11) smoothing_norm: f32,     // smoothing / radius
+    @location(12) outline_color: vec3<f32>,
+    @location(13) outline_width_norm: f32, // outline_width / radius, 0 = disabled
 };
 
 // Fullscreen quad — two triangles, 6 vertices
@@ -105,17 +138,20 @@ fn vs_sdf(input: VertexInput) -> VertexOutput {
 
     // Determine quad extent based on shape:
     // Capsule/RoundedBox need elongated quads to cover the full shape.
-    var quad_w = inst.radius * overscan;
-    var quad_h = inst.radius * overscan;
-
-    // For non-sphere shapes, extend the quad to cover full geometry
-    let is_capsule = inst.shape_type > 0.5 && inst.shape_type < 1.5;
-    let is_box = inst.shape_type > 1.5;
-    if (is_capsule || is_box) {
-        // half_height is in world units — add it to the quad extent
-        quad_w = (inst.radius + inst.half_height) * overscan;
-        quad_h = (inst.radius + inst.half_height) * overscan;
+    let shape_a = decode_shape_a(inst.shape_type);
+
+    // half_height is in world units — every non-sphere shape extends
+    // along its local Y axis, so add it to the quad extent.
+    var reach = inst.radius;
+    if (shape_a > 0.5) {
+        reach = inst.radius + inst.half_height;
+    }
+    // Combined shapes must also cover the second shape's footprint
+    if (decode_op(inst.shape_type) != 0u) {
+        reach = max(reach, inst.radius_b + inst.half_height_b);
     }
+    let quad_w = reach * overscan;
+    let quad_h = reach * overscan;
 
     // Apply entity rotation to the quad corners
     let cos_r = cos(inst.rotation);
@@ -131,12 +167,7 @@ fn vs_sdf(input: VertexInput) -> VertexOutput {
     // Pass UV and normalize shape params relative to radius.
     // Multiply by overscan so |local_uv|=1.0 maps to exactly inst.radius
     // in world space (the extra quad area beyond 1.0 is discarded by the SDF).
-    out.local_uv = uv * overscan;
-    if (is_capsule || is_box) {
-        // Scale UV to cover the full elongated extent
-        let extent = (inst.radius + inst.half_height) / inst.radius;
-        out.local_uv = uv * extent * overscan;
-    }
+    out.local_uv = uv * (reach / max(inst.radius, 0.001)) * overscan;
     out.base_color = inst.color;
     out.shininess = inst.shininess;
     out.emissive = inst.emissive;
@@ -146,6 +177,14 @@ fn vs_sdf(input: VertexInput) -> VertexOutput {
     out.world_center = inst.position;
     out.world_radius = inst.radius;
     out.rotation = inst.rotation;
+    out.b_params = vec3(
+        inst.radius_b / max(inst.radius, 0.001),
+        inst.half_height_b / max(inst.radius_b, 0.001),
+        inst.extra_b / max(inst.radius_b, 0.001),
+    );
+    out.smoothing_norm = inst.smoothing / max(inst.radius, 0.001);
+    out.outline_color = vec3(inst.outline_r, inst.outline_g, inst.outline_b);
+    out.outline_width_norm = inst.outline_width / max(inst.radius, 0.001);
 
     return out;
 }
@@ -171,6 +210,57 @@ fn sdf_rounded_box(p: vec2<f32>, half_h: f32, corner_r: f32) -> f32 {
     return length(max(d, vec2(0.0))) + min(max(d.x, d.y), 0.0) - corner_r;
 }
 
+// Dispatch on a decoded shape code (0 = sphere, 1 = capsule, 2 = rounded
+// box, 3 = box, 4 = segment). Boxes share the rounded-box field — their
+// half-extents are pre-normalized on the Rust side — and segments arrive
+// as rotated capsules.
+fn sdf_primitive(p: vec2<f32>, shape: f32, half_h: f32, corner_r: f32) -> f32 {
+    if (shape < 0.5) {
+        return sdf_sphere(p);
+    } else if (shape < 1.5 || shape > 3.5) {
+        return sdf_capsule(p, half_h);
+    }
+    return sdf_rounded_box(p, half_h, corner_r);
+}
+
+// ---- Smooth boolean ops (polynomial smooth min) ----
+
+fn smin(a: f32, b: f32, k: f32) -> f32 {
+    if (k <= 0.0) {
+        return min(a, b);
+    }
+    let h = saturate(0.5 + 0.5 * (b - a) / k);
+    return mix(b, a, h) - k * h * (1.0 - h);
+}
+
+fn smax(a: f32, b: f32, k: f32) -> f32 {
+    return -smin(-a, -b, k);
+}
+
+// Combined field for instances carrying an op code. Shape b is evaluated
+// in its own unit space (radius_b = 1.0) and rescaled so both distances
+// share shape a's normalization.
+fn sdf_combined(
+    p: vec2<f32>,
+    shape_a: f32,
+    half_h_a: f32,
+    corner_r_a: f32,
+    shape_b: f32,
+    b_params: vec3<f32>,
+    op: u32,
+    k: f32,
+) -> f32 {
+    let d_a = sdf_primitive(p, shape_a, half_h_a, corner_r_a);
+    let scale_b = max(b_params.x, 0.001);
+    let d_b = sdf_primitive(p / scale_b, shape_b, b_params.y, b_params.z) * scale_b;
+    if (op == 1u) {
+        return smin(d_a, d_b, k);       // Union
+    } else if (op == 2u) {
+        return smax(d_a, -d_b, k);      // Subtract
+    }
+    return smax(d_a, d_b, k);           // Intersect
+}
+
 
 
 // ---- Fragment Shader: Raymarched SDF ----
@@ -187,7 +277,34 @@ fn fs_sdf(in: VertexOutput) -> @location(0) vec4<f32> {
     // Track if this is a striped pool ball (extra_norm > 0.5)
     var is_striped_ball = false;
 
-    if (in.shape_type < 0.5) {
+    let op = decode_op(in.shape_type);
+    let shape_a = decode_shape_a(in.shape_type);
+
+    if (op != 0u) {
+        // ---- Combined shapes ----
+        let shape_b = decode_shape_b(in.shape_type);
+        let k = in.smoothing_norm;
+        dist = sdf_combined(p, shape_a, in.half_height_norm, in.extra_norm,
+                            shape_b, in.b_params, op, k);
+        if (dist > 0.02) {
+            discard;
+        }
+        // Central-difference gradient of the combined field; the z term
+        // uses the hemisphere profile z² = -d(2 + d), which reproduces
+        // the analytic sphere normal when the field is a plain sphere.
+        let eps = 0.01;
+        let gx = sdf_combined(p + vec2(eps, 0.0), shape_a, in.half_height_norm, in.extra_norm,
+                              shape_b, in.b_params, op, k)
+               - sdf_combined(p - vec2(eps, 0.0), shape_a, in.half_height_norm, in.extra_norm,
+                              shape_b, in.b_params, op, k);
+        let gy = sdf_combined(p + vec2(0.0, eps), shape_a, in.half_height_norm, in.extra_norm,
+                              shape_b, in.b_params, op, k)
+               - sdf_combined(p - vec2(0.0, eps), shape_a, in.half_height_norm, in.extra_norm,
+                              shape_b, in.b_params, op, k);
+        let g = vec2(gx, gy) / (2.0 * eps);
+        let z = sqrt(max(-dist * (2.0 + dist), 0.0));
+        normal = normalize(vec3(g * (1.0 + dist), z));
+    } else if (shape_a < 0.5) {
         // ---- Sphere ----
         let d2 = dot(p, p);
         if (d2 > 1.0) {
@@ -200,8 +317,8 @@ fn fs_sdf(in: VertexOutput) -> @location(0) vec4<f32> {
         // Check for striped ball (pool balls 9-15)
         // Note: extra_norm = extra / radius, so 1.0/12.0 ≈ 0.08 for typical balls
         is_striped_ball = in.extra_norm > 0.01;
-    } else if (in.shape_type < 1.5) {
-        // ---- Capsule ----
+    } else if (shape_a < 1.5 || shape_a > 3.5) {
+        // ---- Capsule / Segment ----
         // Analytic normal: vector from clamped axis point to p
         let half_h = in.half_height_norm;
         let q_y_clamped = clamp(p.y, -half_h, half_h);
@@ -240,7 +357,7 @@ fn fs_sdf(in: VertexOutput) -> @location(0) vec4<f32> {
             normal = vec3(n2d, z);
         }
     } else {
-        // ---- RoundedBox ----
+        // ---- RoundedBox / Box ----
         // Analytic gradient for rounded box
         // d = length(max(q, 0)) + min(max(q.x, q.y), 0) - r
         // where q = abs(p) - b
@@ -317,6 +434,13 @@ fn fs_sdf(in: VertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
+    // Outline ring: replace the base color in the band just inside the
+    // surface. Width is normalized to radius, so the ring stays a constant
+    // world-space thickness as shapes scale.
+    if (in.outline_width_norm > 0.0 && dist > -in.outline_width_norm) {
+        final_base_color = in.outline_color;
+    }
+
     // Transform normal from local space to world space for lighting
     // The normal's XY components need to be rotated by the entity's rotation
     // so that light reflections stay fixed in world space as the ball spins
@@ -412,35 +536,19 @@ fn fs_sdf_normal(in: VertexOutput) -> @location(0) vec4<f32> {
     let p = in.local_uv;
 
     // Discard outside shape bounds (same logic as main fragment shader)
-    if (in.shape_type < 0.5) {
-        // Sphere
-        let d2 = dot(p, p);
-        if (d2 > 1.0) {
-            discard;
-        }
-    } else if (in.shape_type < 1.5) {
-        // Capsule
-        let half_h = in.half_height_norm;
-        let q_y_clamped = clamp(p.y, -half_h, half_h);
-        let closest = vec2(0.0, q_y_clamped);
-        let diff = p - closest;
-        let dist = length(diff) - 1.0;
-        if (dist > 0.02) {
-            discard;
-        }
+    let op = decode_op(in.shape_type);
+    let shape_a = decode_shape_a(in.shape_type);
+    var dist: f32;
+    if (op != 0u) {
+        dist = sdf_combined(p, shape_a, in.half_height_norm, in.extra_norm,
+                            decode_shape_b(in.shape_type), in.b_params, op, in.smoothing_norm);
     } else {
-        // RoundedBox
-        let half_h = in.half_height_norm;
-        let corner_r = in.extra_norm;
-        let half_extents = vec2(1.0, half_h);
-        let q = abs(p) - half_extents + vec2(corner_r);
-        let dist_vec = max(q, vec2(0.0));
-        let outside_dist = length(dist_vec);
-        let inside_dist = min(max(q.x, q.y), 0.0);
-        let dist = outside_dist + inside_dist - corner_r;
-        if (dist > 0.02) {
-            discard;
-        }
+        dist = sdf_primitive(p, shape_a, in.half_height_norm, in.extra_norm);
+    }
+    // Plain spheres have no AA slack beyond their radius
+    let limit = select(0.02, 0.0, op == 0u && shape_a < 0.5);
+    if (dist > limit) {
+        discard;
     }
 
     // Write flat normal (0, 0, 1) encoded as (0.5, 0.5, 1.0)
@@ -15,17 +15,28 @@ struct Camera {
 @group(1) @binding(1) var s_atlas: sampler;
 
 // ---- Instance data from storage buffer ----
-// Matches RenderInstance layout: 8 floats = 32 bytes per instance.
-// [x, y, rotation, scale, sprite_col, alpha, cell_span, atlas_row]
+// Matches RenderInstance layout: 15 floats = 60 bytes per instance.
+// [x, y, rotation, scale, sprite_col, alpha, cell_span, atlas_row, alpha_cutoff,
+//  tint_r, tint_g, tint_b, scale_y, cell_span_y, blend]
+// Position is stored as two scalars — a vec2 would force 8-byte alignment
+// and pad the struct to 40 bytes, breaking the wire stride.
 
 struct Instance {
-    position: vec2<f32>,
+    x: f32,
+    y: f32,
     rotation: f32,
     scale: f32,
     sprite_col: f32,
     alpha: f32,
     cell_span: f32,
     atlas_row: f32,
+    alpha_cutoff: f32,
+    tint_r: f32,
+    tint_g: f32,
+    tint_b: f32,
+    scale_y: f32,
+    cell_span_y: f32,
+    blend: f32,
 };
 
 @group(2) @binding(0) var<storage, read> instances: array<Instance>;
@@ -38,6 +49,17 @@ struct SegmentColors {
 @group(3) @binding(0) var<uniform> segment_colors: SegmentColors;
 
 fn segment_color(idx: f32) -> vec3<f32> {
+    // Negative values carry a packed RGB color instead of a palette
+    // index: -(1 + r8 + (g8 << 8) + (b8 << 16)), emitted by gradient
+    // particles. 24 bits are exact in f32, so decoding is lossless.
+    if (idx < 0.0) {
+        let packed = u32(-idx - 1.0);
+        return vec3<f32>(
+            f32(packed & 0xFFu),
+            f32((packed >> 8u) & 0xFFu),
+            f32((packed >> 16u) & 0xFFu),
+        ) / 255.0;
+    }
     let i = min(u32(idx + 0.5), 12u);
     return segment_colors.values[i].xyz;
 }
@@ -54,6 +76,8 @@ struct VertexOutput {
     @location(0) tex_coord: vec2<f32>,
     @location(1) alpha: f32,
     @location(2) color_idx: f32,
+    @location(3) alpha_cutoff: f32,
+    @location(4) tint: vec3<f32>,
 };
 
 const QUAD_POS = array<vec2<f32>, 4>(
@@ -89,32 +113,40 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     // Scale is now world-space size directly (no hardcoded tile_size multiplier).
     // Games write the actual rendered size into inst.scale.
-    let tile_size = inst.scale;
+    // scale_y == 0 means a square quad (legacy single-float scale).
+    let tile_size = vec2<f32>(
+        inst.scale,
+        select(inst.scale_y, inst.scale, inst.scale_y <= 0.0),
+    );
 
-    // Apply rotation
+    // Scale in sprite space, then rotate into world space
+    let scaled = pos * tile_size;
     let cos_r = cos(inst.rotation);
     let sin_r = sin(inst.rotation);
     let rotated = vec2<f32>(
-        pos.x * cos_r - pos.y * sin_r,
-        pos.x * sin_r + pos.y * cos_r,
+        scaled.x * cos_r - scaled.y * sin_r,
+        scaled.x * sin_r + scaled.y * cos_r,
     );
 
-    // Scale and translate to world position
-    let world_pos = rotated * tile_size + inst.position;
+    let world_pos = rotated + vec2<f32>(inst.x, inst.y);
     out.clip_position = camera.projection * vec4<f32>(world_pos, 0.0, 1.0);
 
     // Map sprite_col to atlas UV.
     let col = inst.sprite_col % ATLAS_COLS;
     let row = inst.atlas_row;
 
-    // cell_span encodes UV cell count: 1.0 = single cell, 2.0 = 2×2 block
-    let cell_size = max(inst.cell_span, 1.0);
+    // cell_span encodes UV cell count: 1.0 = single cell, 2.0 = 2×2 block.
+    // Fractional spans address sub-cell regions (9-slice); 0 = default cell.
+    let span_x = select(inst.cell_span, 1.0, inst.cell_span <= 0.0);
+    let span_y = select(inst.cell_span_y, span_x, inst.cell_span_y <= 0.0);
     let uv_origin = vec2<f32>(col / ATLAS_COLS, row / ATLAS_ROWS);
-    let uv_size = vec2<f32>(cell_size / ATLAS_COLS, cell_size / ATLAS_ROWS);
+    let uv_size = vec2<f32>(span_x / ATLAS_COLS, span_y / ATLAS_ROWS);
     out.tex_coord = uv_origin + uv * uv_size;
 
     out.alpha = inst.alpha;
     out.color_idx = 0.0;
+    out.alpha_cutoff = inst.alpha_cutoff;
+    out.tint = vec3<f32>(inst.tint_r, inst.tint_g, inst.tint_b);
 
     return out;
 }
@@ -123,7 +155,11 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let color = textureSample(t_atlas, s_atlas, in.tex_coord);
-    return color * in.alpha;
+    // Cutout rendering: discard texels below the instance's alpha threshold
+    if (color.a < in.alpha_cutoff) {
+        discard;
+    }
+    return vec4<f32>(color.rgb * in.tint, color.a) * in.alpha;
 }
 
 // Normal-map fragment shader — outputs normal-atlas texels to the G-buffer.
@@ -132,6 +168,9 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 @fragment
 fn fs_normal(in: VertexOutput) -> @location(0) vec4<f32> {
     let sample = textureSample(t_atlas, s_atlas, in.tex_coord);
+    if (sample.a < in.alpha_cutoff) {
+        discard;
+    }
     return vec4<f32>(sample.rgb, sample.a * in.alpha);
 }
 
@@ -149,6 +188,8 @@ fn vs_effects(input: EffectsVertexInput) -> VertexOutput {
     out.tex_coord = input.tex_coord;
     out.alpha = 1.0;
     out.color_idx = input.position.z;
+    out.alpha_cutoff = 0.0;
+    out.tint = vec3<f32>(1.0, 1.0, 1.0);
     return out;
 }
 
@@ -13,6 +13,22 @@ export interface LayerBatchDescriptor {
   end: number;
   /** Atlas ID for this batch (index into manifest's atlas list). All instances in batch use this atlas. */
   atlasId: number;
+  /** Parallax factor for this batch's layer (1.0 = moves with the world). */
+  parallax: number;
+}
+
+/** World-space rect of a baked layer that needs re-rendering.
+ *  Mirrors Rust DirtyRegion — lets the bake pass refresh just the changed
+ *  area of a large static layer instead of the whole texture. */
+export interface DirtyRegion {
+  /** RenderLayer enum value the rect applies to. */
+  layerId: number;
+  /** Rect min corner in world units. */
+  minX: number;
+  minY: number;
+  /** Rect max corner in world units. */
+  maxX: number;
+  maxY: number;
 }
 
 /** Bake state decoded from SAB header — controls layer caching. */
@@ -21,6 +37,8 @@ export interface BakeState {
   bakedMask: number;
   /** Monotonic generation counter — changes signal cache invalidation. */
   bakeGen: number;
+  /** Pending partial invalidation, when the last one was region-scoped. */
+  dirtyRegion?: DirtyRegion;
 }
 
 /** Lighting state decoded from SAB header + light data section. */
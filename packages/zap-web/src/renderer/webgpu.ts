@@ -318,7 +318,8 @@ export async function initWebGPURenderer(config: WebGPURendererConfig): Promise<
 
     // ---- Phase 1: Bake pass (render dirty baked layers to textures) ----
     if (hasBaking) {
-      encodeBakePass(encoder, compositor, layerBatches, bakeState, drawBatchInstances);
+      const projection = computeProjection(canvas.width, canvas.height, gameWidth, gameHeight);
+      encodeBakePass(encoder, compositor, layerBatches, bakeState, drawBatchInstances, projection);
     }
 
     // ---- Phase 2: Main scene render ----
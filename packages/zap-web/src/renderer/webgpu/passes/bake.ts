@@ -1,12 +1,45 @@
 // Layer baking pass — renders baked layers to intermediate textures.
 
-import type { LayerBatchDescriptor, BakeState } from '../../types';
+import type { LayerBatchDescriptor, BakeState, DirtyRegion } from '../../types';
 import { LayerCompositor } from '../../compositor';
+import type { Projection } from '../../camera';
 import type { DrawBatchFn } from './scene';
 
+/** Pixel-space scissor bounds for a partial re-bake. */
+interface ScissorRect {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/**
+ * Convert a world-space dirty rect to integer pixel scissor bounds, clamped
+ * to the target texture. Floor/ceil expand outward so the scissor never
+ * clips a partially covered pixel. Returns null when the rect lands fully
+ * outside the texture.
+ */
+function regionToScissor(
+  region: DirtyRegion,
+  projection: Projection,
+  texWidth: number,
+  texHeight: number,
+): ScissorRect | null {
+  const x0 = Math.max(0, Math.floor(region.minX * projection.scaleX));
+  const y0 = Math.max(0, Math.floor(region.minY * projection.scaleY));
+  const x1 = Math.min(texWidth, Math.ceil(region.maxX * projection.scaleX));
+  const y1 = Math.min(texHeight, Math.ceil(region.maxY * projection.scaleY));
+  if (x1 <= x0 || y1 <= y0) return null;
+  return { x: x0, y: y0, width: x1 - x0, height: y1 - y0 };
+}
+
 /**
  * Render baked+dirty layers to intermediate textures.
- * Returns true if any layers were baked.
+ *
+ * When the bake state carries a dirty region for a layer that already has
+ * cached content, only that rect is re-rendered (scissored, loading the
+ * previous texture contents) instead of clearing and redrawing the whole
+ * layer. Returns true if any layers were baked.
  */
 export function encodeBakePass(
   encoder: GPUCommandEncoder,
@@ -14,6 +47,7 @@ export function encodeBakePass(
   layerBatches: LayerBatchDescriptor[],
   bakeState: BakeState,
   drawBatchInstances: DrawBatchFn,
+  projection: Projection,
 ): boolean {
   let anyBaked = false;
 
@@ -21,16 +55,28 @@ export function encodeBakePass(
     if (!LayerCompositor.isLayerBaked(bakeState.bakedMask, batch.layerId)) continue;
     if (!compositor.needsRefresh(batch.layerId, bakeState.bakeGen)) continue;
 
+    // Partial re-bake: scissor to the dirty rect when this layer has prior
+    // cached content to load from; otherwise fall back to a full re-bake.
+    const region = bakeState.dirtyRegion;
+    let scissor: ScissorRect | null = null;
+    if (region && region.layerId === batch.layerId && compositor.hasBakedContent(batch.layerId)) {
+      const { texture } = compositor.getOrCreateTarget(batch.layerId);
+      scissor = regionToScissor(region, projection, texture.width, texture.height);
+    }
+
     // Render this layer's instances to an intermediate texture
     const { view: targetView } = compositor.getOrCreateTarget(batch.layerId);
     const layerPass = encoder.beginRenderPass({
       colorAttachments: [{
         view: targetView,
         clearValue: { r: 0, g: 0, b: 0, a: 0 },
-        loadOp: 'clear',
+        loadOp: scissor ? 'load' : 'clear',
         storeOp: 'store',
       }],
     });
+    if (scissor) {
+      layerPass.setScissorRect(scissor.x, scissor.y, scissor.width, scissor.height);
+    }
     drawBatchInstances(layerPass, batch.start, batch.end, batch.atlasId);
     layerPass.end();
 
@@ -14,6 +14,8 @@ import {
   HEADER_WORLD_HEIGHT,
   HEADER_MAX_SOUNDS,
   HEADER_SOUND_COUNT,
+  SOUND_EVENT_BYTES,
+  SOUND_NO_VOICE_GROUP,
   HEADER_MAX_EVENTS,
   HEADER_EVENT_COUNT,
   HEADER_PROTOCOL_VERSION,
@@ -31,10 +33,16 @@ import {
   HEADER_AMBIENT_G,
   HEADER_AMBIENT_B,
   HEADER_WASM_TIME_US,
+  HEADER_DIRTY_LAYER,
+  HEADER_DIRTY_MIN_X,
+  HEADER_DIRTY_MIN_Y,
+  HEADER_DIRTY_MAX_X,
+  HEADER_DIRTY_MAX_Y,
   PROTOCOL_VERSION,
   INSTANCE_FLOATS,
   EFFECTS_VERTEX_FLOATS,
   EVENT_FLOATS,
+  readGameEvent,
   SDF_INSTANCE_FLOATS,
   VECTOR_VERTEX_FLOATS,
   LAYER_BATCH_FLOATS,
@@ -47,9 +55,9 @@ import { computeProjection } from '../renderer/camera';
 interface GameWasmExports {
   game_init: () => void;
   game_tick: (dt: number) => void;
-  game_pointer_down: (x: number, y: number) => void;
-  game_pointer_up: (x: number, y: number) => void;
-  game_pointer_move: (x: number, y: number) => void;
+  game_pointer_down: (pointerId: number, x: number, y: number) => void;
+  game_pointer_up: (pointerId: number, x: number, y: number) => void;
+  game_pointer_move: (pointerId: number, x: number, y: number) => void;
   game_key_down: (keyCode: number) => void;
   game_key_up: (keyCode: number) => void;
   get_instances_ptr: () => number;
@@ -68,6 +76,7 @@ interface GameWasmExports {
   get_max_sounds: () => number;
   get_max_events: () => number;
   get_buffer_total_floats: () => number;
+  get_protocol_version?: () => number;
   get_sdf_instances_ptr: () => number;
   get_sdf_instance_count: () => number;
   get_max_sdf_instances: () => number;
@@ -88,6 +97,12 @@ interface GameWasmExports {
   get_layer_batch_data_offset?: () => number;
   // Bake state export
   get_bake_state?: () => number;
+  // Dirty region exports (header[28..33])
+  get_dirty_layer?: () => number;
+  get_dirty_min_x?: () => number;
+  get_dirty_min_y?: () => number;
+  get_dirty_max_x?: () => number;
+  get_dirty_max_y?: () => number;
   // Lighting exports
   get_lights_ptr?: () => number;
   get_light_count?: () => number;
@@ -156,6 +171,7 @@ async function initialize(wasmUrl: string, manifestJson?: string) {
     get_max_sounds: mod.get_max_sounds,
     get_max_events: mod.get_max_events,
     get_buffer_total_floats: mod.get_buffer_total_floats,
+    get_protocol_version: mod.get_protocol_version,
     get_sdf_instances_ptr: mod.get_sdf_instances_ptr,
     get_sdf_instance_count: mod.get_sdf_instance_count,
     get_max_sdf_instances: mod.get_max_sdf_instances,
@@ -172,6 +188,12 @@ async function initialize(wasmUrl: string, manifestJson?: string) {
     get_layer_batch_data_offset: mod.get_layer_batch_data_offset,
     // Bake state export
     get_bake_state: mod.get_bake_state,
+    // Dirty region exports
+    get_dirty_layer: mod.get_dirty_layer,
+    get_dirty_min_x: mod.get_dirty_min_x,
+    get_dirty_min_y: mod.get_dirty_min_y,
+    get_dirty_max_x: mod.get_dirty_max_x,
+    get_dirty_max_y: mod.get_dirty_max_y,
     // Lighting exports
     get_lights_ptr: mod.get_lights_ptr,
     get_light_count: mod.get_light_count,
@@ -186,6 +208,16 @@ async function initialize(wasmUrl: string, manifestJson?: string) {
     reload_sprite_manifest: mod.reload_sprite_manifest,
   };
 
+  // Refuse to run against a WASM build speaking a different wire format —
+  // a silent mismatch would misread the shared buffer
+  const wasmVersion = wasm.get_protocol_version?.();
+  if (wasmVersion !== undefined && wasmVersion !== PROTOCOL_VERSION) {
+    throw new Error(
+      `[worker] Protocol version mismatch: WASM module speaks v${wasmVersion}, ` +
+      `JS expects v${PROTOCOL_VERSION}. Rebuild with wasm-pack so both sides match.`
+    );
+  }
+
   wasm.game_init();
 
   // Load manifest into WASM sprite registry (if available)
@@ -272,7 +304,11 @@ function gameLoop() {
 
     const instanceCount = Math.min(wasm.get_instance_count(), layout.maxInstances);
     const effectsVertexCount = Math.min(wasm.get_effects_vertex_count(), layout.maxEffectsVertices);
-    const soundLen = Math.min(wasm.get_sound_events_len(), layout.maxSounds);
+    // get_sound_events_len returns bytes (SOUND_EVENT_BYTES per record)
+    const soundLen = Math.min(
+      wasm.get_sound_events_len() / SOUND_EVENT_BYTES,
+      layout.maxSounds,
+    );
     const eventLen = Math.min(wasm.get_game_events_len(), layout.maxEvents);
     const sdfCount = Math.min(wasm.get_sdf_instance_count(), layout.maxSdfInstances);
     const vectorVertexCount = wasm.get_vector_vertex_count
@@ -298,6 +334,11 @@ function gameLoop() {
     sharedF32[HEADER_VECTOR_VERTEX_COUNT] = vectorVertexCount;
     sharedF32[HEADER_LAYER_BATCH_COUNT] = layerBatchCount;
     sharedF32[HEADER_BAKE_STATE] = wasm.get_bake_state?.() ?? 0;
+    sharedF32[HEADER_DIRTY_LAYER] = wasm.get_dirty_layer?.() ?? -1;
+    sharedF32[HEADER_DIRTY_MIN_X] = wasm.get_dirty_min_x?.() ?? -1;
+    sharedF32[HEADER_DIRTY_MIN_Y] = wasm.get_dirty_min_y?.() ?? -1;
+    sharedF32[HEADER_DIRTY_MAX_X] = wasm.get_dirty_max_x?.() ?? -1;
+    sharedF32[HEADER_DIRTY_MAX_Y] = wasm.get_dirty_max_y?.() ?? -1;
     sharedF32[HEADER_LIGHT_COUNT] = lightCount;
     sharedF32[HEADER_AMBIENT_R] = wasm.get_ambient_r?.() ?? 1.0;
     sharedF32[HEADER_AMBIENT_G] = wasm.get_ambient_g?.() ?? 1.0;
@@ -346,11 +387,25 @@ function gameLoop() {
       sharedF32.set(lightData, layout.lightDataOffset);
     }
 
-    // Forward sound events
+    // Forward sound events (record layout documented in protocol.ts)
     if (soundLen > 0) {
       const ptr = wasm.get_sound_events_ptr();
-      const soundData = new Uint8Array(wasmMemory.buffer, ptr, soundLen);
-      const events = Array.from(soundData);
+      const bytes = new Uint8Array(wasmMemory.buffer, ptr, soundLen * SOUND_EVENT_BYTES);
+      const view = new DataView(wasmMemory.buffer, ptr, soundLen * SOUND_EVENT_BYTES);
+      const events = [];
+      for (let i = 0; i < soundLen; i++) {
+        const base = i * SOUND_EVENT_BYTES;
+        events.push({
+          id: bytes[base],
+          priority: bytes[base + 1],
+          voiceGroup: bytes[base + 2] === SOUND_NO_VOICE_GROUP ? null : bytes[base + 2],
+          looping: (bytes[base + 3] & 1) !== 0,
+          positional: (bytes[base + 3] & 2) !== 0,
+          volume: view.getFloat32(base + 4, true),
+          x: view.getFloat32(base + 8, true),
+          y: view.getFloat32(base + 12, true),
+        });
+      }
       self.postMessage({ type: 'sound', events });
     }
 
@@ -360,12 +415,7 @@ function gameLoop() {
       const eventData = new Float32Array(wasmMemory.buffer, ptr, eventLen * EVENT_FLOATS);
       const events = [];
       for (let i = 0; i < eventLen; i++) {
-        events.push({
-          kind: eventData[i * EVENT_FLOATS],
-          a: eventData[i * EVENT_FLOATS + 1],
-          b: eventData[i * EVENT_FLOATS + 2],
-          c: eventData[i * EVENT_FLOATS + 3],
-        });
+        events.push(readGameEvent(eventData, i));
       }
       self.postMessage({ type: 'event', events });
     }
@@ -419,19 +469,19 @@ self.onmessage = (e: MessageEvent) => {
 
     case 'pointer_down': {
       const w = screenToWorld(e.data.x, e.data.y);
-      wasm?.game_pointer_down(w.x, w.y);
+      wasm?.game_pointer_down(e.data.pointerId ?? 0, w.x, w.y);
       break;
     }
 
     case 'pointer_up': {
       const w = screenToWorld(e.data.x, e.data.y);
-      wasm?.game_pointer_up(w.x, w.y);
+      wasm?.game_pointer_up(e.data.pointerId ?? 0, w.x, w.y);
       break;
     }
 
     case 'pointer_move': {
       const w = screenToWorld(e.data.x, e.data.y);
-      wasm?.game_pointer_move(w.x, w.y);
+      wasm?.game_pointer_move(e.data.pointerId ?? 0, w.x, w.y);
       break;
     }
 
@@ -17,6 +17,11 @@ import {
   HEADER_AMBIENT_G,
   HEADER_AMBIENT_B,
   HEADER_WASM_TIME_US,
+  HEADER_DIRTY_LAYER,
+  HEADER_DIRTY_MIN_X,
+  HEADER_DIRTY_MIN_Y,
+  HEADER_DIRTY_MAX_X,
+  HEADER_DIRTY_MAX_Y,
   INSTANCE_FLOATS,
   EFFECTS_VERTEX_FLOATS,
   SDF_INSTANCE_FLOATS,
@@ -119,6 +124,7 @@ export function readFrameState(buf: Float32Array, layout: ProtocolLayout): Frame
         start: buf[base + 1],
         end: buf[base + 2],
         atlasId: buf[base + 3],
+        parallax: buf[base + 4],
       });
     }
   }
@@ -129,9 +135,20 @@ export function readFrameState(buf: Float32Array, layout: ProtocolLayout): Frame
   if (rawBakeState > 0) {
     const raw = Math.floor(rawBakeState);
     bakeState = {
-      bakedMask: raw & 0x3F,
-      bakeGen: raw >>> 6,
+      bakedMask: raw & 0x7F,
+      bakeGen: raw >>> 7,
     };
+    // Dirty-region rect (layer -1 = no pending partial invalidation)
+    const dirtyLayer = buf[HEADER_DIRTY_LAYER] ?? -1;
+    if (dirtyLayer >= 0) {
+      bakeState.dirtyRegion = {
+        layerId: dirtyLayer,
+        minX: buf[HEADER_DIRTY_MIN_X],
+        minY: buf[HEADER_DIRTY_MIN_Y],
+        maxX: buf[HEADER_DIRTY_MAX_X],
+        maxY: buf[HEADER_DIRTY_MAX_Y],
+      };
+    }
   }
 
   // Lighting state
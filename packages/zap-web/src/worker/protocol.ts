@@ -5,7 +5,7 @@
 // TypeScript reads them from the header to compute offsets dynamically.
 
 /** Number of floats in the header section. */
-export const HEADER_FLOATS = 28;
+export const HEADER_FLOATS = 33;
 
 /** Header field indices. */
 export const HEADER_LOCK = 0;
@@ -30,7 +30,7 @@ export const HEADER_VECTOR_VERTEX_COUNT = 17;
 export const HEADER_MAX_LAYER_BATCHES = 18;
 export const HEADER_LAYER_BATCH_COUNT = 19;
 export const HEADER_LAYER_BATCH_OFFSET = 20;
-/** Encoded bake state: baked_layers_mask | (bake_generation << 6). */
+/** Encoded bake state: baked_layers_mask | (bake_generation << 7). */
 export const HEADER_BAKE_STATE = 21;
 // Phase 9: Dynamic lighting
 export const HEADER_MAX_LIGHTS = 22;
@@ -40,27 +40,85 @@ export const HEADER_AMBIENT_G = 25;
 export const HEADER_AMBIENT_B = 26;
 /** WASM tick execution time in microseconds (written each frame by worker). */
 export const HEADER_WASM_TIME_US = 27;
+/** Dirty-region rect for partial re-bakes (mirrors Rust BakeState::encode_dirty_region):
+ *  layer id (-1 = no pending region) and the world-space min/max corners. */
+export const HEADER_DIRTY_LAYER = 28;
+export const HEADER_DIRTY_MIN_X = 29;
+export const HEADER_DIRTY_MIN_Y = 30;
+export const HEADER_DIRTY_MAX_X = 31;
+export const HEADER_DIRTY_MAX_Y = 32;
 
-/** Protocol version written into the header. */
-export const PROTOCOL_VERSION = 4.0;
+/** Protocol version written into the header.
+ *  v5: instances grew from 8 to 9 floats (alpha_cutoff).
+ *  v9: SDF instances grew from 12 to 16 floats (combine ops).
+ *  v10: SDF instances grew from 16 to 20 floats (outlines).
+ *  v11: sound events grew from 1 to 16 bytes (priority, looping, position, volume).
+ *  v12: Overlay layer added — bake generation and light falloff bits moved up one.
+ *  v13: instances grew from 14 to 15 floats (blend mode).
+ *  v14: header grew from 28 to 33 floats (dirty-region rect for partial re-bakes). */
+export const PROTOCOL_VERSION = 14.0;
 
-/** Floats per render instance (wire format — never changes). */
-export const INSTANCE_FLOATS = 8;
+/** Floats per render instance: x, y, rotation, scale, sprite_col, alpha,
+ *  cell_span, atlas_row, alpha_cutoff, tint_r, tint_g, tint_b, scale_y,
+ *  cell_span_y, blend. Bump PROTOCOL_VERSION when this changes. */
+export const INSTANCE_FLOATS = 15;
 
 /** Floats per effects vertex: x, y, z, u, v (wire format — never changes). */
 export const EFFECTS_VERTEX_FLOATS = 5;
 
+/** Bytes per packed sound event record:
+ *  byte 0 = id, byte 1 = priority, byte 2 = voice_group (255 = none),
+ *  byte 3 = flags (bit 0 looping, bit 1 positional), bytes 4-7 = volume,
+ *  bytes 8-11 = x, bytes 12-15 = y (f32 LE).
+ *  Bump PROTOCOL_VERSION when this changes. */
+export const SOUND_EVENT_BYTES = 16;
+
+/** Sound event record size in floats (records are 4-byte aligned). */
+export const SOUND_EVENT_FLOATS = SOUND_EVENT_BYTES / 4;
+
+/** Sentinel in the packed voice_group byte meaning "no voice group". */
+export const SOUND_NO_VOICE_GROUP = 255;
+
 /** Floats per game event: kind, a, b, c (wire format — never changes). */
 export const EVENT_FLOATS = 4;
 
-/** Floats per SDF instance: x, y, radius, rotation, r, g, b, shininess, emissive, shape_type, half_height, extra. */
-export const SDF_INSTANCE_FLOATS = 12;
+/** Slot indices within a game event record (see `GameEvent` in Rust). */
+export const EVENT_KIND = 0;
+export const EVENT_A = 1;
+export const EVENT_B = 2;
+export const EVENT_C = 3;
+
+/** Decoded game event, mirroring Rust's `GameEvent`. Vec2 payloads live in
+ *  `a`/`b`, integer payloads in `a` (see `GameEvent::vec2` / `::int`). */
+export interface GameEvent {
+  kind: number;
+  a: number;
+  b: number;
+  c: number;
+}
+
+/** Read game event `index` out of a float view over the event section. */
+export function readGameEvent(data: Float32Array, index: number): GameEvent {
+  const base = index * EVENT_FLOATS;
+  return {
+    kind: data[base + EVENT_KIND],
+    a: data[base + EVENT_A],
+    b: data[base + EVENT_B],
+    c: data[base + EVENT_C],
+  };
+}
+
+/** Floats per SDF instance: x, y, radius, rotation, r, g, b, shininess,
+ *  emissive, shape_type, half_height, extra, radius_b, half_height_b,
+ *  extra_b, smoothing, outline_width, outline_r, outline_g, outline_b.
+ *  Bump PROTOCOL_VERSION when this changes. */
+export const SDF_INSTANCE_FLOATS = 20;
 
 /** Floats per vector vertex: x, y, r, g, b, a (wire format — never changes). */
 export const VECTOR_VERTEX_FLOATS = 6;
 
-/** Floats per layer batch descriptor: layer_id, start, end, atlas_id. */
-export const LAYER_BATCH_FLOATS = 4;
+/** Floats per layer batch descriptor: layer_id, start, end, atlas_id, parallax. */
+export const LAYER_BATCH_FLOATS = 5;
 
 /** Floats per point light: x, y, r, g, b, intensity, radius, layer_mask. */
 export const LIGHT_FLOATS = 8;
@@ -69,14 +127,14 @@ export const LIGHT_FLOATS = 8;
 // Byte Strides (for buffer layout calculations)
 // ============================================================================
 
-/** Bytes per render instance (8 floats × 4 bytes). */
-export const INSTANCE_STRIDE_BYTES = INSTANCE_FLOATS * 4; // 32
+/** Bytes per render instance (15 floats × 4 bytes). */
+export const INSTANCE_STRIDE_BYTES = INSTANCE_FLOATS * 4; // 60
 
 /** Bytes per effects vertex (5 floats × 4 bytes). */
 export const EFFECTS_VERTEX_BYTES = EFFECTS_VERTEX_FLOATS * 4; // 20
 
-/** Bytes per SDF instance (12 floats × 4 bytes). */
-export const SDF_INSTANCE_STRIDE_BYTES = SDF_INSTANCE_FLOATS * 4; // 48
+/** Bytes per SDF instance (20 floats × 4 bytes). */
+export const SDF_INSTANCE_STRIDE_BYTES = SDF_INSTANCE_FLOATS * 4; // 80
 
 /** Bytes per vector vertex (6 floats × 4 bytes). */
 export const VECTOR_VERTEX_BYTES = VECTOR_VERTEX_FLOATS * 4; // 24
@@ -89,7 +147,7 @@ export const LIGHT_STRIDE_BYTES = LIGHT_FLOATS * 4; // 32
 // ============================================================================
 
 /** Default maximum layer batches (one per (layer, atlas) pair). */
-export const DEFAULT_MAX_LAYER_BATCHES = 48;
+export const DEFAULT_MAX_LAYER_BATCHES = 56;
 
 /** Default maximum point lights. */
 export const DEFAULT_MAX_LIGHTS = 64;
@@ -150,7 +208,7 @@ export class ProtocolLayout {
 
     this.instanceDataFloats = maxInstances * INSTANCE_FLOATS;
     this.effectsDataFloats = maxEffectsVertices * EFFECTS_VERTEX_FLOATS;
-    this.soundDataFloats = maxSounds;
+    this.soundDataFloats = maxSounds * SOUND_EVENT_FLOATS;
     this.eventDataFloats = maxEvents * EVENT_FLOATS;
     this.sdfDataFloats = maxSdfInstances * SDF_INSTANCE_FLOATS;
     this.vectorDataFloats = maxVectorVertices * VECTOR_VERTEX_FLOATS;